@@ -10,24 +10,38 @@ pub fn calc_trade_size() -> usize {
     8 + size_of::<Trade>()
 }
 
-pub fn has_admin_or_sub_admin_rights<'info>(
+pub fn has_permission<'info>(
     address: Pubkey,
+    permission: u64,
     cpi_program: AccountInfo<'info>,
-    global_config: AccountInfo<'info>,
+    role_membership: AccountInfo<'info>,
 ) -> Result<()> {
-    let cpi_accounts = fund::cpi::accounts::HasRole { global_config };
+    let cpi_accounts = fund::cpi::accounts::HasPermission { role_membership };
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    let is_either_admin_or_sub_admin =
-        fund::cpi::is_either_admin_or_sub_admin(cpi_ctx, address)?.get();
+    let has_permission = fund::cpi::has_permission(cpi_ctx, address, permission)?.get();
 
     require!(
-        is_either_admin_or_sub_admin,
-        fund::errors::CustomError::Unauthorized // Return an error if unauthorized
+        has_permission,
+        fund::errors::CustomError::MissingPermission // Return an error if unauthorized
     );
 
     Ok(())
 }
 
+/// Ensures a fund proposal targets the DAO of the given mint.
+///
+/// # Arguments
+/// * `dao_name` - Token name of the DAO the proposal was created for.
+/// * `mint` - Mint of the DAO the proposal is executed against.
+pub fn require_proposal_mint(dao_name: Option<&String>, mint: &Pubkey) -> Result<()> {
+    let dao_name = dao_name.ok_or(fund::errors::CustomError::NotFound)?;
+    let (dao_mint, _) =
+        Pubkey::find_program_address(&[fund::MINT_TAG, dao_name.as_bytes()], &fund::ID);
+    require_keys_eq!(dao_mint, *mint, fund::errors::CustomError::AccountMisMatch);
+
+    Ok(())
+}
+
 pub fn is_owner<'info>(
     address: Pubkey,
     cpi_program: AccountInfo<'info>,
@@ -44,3 +58,69 @@ pub fn is_owner<'info>(
 
     Ok(())
 }
+
+/// Calculates the fee withheld when transferring tokens of a mint.
+///
+/// # Arguments
+/// * `mint` - The mint, of the legacy token program or Token-2022.
+/// * `amount` - The amount transferred.
+///
+/// # Returns
+/// * `Result<u64>` - The fee withheld, 0 unless the mint has a transfer fee.
+pub fn calc_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    calc_epoch_transfer_fee(mint, Clock::get()?.epoch, amount)
+}
+
+/// Calculates the fee withheld when transferring tokens of a mint during a given epoch.
+///
+/// # Arguments
+/// * `mint` - Mint of the legacy token program or Token-2022.
+/// * `epoch` - The epoch of the transfer.
+/// * `amount` - The amount transferred.
+///
+/// # Returns
+/// * `Result<u64>` - The fee withheld, 0 unless the mint has a transfer fee.
+pub fn calc_epoch_transfer_fee(mint: &AccountInfo, epoch: u64, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(fund::errors::CustomError::InvalidTransferFee)?),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Transfers tokens of a mint, resolving the accounts of its transfer hook if any.
+///
+/// # Arguments
+/// * `token_program` - The token program of the mint.
+/// * `accounts` - The transfer accounts.
+/// * `additional_accounts` - Accounts required by the transfer hook, usually the remaining accounts.
+/// * `amount` - The amount transferred, fees withheld included.
+/// * `decimals` - Decimals of the mint.
+/// * `signer` - Signer seeds of the authority, if a PDA.
+///
+/// # Returns
+/// * `Result<()>` - Ok if the transfer succeeded.
+pub fn transfer_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    accounts: TransferChecked<'info>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    spl_token_2022::onchain::invoke_transfer_checked(
+        token_program.key,
+        accounts.from,
+        accounts.mint,
+        accounts.to,
+        accounts.authority,
+        additional_accounts,
+        amount,
+        decimals,
+        signer,
+    )
+    .map_err(Into::into)
+}
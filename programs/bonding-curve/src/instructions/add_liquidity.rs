@@ -10,7 +10,10 @@ use super::*;
 ///
 /// # Returns
 /// * `Result<()>` - Returns `Ok(())` if successful, otherwise an error.
-pub fn handler(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+    proposal_id: u32,
+) -> Result<()> {
     // Ensure the payer has sub-admin rights before proceeding.
     is_owner(
         *ctx.accounts.payer.key,
@@ -29,10 +32,16 @@ pub fn handler(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
     let proposal =
         fund::cpi::get_proposal_data(cpi_ctx, proposal_id, fund::ProposalType::PublishToAMM)?.get();
 
+    // Ensure the proposal targets this token
+    require_proposal_mint(proposal.dao_name.as_ref(), &ctx.accounts.mint_account.key())?;
+
     let cpi_accounts = fund::cpi::accounts::MoveToLP {
         global_config: ctx.accounts.fund_global_config.to_account_info(),
+        pause_config: ctx.accounts.pause_config.to_account_info(),
         escrow_sol_account: ctx.accounts.escrow_sol_account.to_account_info(),
+        treasury_ledger: ctx.accounts.treasury_ledger.to_account_info(),
         fund_data_store: ctx.accounts.fund_data_store.to_account_info(),
+        sale_tiers: ctx.accounts.sale_tiers.to_account_info(),
         escrow_mint_account: ctx.accounts.escrow_mint_account.to_account_info(),
         escrow_mint_ata: ctx.accounts.escrow_mint_ata.to_account_info(),
         mint_account: ctx.accounts.mint_account.to_account_info(),
@@ -45,7 +54,9 @@ pub fn handler(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
         caller_program: ctx.accounts.bonding_curve_program.to_account_info(),
     };
 
-    let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
+    // Pass the accounts of the mint's transfer hook, if any
+    let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
 
     let rent = ctx.accounts.sol_reserve.lamports();
 
@@ -85,16 +96,27 @@ pub struct AddLiquidity<'info> {
     #[account()]
     pub fund_global_config: AccountInfo<'info>,
 
+    /// CHECK: Fund's pause configuration account
+    pub pause_config: AccountInfo<'info>,
+
     /// The escrow account storing SOL before it is transferred
     /// CHECK: Escrow SOL account where committed SOL is stored
     #[account(mut)]
     pub escrow_sol_account: AccountInfo<'info>,
 
+    /// Treasury ledger of the DAO, recording the SOL moved to the pool
+    /// CHECK: Treasury ledger account, checked by the fund program
+    #[account(mut)]
+    pub treasury_ledger: AccountInfo<'info>,
+
     /// The fund data store tracking fundraising status
     /// CHECK: Fund Data Store
     #[account(mut)]
     pub fund_data_store: AccountInfo<'info>,
 
+    /// CHECK: Sale tiers of the DAO, validated by the fund program
+    pub sale_tiers: AccountInfo<'info>,
+
     /// The escrow account that holds the minted tokens
     /// CHECK: Escrow account for holding minted tokens
     #[account(mut)]
@@ -157,7 +179,7 @@ pub struct AddLiquidity<'info> {
     pub payer: Signer<'info>,
 
     /// Token program required for token-related operations.
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// Associated Token Program used for managing ATA accounts.
     pub associated_token_program: Program<'info, AssociatedToken>,
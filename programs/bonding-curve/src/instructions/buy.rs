@@ -9,16 +9,32 @@ use super::*;
 ///
 /// # Returns
 /// * `Result<()>` - Returns `Ok(())` if successful, otherwise an error.
-pub fn handler(ctx: Context<Buy>, sol_amount: u64) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Buy<'info>>, sol_amount: u64) -> Result<()> {
     // Ensure the provided SOL amount is greater than zero
     require_gt!(sol_amount, 0, CustomError::AmountCantBeZero);
 
     // Ensure signer is not blocked
     let cpi_accounts = fund::cpi::accounts::IsUserBlocked {
+        blacklist_entry: ctx.accounts.blacklist_entry.to_account_info(),
+        dao_blacklist_entry: ctx.accounts.dao_blacklist_entry.to_account_info(),
         blacklist: ctx.accounts.blacklist.to_account_info(),
+        dao_blacklist: ctx.accounts.dao_blacklist.to_account_info(),
+        blacklist_policy: ctx.accounts.blacklist_policy.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
-    fund::cpi::is_user_blocked(cpi_ctx, *ctx.accounts.payer.key)?;
+    fund::cpi::is_user_blocked(
+        cpi_ctx,
+        *ctx.accounts.payer.key,
+        ctx.accounts.mint_account.key(),
+        fund::BlacklistAction::Buy,
+    )?;
+
+    // Ensure trading on the mint is not paused
+    let cpi_accounts = fund::cpi::accounts::IsPaused {
+        pause_config: ctx.accounts.pause_config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
+    fund::cpi::is_paused(cpi_ctx, ctx.accounts.mint_account.key())?;
 
     let trade = &mut ctx.accounts.trade;
 
@@ -75,21 +91,25 @@ pub fn handler(ctx: Context<Buy>, sol_amount: u64) -> Result<()> {
     let signer = [&seeds[..]];
 
     // Transfer tokens from the reserve account to the recipient's associated token account
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.token_reserve.to_account_info(),
+        mint: ctx.accounts.mint_account.to_account_info(),
         to: ctx.accounts.to_ata.to_account_info(),
         authority: ctx.accounts.token_reserve.to_account_info(),
     };
 
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts,
-            &signer,
-        ),
+    transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        ctx.remaining_accounts,
         token_amount,
+        ctx.accounts.mint_account.decimals,
+        &signer,
     )?;
 
+    // The buyer receives the tokens less the fee withheld on transfer
+    let token_amount = token_amount - calc_transfer_fee(mint_account, token_amount)?;
+
     // Emit an event indicating that tokens have been purchased
     emit!(events::TokensBought {
         token: mint_key,
@@ -165,15 +185,30 @@ pub struct Buy<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// CHECK: Blacklist User Account
+    /// CHECK: Marker of the payer blocked in every DAO, validated by the fund program
+    pub blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the payer blocked in the DAO, validated by the fund program
+    pub dao_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Legacy global blacklist, validated by the fund program
     pub blacklist: AccountInfo<'info>,
 
+    /// CHECK: Legacy blacklist of the DAO, validated by the fund program
+    pub dao_blacklist: AccountInfo<'info>,
+
+    /// CHECK: Blacklist policy of the DAO, validated by the fund program
+    pub blacklist_policy: AccountInfo<'info>,
+
+    /// CHECK: Fund's pause configuration account
+    pub pause_config: AccountInfo<'info>,
+
     /// CHECK: Fund Program Address
     #[account(executable, address = fund::ID)]
     pub fund: AccountInfo<'info>,
 
     /// Token program used for token transfers.
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// Associated Token Program used for managing ATAs.
     pub associated_token_program: Program<'info, AssociatedToken>,
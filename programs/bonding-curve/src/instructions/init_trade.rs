@@ -2,12 +2,13 @@ use super::*;
 
 /// Handles the initialization of a trade by setting up required accounts and ensuring rent is paid.
 pub fn handler(ctx: Context<InitTrade>) -> Result<()> {
-    // Ensure the payer has sub-admin rights before proceeding.
+    // Ensure the payer is allowed to initialize trading before proceeding.
     // This prevents unauthorized users from initiating a trade.
-    is_owner(
+    has_permission(
         *ctx.accounts.payer.key,
+        fund::permissions::TRADE,
         ctx.accounts.fund.to_account_info(),
-        ctx.accounts.fund_global_config.to_account_info(),
+        ctx.accounts.role_membership.to_account_info(),
     )?;
 
     // Calculate the minimum rent-exempt balance required for the SOL reserve account.
@@ -43,8 +44,8 @@ pub fn handler(ctx: Context<InitTrade>) -> Result<()> {
 #[derive(Accounts)]
 #[instruction()]
 pub struct InitTrade<'info> {
-    /// CHECK: Fund's global configuration account, which stores admin and sub-admin information
-    pub fund_global_config: AccountInfo<'info>,
+    /// CHECK: Payer's role membership account in the fund program
+    pub role_membership: AccountInfo<'info>,
 
     /// CHECK: Fund Program Address
     #[account(executable, address = fund::ID)]
@@ -83,7 +84,7 @@ pub struct InitTrade<'info> {
     pub payer: Signer<'info>,
 
     /// Token program required for token-related operations such as minting and transfers.
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// System program required for system-level operations, including transferring SOL.
     /// This is needed for funding the SOL reserve account.
@@ -7,35 +7,63 @@ use super::*;
 ///
 /// # Arguments
 /// * `ctx` - The transaction context containing necessary accounts.
-/// * `percent` - The percent of amount to be moved out from liquidity, upto 6 decimal places.
+/// * `proposal_id` - Id of the approved proposal, unused when executed by the fund governance PDA.
+/// * `percent` - The percent of amount to be moved out from liquidity, upto 6 decimal places,
+///   applied when executed by the fund governance PDA.
 ///
 /// # Returns
 /// * `Result<()>` - Returns `Ok(())` if successful, otherwise an error.
-pub fn handler(ctx: Context<RemoveLiquidity>, proposal_id: u32) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+    proposal_id: u32,
+    percent: Option<u32>,
+) -> Result<()> {
     let trade = &ctx.accounts.trade;
 
-    // Ensure that the caller has admin rights
-    is_owner(
-        *ctx.accounts.authority.key,
-        ctx.accounts.fund.to_account_info(),
-        ctx.accounts.fund_global_config.to_account_info(),
-    )?;
-
-    let cpi_accounts = fund::cpi::accounts::GetProposalData {
-        global_config: ctx.accounts.fund_global_config.to_account_info(),
-        proposals_list: ctx.accounts.proposals_list.to_account_info(),
-        executer: ctx.accounts.authority.to_account_info(),
+    let authority = ctx.accounts.authority.key;
+
+    let percent = if fund::is_governance(authority) {
+        // The approved custom instruction proposal carries the percent itself
+        percent.ok_or(fund::errors::CustomError::MissingGovernanceValue)?
+    } else {
+        // Ensure that the caller has admin rights
+        is_owner(
+            *authority,
+            ctx.accounts.fund.to_account_info(),
+            ctx.accounts.fund_global_config.to_account_info(),
+        )?;
+
+        let cpi_accounts = fund::cpi::accounts::GetProposalData {
+            global_config: ctx.accounts.fund_global_config.to_account_info(),
+            proposals_list: ctx.accounts.proposals_list.to_account_info(),
+            executer: ctx.accounts.authority.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
+
+        let proposal = fund::cpi::get_proposal_data(
+            cpi_ctx,
+            proposal_id,
+            fund::ProposalType::RemoveLiquidity,
+        )?
+        .get();
+
+        // Ensure the proposal targets this token
+        require_proposal_mint(proposal.dao_name.as_ref(), &ctx.accounts.mint_account.key())?;
+
+        proposal.transfer_amount.unwrap_or_default() as u32
     };
 
-    let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
-
-    let proposal =
-        fund::cpi::get_proposal_data(cpi_ctx, proposal_id, fund::ProposalType::RemoveLiquidity)?
-            .get();
-
     let mint_account = &ctx.accounts.mint_account.to_account_info();
     let mint_key = mint_account.key();
 
+    // Ensure the liquidity of the mint is not paused
+    let cpi_accounts = fund::cpi::accounts::IsPaused {
+        pause_config: ctx.accounts.pause_config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
+    fund::cpi::is_paused(cpi_ctx, mint_key)?;
+
     // Define the seeds and signer for the SOL reserve account
     let seeds = &[
         RESERVE_TAG,
@@ -51,7 +79,7 @@ pub fn handler(ctx: Context<RemoveLiquidity>, proposal_id: u32) -> Result<()> {
         to: ctx.accounts.to_account.to_account_info(),
     };
 
-    let percent = u128::from(proposal.transfer_amount.unwrap_or_default());
+    let percent = u128::from(percent);
     let sol_amount = calc_amount(u128::from(trade.sol_reserve), percent);
 
     system_program::transfer(
@@ -73,21 +101,22 @@ pub fn handler(ctx: Context<RemoveLiquidity>, proposal_id: u32) -> Result<()> {
     let signer = [&seeds[..]];
 
     // Transfer all tokens from the reserve account to the recipient's associated token account
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.token_reserve.to_account_info(),
+        mint: ctx.accounts.mint_account.to_account_info(),
         to: ctx.accounts.to_ata.to_account_info(),
         authority: ctx.accounts.token_reserve.to_account_info(),
     };
 
     let token_amount = calc_amount(u128::from(trade.token_reserve), percent);
 
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts,
-            &signer,
-        ),
+    transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        ctx.remaining_accounts,
         token_amount,
+        ctx.accounts.mint_account.decimals,
+        &signer,
     )?;
 
     // Emit an event to log the liquidity removal action
@@ -108,6 +137,9 @@ pub struct RemoveLiquidity<'info> {
     /// CHECK: Fund's global configuration account, which stores admin and sub-admin information
     pub fund_global_config: AccountInfo<'info>,
 
+    /// CHECK: Fund's pause configuration account
+    pub pause_config: AccountInfo<'info>,
+
     /// CHECK: Fund Program Address
     #[account(executable, address = fund::ID)]
     pub fund: AccountInfo<'info>,
@@ -161,12 +193,12 @@ pub struct RemoveLiquidity<'info> {
     #[account(mut)]
     pub to_account: AccountInfo<'info>,
 
-    /// The authority executing the transaction, who must have sub-admin privileges.
+    /// The authority executing the transaction, the owner or the fund governance PDA.
     #[account(mut)]
     pub authority: Signer<'info>,
 
     /// The Solana token program, used for token transfers.
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The associated token program required for managing token accounts.
     pub associated_token_program: Program<'info, AssociatedToken>,
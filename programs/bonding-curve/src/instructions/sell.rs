@@ -10,28 +10,46 @@ use super::*;
 ///
 /// # Returns
 /// * `Result<()>` - Returns `Ok(())` if successful, otherwise an error.
-pub fn handler(ctx: Context<Sell>, token_amount: u64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
+    token_amount: u64,
+) -> Result<()> {
     // Ensure that the amount being sold is greater than zero
     require_gt!(token_amount, 0, CustomError::AmountCantBeZero);
 
     // Ensure signer is not blocked
     let cpi_accounts = fund::cpi::accounts::IsUserBlocked {
+        blacklist_entry: ctx.accounts.blacklist_entry.to_account_info(),
+        dao_blacklist_entry: ctx.accounts.dao_blacklist_entry.to_account_info(),
         blacklist: ctx.accounts.blacklist.to_account_info(),
+        dao_blacklist: ctx.accounts.dao_blacklist.to_account_info(),
+        blacklist_policy: ctx.accounts.blacklist_policy.to_account_info(),
     };
     let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
-    fund::cpi::is_user_blocked(cpi_ctx, *ctx.accounts.payer.key)?;
-
-    let trade = &mut ctx.accounts.trade;
+    fund::cpi::is_user_blocked(
+        cpi_ctx,
+        *ctx.accounts.payer.key,
+        ctx.accounts.mint_account.key(),
+        fund::BlacklistAction::Sell,
+    )?;
 
-    // Compute the amount of SOL to be received after selling the tokens
-    let sol_amount = get_amount_out(
-        token_amount as u128,
-        trade.token_reserve as u128,
-        trade.sol_reserve as u128,
-    );
+    // Ensure trading on the mint is not paused
+    let cpi_accounts = fund::cpi::accounts::IsPaused {
+        pause_config: ctx.accounts.pause_config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
+    fund::cpi::is_paused(cpi_ctx, ctx.accounts.mint_account.key())?;
 
+    let trade = &mut ctx.accounts.trade;
     let mint_account = &ctx.accounts.mint_account.to_account_info();
     let mint_key: Pubkey = mint_account.key();
+
+    // Only the tokens reaching the reserve are priced, less the fee withheld on transfer
+    let received_amount = token_amount - calc_transfer_fee(mint_account, token_amount)?;
+
+    // Compute the amount of SOL to be received after selling the tokens, updating the reserves
+    let sol_amount = trade.sell(received_amount);
+
     let seeds = &[
         RESERVE_TAG,
         SOL_TAG,
@@ -62,10 +80,6 @@ pub fn handler(ctx: Context<Sell>, token_amount: u64) -> Result<()> {
     // Calculate the final amount of SOL to be received after deducting fees
     let sol_amount_after_fee = sol_amount - fees;
 
-    // Update the trade reserves accordingly
-    trade.sol_reserve -= sol_amount;
-    trade.token_reserve += token_amount;
-
     // Transfer SOL from the reserve account to the seller's account
     let cpi_accounts = system_program::Transfer {
         from: ctx.accounts.sol_reserve.to_account_info(),
@@ -82,15 +96,20 @@ pub fn handler(ctx: Context<Sell>, token_amount: u64) -> Result<()> {
     )?;
 
     // Transfer the sold tokens from the seller's account to the reserve
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.from_ata.to_account_info(),
+        mint: ctx.accounts.mint_account.to_account_info(),
         to: ctx.accounts.token_reserve.to_account_info(),
         authority: ctx.accounts.payer.to_account_info(),
     };
 
-    token::transfer(
-        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+    transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        ctx.remaining_accounts,
         token_amount,
+        ctx.accounts.mint_account.decimals,
+        &[],
     )?;
 
     // Emit an event to log the token sale transaction
@@ -167,15 +186,30 @@ pub struct Sell<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// CHECK: Blacklist User Account
+    /// CHECK: Marker of the payer blocked in every DAO, validated by the fund program
+    pub blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the payer blocked in the DAO, validated by the fund program
+    pub dao_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Legacy global blacklist, validated by the fund program
     pub blacklist: AccountInfo<'info>,
 
+    /// CHECK: Legacy blacklist of the DAO, validated by the fund program
+    pub dao_blacklist: AccountInfo<'info>,
+
+    /// CHECK: Blacklist policy of the DAO, validated by the fund program
+    pub blacklist_policy: AccountInfo<'info>,
+
+    /// CHECK: Fund's pause configuration account
+    pub pause_config: AccountInfo<'info>,
+
     /// CHECK: Fund Program Address
     #[account(executable, address = fund::ID)]
     pub fund: AccountInfo<'info>,
 
     /// The Solana token program for managing token transfers.
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The associated token program required for managing token accounts.
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -1,24 +1,57 @@
 use super::*;
 
 /// Function to update the fees collection account
+/// once an `UpdateTradeFeeAccount` proposal has been approved in the fund program, or
+/// by the fund governance PDA executing an approved custom instruction proposal.
 ///
 /// # Arguments
 /// * `ctx` - The execution context containing the global configuration and authority account
-/// * `new_account` - The new public key for the fees collection account
+/// * `proposal_id` - Id of the approved proposal, unused when executed by the governance PDA
+/// * `fees_collection_account` - Account applied when executed by the governance PDA
 ///
 /// # Returns
 /// * `Result<()>` - Returns Ok(()) if successful, otherwise an error
-pub fn handler(ctx: Context<UpdateGlobalConfig>, new_account: Pubkey) -> Result<()> {
+pub fn handler(
+    ctx: Context<UpdateGlobalConfig>,
+    proposal_id: u32,
+    fees_collection_account: Option<Pubkey>,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key;
+
+    let new_account = if fund::is_governance(authority) {
+        // The approved custom instruction proposal carries the new account itself
+        fees_collection_account.ok_or(fund::errors::CustomError::MissingGovernanceValue)?
+    } else {
+        // Ensure that the caller has admin rights
+        is_owner(
+            *authority,
+            ctx.accounts.fund.to_account_info(),
+            ctx.accounts.fund_global_config.to_account_info(),
+        )?;
+
+        let cpi_accounts = fund::cpi::accounts::GetProposalData {
+            global_config: ctx.accounts.fund_global_config.to_account_info(),
+            proposals_list: ctx.accounts.proposals_list.to_account_info(),
+            executer: ctx.accounts.authority.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
+
+        let proposal = fund::cpi::get_proposal_data(
+            cpi_ctx,
+            proposal_id,
+            fund::ProposalType::UpdateTradeFeeAccount,
+        )?
+        .get();
+
+        proposal
+            .address
+            .ok_or(fund::errors::CustomError::AddressNotFound)?
+    };
+
     // Get a mutable reference to the global configuration account
     let global_config = &mut ctx.accounts.global_config;
 
-    // Ensure that the caller has sub-admin rights
-    has_admin_or_sub_admin_rights(
-        *ctx.accounts.authority.key,
-        ctx.accounts.fund.to_account_info(),
-        ctx.accounts.fund_global_config.to_account_info(),
-    )?;
-
     // Store the old fees collection account before updating
     let from = global_config.fees_collection_account;
 
@@ -55,7 +88,11 @@ pub struct UpdateGlobalConfig<'info> {
     #[account(executable, address = fund::ID)]
     pub fund: AccountInfo<'info>,
 
-    /// The account that is making the update request (must be the current admin).
+    /// CHECK: Proposal List
+    #[account(mut)]
+    pub proposals_list: AccountInfo<'info>,
+
+    /// The account that is making the update request (the owner or the fund governance PDA).
     #[account(mut)]
     pub authority: Signer<'info>,
 
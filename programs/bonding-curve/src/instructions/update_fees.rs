@@ -1,23 +1,56 @@
 use super::*;
 
 /// Function to update the trading fee percentage
+/// once an `UpdateTradeFees` proposal has been approved in the fund program, or
+/// by the fund governance PDA executing an approved custom instruction proposal.
 ///
 /// # Arguments
 /// * `ctx` - The execution context containing relevant accounts
-/// * `fee_percent` - The new fee percentage to be set
+/// * `proposal_id` - Id of the approved proposal, unused when executed by the governance PDA
+/// * `fee_percent` - Fee percent applied when executed by the governance PDA
 ///
 /// # Returns
 /// * `Result<()>` - Returns Ok(()) if successful, otherwise an error
-pub fn handler(ctx: Context<UpdateFees>, fee_percent: u32) -> Result<()> {
-    // Get mutable references to the global configuration and trade accounts
-    let trade = &mut ctx.accounts.trade;
+pub fn handler(ctx: Context<UpdateFees>, proposal_id: u32, fee_percent: Option<u32>) -> Result<()> {
+    let authority = ctx.accounts.authority.key;
+
+    let fee_percent = if fund::is_governance(authority) {
+        // The approved custom instruction proposal carries the new fee itself
+        fee_percent.ok_or(fund::errors::CustomError::MissingGovernanceValue)?
+    } else {
+        // Ensure that the caller has admin rights
+        is_owner(
+            *authority,
+            ctx.accounts.fund.to_account_info(),
+            ctx.accounts.fund_global_config.to_account_info(),
+        )?;
+
+        let cpi_accounts = fund::cpi::accounts::GetProposalData {
+            global_config: ctx.accounts.fund_global_config.to_account_info(),
+            proposals_list: ctx.accounts.proposals_list.to_account_info(),
+            executer: ctx.accounts.authority.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(ctx.accounts.fund.to_account_info(), cpi_accounts);
+
+        let proposal = fund::cpi::get_proposal_data(
+            cpi_ctx,
+            proposal_id,
+            fund::ProposalType::UpdateTradeFees,
+        )?
+        .get();
 
-    // Ensure the caller has sub-admin rights
-    has_admin_or_sub_admin_rights(
-        *ctx.accounts.authority.key,
-        ctx.accounts.fund.to_account_info(),
-        ctx.accounts.fund_global_config.to_account_info(),
-    )?;
+        // Ensure the proposal targets this token
+        require!(
+            proposal.address == Some(ctx.accounts.mint_account.key()),
+            fund::errors::CustomError::AccountMisMatch
+        );
+
+        proposal.transfer_amount.unwrap_or_default() as u32
+    };
+
+    // Get mutable reference to the trade account
+    let trade = &mut ctx.accounts.trade;
 
     // Store the previous fee percentage before updating
     let from = trade.fee_percent;
@@ -54,11 +87,15 @@ pub struct UpdateFees<'info> {
     )]
     pub trade: Box<Account<'info, Trade>>,
 
+    /// CHECK: Proposal List
+    #[account(mut)]
+    pub proposals_list: AccountInfo<'info>,
+
     // Mint account, which represents the token mint
     #[account(mut)]
     pub mint_account: Box<InterfaceAccount<'info, Mint>>,
 
-    // Authority account, which must be a signer and must have sub-admin permissions
+    // Authority account, which must be a signer and must be the owner or the fund governance PDA
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -10,8 +10,15 @@ use anchor_lang::{
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Token, Transfer},
-    token_interface::{Mint, TokenAccount},
+    token_interface::{
+        spl_token_2022::{
+            self,
+            extension::{
+                transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+            },
+        },
+        Mint, TokenAccount, TokenInterface, TransferChecked,
+    },
 };
 use std::mem::size_of;
 
@@ -24,6 +31,9 @@ mod instructions; // Instruction handlers
 mod states; // Program state definitions
 mod utils; // Utility functions
 
+#[cfg(test)]
+mod tests;
+
 declare_id!("ADgy4JNoyTP8X78cC9nV3sw8Wk9Yz6mNX49Y7YCzzkJX");
 
 #[program]
@@ -41,32 +51,53 @@ pub mod bonding_curve {
     }
 
     /// Update the fee collection account address
-    pub fn update_fee_account(ctx: Context<UpdateGlobalConfig>, fee_account: Pubkey) -> Result<()> {
-        update_fee_account::handler(ctx, fee_account)
+    pub fn update_fee_account(
+        ctx: Context<UpdateGlobalConfig>,
+        proposal_id: u32,
+        fees_collection_account: Option<Pubkey>,
+    ) -> Result<()> {
+        update_fee_account::handler(ctx, proposal_id, fees_collection_account)
     }
 
     /// Update the fee percentage charged on trades
-    pub fn update_fees(ctx: Context<UpdateFees>, fee_percent: u32) -> Result<()> {
-        update_fees::handler(ctx, fee_percent)
+    pub fn update_fees(
+        ctx: Context<UpdateFees>,
+        proposal_id: u32,
+        fee_percent: Option<u32>,
+    ) -> Result<()> {
+        update_fees::handler(ctx, proposal_id, fee_percent)
     }
 
     /// Add liquidity to the bonding curve pool
-    pub fn add_liquidity(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
+    pub fn add_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+        proposal_id: u32,
+    ) -> Result<()> {
         add_liquidity::handler(ctx, proposal_id)
     }
 
     /// Remove liquidity from the bonding curve pool
-    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, percent: u32) -> Result<()> {
-        remove_liquidity::handler(ctx, percent)
+    pub fn remove_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveLiquidity<'info>>,
+        proposal_id: u32,
+        percent: Option<u32>,
+    ) -> Result<()> {
+        remove_liquidity::handler(ctx, proposal_id, percent)
     }
 
     /// Buy tokens from the bonding curve pool using SOL
-    pub fn buy_tokens(ctx: Context<Buy>, sol_amount: u64) -> Result<()> {
+    pub fn buy_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+        sol_amount: u64,
+    ) -> Result<()> {
         buy::handler(ctx, sol_amount)
     }
 
     /// Sell tokens back to the bonding curve pool for SOL
-    pub fn sell_tokens(ctx: Context<Sell>, token_amount: u64) -> Result<()> {
+    pub fn sell_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
+        token_amount: u64,
+    ) -> Result<()> {
         sell::handler(ctx, token_amount)
     }
 
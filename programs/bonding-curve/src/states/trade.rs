@@ -13,3 +13,25 @@ pub struct Trade {
     /// Trading fee percentage
     pub fee_percent: u32,
 }
+
+impl Trade {
+    /// Sells tokens reaching the token reserve against SOL of the SOL reserve
+    ///
+    /// # Arguments
+    /// * `received_amount` - The amount of tokens received by the reserve, less any transfer fee
+    ///
+    /// # Returns
+    /// * `u64` - The amount of SOL taken from the SOL reserve, trading fees included
+    pub fn sell(&mut self, received_amount: u64) -> u64 {
+        let sol_amount = get_amount_out(
+            received_amount as u128,
+            self.token_reserve as u128,
+            self.sol_reserve as u128,
+        );
+
+        self.sol_reserve -= sol_amount;
+        self.token_reserve += received_amount;
+
+        sol_amount
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+/// Builds a Token-2022 mint with a transfer fee of `basis_points`, uncapped.
+fn transfer_fee_mint_data(basis_points: u16) -> Vec<u8> {
+    use spl_token_2022::extension::{
+        transfer_fee::TransferFee, BaseStateWithExtensionsMut, ExtensionType,
+        StateWithExtensionsMut,
+    };
+
+    let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state =
+        StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+            .unwrap();
+    let fee = TransferFee {
+        epoch: 0.into(),
+        maximum_fee: u64::MAX.into(),
+        transfer_fee_basis_points: basis_points.into(),
+    };
+    let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+    config.older_transfer_fee = fee;
+    config.newer_transfer_fee = fee;
+    state.base.is_initialized = true;
+    state.pack_base();
+    state.init_account_type().unwrap();
+    data
+}
+
+#[test]
+fn test_sell_prices_received_amount() {
+    let key = Pubkey::new_unique();
+    let owner = spl_token_2022::ID;
+    let mut lamports = 0;
+    let mut data = transfer_fee_mint_data(1_000);
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let mut trade = Trade {
+        sol_reserve: 1_000_000,
+        token_reserve: 1_000_000,
+        fee_percent: 0,
+    };
+
+    // A 10% fee leaves 100_000 of the 111_112 tokens sold in the reserve
+    let token_amount = 111_112;
+    let received_amount = token_amount - calc_epoch_transfer_fee(&mint, 1, token_amount).unwrap();
+    assert_eq!(received_amount, 100_000);
+
+    let sol_amount = trade.sell(received_amount);
+    assert_eq!(sol_amount, 90_909);
+    assert_eq!(trade.sol_reserve, 909_091);
+    assert_eq!(trade.token_reserve, 1_100_000);
+
+    // Pricing the tokens sent would pay out more SOL than the reserve received tokens for
+    let mut untaxed = Trade {
+        sol_reserve: 1_000_000,
+        token_reserve: 1_000_000,
+        fee_percent: 0,
+    };
+    assert!(untaxed.sell(token_amount) > sol_amount);
+
+    // The product of the reserves never decreases
+    assert!(trade.sol_reserve as u128 * trade.token_reserve as u128 >= 1_000_000u128 * 1_000_000);
+}
@@ -49,3 +49,137 @@ pub const PROPOSAL_TAG: &[u8] = b"proposal";
 /// Tag for block user
 #[constant]
 pub const BLACKLIST_TAG: &[u8] = b"blacklist";
+
+/// Tag for the governance PDA that signs custom instruction proposals
+#[constant]
+pub const GOVERNANCE_TAG: &[u8] = b"governance";
+
+/// Tag for accounts storing the instructions of a custom instruction proposal
+#[constant]
+pub const PROPOSAL_INSTRUCTIONS_TAG: &[u8] = b"proposal_instructions";
+
+/// Tag for per-mint token holder governance accounts
+#[constant]
+pub const DAO_GOVERNANCE_TAG: &[u8] = b"dao_governance";
+
+/// Tag for accounts tracking tokens deposited by a voter
+#[constant]
+pub const VOTER_TAG: &[u8] = b"voter";
+
+/// Tag for accounts recording a vote on a community proposal
+#[constant]
+pub const VOTE_TAG: &[u8] = b"vote";
+
+/// Tag for the emergency pause configuration
+#[constant]
+pub const PAUSE_TAG: &[u8] = b"pause";
+
+/// Maximum number of proposal ids returned by a single `list_proposals` call,
+/// keeping the result within the return data limit
+#[constant]
+pub const MAX_PROPOSALS_PAGE_SIZE: u32 = 200;
+
+/// Tag for role membership accounts holding permission bitmasks
+#[constant]
+pub const ROLE_TAG: &[u8] = b"role";
+
+/// Tag for marker accounts of blocked addresses
+#[constant]
+pub const BLACKLIST_ENTRY_TAG: &[u8] = b"blacklist_entry";
+
+/// Tag for per-mint Merkle allowlist accounts
+#[constant]
+pub const ALLOWLIST_TAG: &[u8] = b"allowlist";
+
+/// Tag for per-mint blacklist policy accounts
+#[constant]
+pub const BLACKLIST_POLICY_TAG: &[u8] = b"blacklist_policy";
+
+/// Tag for accounts storing the block details of a `BlocklistUser` proposal
+#[constant]
+pub const BLOCK_DETAILS_TAG: &[u8] = b"block_details";
+
+/// Period in seconds a nominated owner has to accept the ownership
+#[constant]
+pub const OWNERSHIP_ACCEPTANCE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// Tag for per-mint sale tier accounts, and per-user tier commitments
+#[constant]
+pub const SALE_TIER_TAG: &[u8] = b"sale_tier";
+
+/// Maximum number of sale tiers of a DAO
+#[constant]
+pub const MAX_SALE_TIERS: usize = 8;
+
+/// Tag for per-mint public round configuration accounts
+#[constant]
+pub const PUBLIC_ROUND_TAG: &[u8] = b"public_round";
+
+/// Total of basis-point shares, i.e. 100%
+#[constant]
+pub const MAX_SHARE_BPS: u16 = 10_000;
+
+/// Minimum share of a co-creator in basis points, i.e. 1% of the token metadata creators
+#[constant]
+pub const MIN_CO_CREATOR_SHARE_BPS: u16 = 100;
+
+/// Maximum number of co-creators of a DAO, as limited by token metadata
+#[constant]
+pub const MAX_CO_CREATORS: usize = 5;
+
+/// Maximum number of milestones of a DAO
+#[constant]
+pub const MAX_MILESTONES: usize = 10;
+
+/// Tag for per-mint stream period accounts, and per-recipient payout streams
+#[constant]
+pub const STREAM_TAG: &[u8] = b"stream";
+
+/// Tag for per-mint treasury ledger accounts
+#[constant]
+pub const TREASURY_TAG: &[u8] = b"treasury";
+
+/// Maximum number of flows kept in a treasury ledger
+#[constant]
+pub const MAX_LEDGER_ENTRIES: usize = 32;
+
+/// Key of the creators field in the native metadata of Token-2022 DAO mints
+#[constant]
+pub const CREATORS_METADATA_KEY: &str = "creators";
+
+/// Maximum royalty withheld on transfers of a DAO token, in basis points
+#[constant]
+pub const MAX_TRANSFER_FEE_BPS: u16 = 1_000;
+
+/// Transfer hook program checking transfers of DAO tokens against the blacklist
+#[constant]
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey =
+    pubkey!("45VLfgrEZk14A5Ev2du94Rc5sWGukZZyPTdP26b811Sc");
+
+/// Tag for the list of accounts the transfer hook program resolves on transfers of a mint
+#[constant]
+pub const EXTRA_ACCOUNT_METAS_TAG: &[u8] = b"extra-account-metas";
+
+/// Discriminator of the transfer hook interface instruction initializing the list of a mint
+pub const INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR: [u8; 8] =
+    [43, 34, 13, 49, 167, 88, 235, 235];
+
+/// Tag for accounts storing the metadata changes of an update token metadata proposal
+#[constant]
+pub const METADATA_UPDATE_TAG: &[u8] = b"metadata_update";
+
+/// Key of the collection field in the native metadata of Token-2022 DAO mints
+#[constant]
+pub const COLLECTION_METADATA_KEY: &str = "collection";
+
+/// Maximum length of a token name in Metaplex metadata
+#[constant]
+pub const MAX_METADATA_NAME_LEN: usize = 32;
+
+/// Maximum length of a token symbol in Metaplex metadata
+#[constant]
+pub const MAX_METADATA_SYMBOL_LEN: usize = 10;
+
+/// Maximum length of a token URI in Metaplex metadata
+#[constant]
+pub const MAX_METADATA_URI_LEN: usize = 200;
@@ -23,10 +23,32 @@ pub enum ProposalStatus {
     Approved { timestamp: i64 },
 }
 
+/// Proposal state used to filter proposals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalState {
+    Pending,
+    Approved,
+    Rejected,
+    Executed,
+}
+
+impl ProposalState {
+    /// Checks whether the proposal is in this state
+    pub fn matches(&self, proposal: &ProposalData) -> bool {
+        match self {
+            ProposalState::Pending => proposal.status == ProposalStatus::Pending,
+            ProposalState::Approved => proposal.is_approved() && !proposal.is_executed(),
+            ProposalState::Rejected => proposal.is_rejected(),
+            ProposalState::Executed => proposal.is_executed(),
+        }
+    }
+}
+
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum ApproverType {
     Admin,
     Deployer,
+    TokenHolders,
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
@@ -52,4 +74,97 @@ pub enum ProposalType {
     UnblockCreator,
     BlocklistUser,
     UnblockUser,
+    CustomInstruction,
+    UpdateFees,
+    UpdateFeeAccount,
+    AddSubAdmin,
+    RemoveSubAdmin,
+    UpdateTradeFees,
+    UpdateTradeFeeAccount,
+    Unpause,
+    SetPermissions,
+    GrantSubAdmin,
+    SetBlacklistPolicy,
+    ReleaseMilestone,
+    FailMilestone,
+    CancelStream,
+    UpdateTokenMetadata,
+    WithdrawRoyalties,
+}
+
+/// Role a payout stream pays its recipient in
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PayoutKind {
+    Creator,
+    Deployer,
+}
+
+impl PayoutKind {
+    /// Returns the seed distinguishing the streams of each kind
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            PayoutKind::Creator => b"creator",
+            PayoutKind::Deployer => b"deployer",
+        }
+    }
+
+    /// Converts the code carried by a `CancelStream` proposal
+    pub fn from_code(code: u64) -> Option<Self> {
+        match code {
+            0 => Some(PayoutKind::Creator),
+            1 => Some(PayoutKind::Deployer),
+            _ => None,
+        }
+    }
+}
+
+/// Policy deciding what blacklisted users may still do in a DAO
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BlacklistPolicy {
+    /// Every action is rejected
+    BlockAll,
+    /// Commitments and buys are rejected, exits and claims remain open
+    BlockInflows,
+    /// Commitments, buys and claims are rejected, only selling remains open
+    FreezeClaims,
+    /// Only SOL refunds of a failed or closed fundraise remain open
+    RefundsOnly,
+}
+
+/// Action of a user checked against the blacklist policy
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BlacklistAction {
+    Commit,
+    Buy,
+    Sell,
+    ClaimTokens,
+    ClaimRefund,
+}
+
+impl BlacklistPolicy {
+    /// Policy applied while a DAO has no policy account, so blacklisted users can
+    /// still recover their SOL from a failed or closed fundraise
+    pub const DEFAULT: BlacklistPolicy = BlacklistPolicy::RefundsOnly;
+
+    /// Checks whether a blacklisted user may perform the action
+    pub fn allows(&self, action: BlacklistAction) -> bool {
+        use BlacklistAction::*;
+        match self {
+            BlacklistPolicy::BlockAll => false,
+            BlacklistPolicy::BlockInflows => matches!(action, Sell | ClaimTokens | ClaimRefund),
+            BlacklistPolicy::FreezeClaims => action == Sell,
+            BlacklistPolicy::RefundsOnly => action == ClaimRefund,
+        }
+    }
+
+    /// Converts the code carried by a `SetBlacklistPolicy` proposal
+    pub fn from_code(code: u64) -> Option<Self> {
+        match code {
+            0 => Some(BlacklistPolicy::BlockAll),
+            1 => Some(BlacklistPolicy::BlockInflows),
+            2 => Some(BlacklistPolicy::FreezeClaims),
+            3 => Some(BlacklistPolicy::RefundsOnly),
+            _ => None,
+        }
+    }
 }
@@ -106,4 +106,112 @@ pub enum CustomError {
 
     #[msg("Address present in admin list.")]
     PresentInAdminList,
+
+    #[msg("Proposal has no instructions.")]
+    EmptyInstructions,
+
+    #[msg("Invalid governance parameters.")]
+    InvalidGovernanceParams,
+
+    #[msg("Voting period has ended.")]
+    VotingClosed,
+
+    #[msg("Voting period is still in progress.")]
+    VotingInProgress,
+
+    #[msg("Insufficient voting weight.")]
+    InsufficientVotingWeight,
+
+    #[msg("Tokens are locked until the voting period ends.")]
+    TokensLocked,
+
+    #[msg("Proposal is decided by token holder vote.")]
+    TokenHolderProposal,
+
+    #[msg("Error: Actions are paused!")]
+    Paused,
+
+    #[msg("Error: Not paused!")]
+    NotPaused,
+
+    #[msg("Error: Missing permission!")]
+    MissingPermission,
+
+    #[msg("Error: Grant duration must be at least one day!")]
+    InvalidGrantDuration,
+
+    #[msg("Error: No ownership transfer pending!")]
+    NoPendingOwner,
+
+    #[msg("Error: Ownership transfer has expired!")]
+    OwnershipTransferExpired,
+
+    #[msg("Error: Block must end in the future!")]
+    InvalidBlockDuration,
+
+    #[msg("Error: Unknown blacklist policy!")]
+    InvalidBlacklistPolicy,
+
+    #[msg("Error: Invalid allowlist proof!")]
+    InvalidMerkleProof,
+
+    #[msg("Error: Invalid sale tiers!")]
+    InvalidSaleTiers,
+
+    #[msg("Error: No sale tier is active!")]
+    NoActiveSaleTier,
+
+    #[msg("Error: Sale tier cap exceeded!")]
+    SaleTierCapExceeded,
+
+    #[msg("Error: Commitment must be the only one of its transaction!")]
+    MultipleCommitments,
+
+    #[msg("Error: Invalid co-creator shares!")]
+    InvalidCoCreators,
+
+    #[msg("Error: Invalid tokenomics!")]
+    InvalidTokenomics,
+
+    #[msg("Error: Invalid milestones!")]
+    InvalidMilestones,
+
+    #[msg("Error: Milestone is not pending!")]
+    InvalidMilestone,
+
+    #[msg("Error: Creator funds are released through milestones!")]
+    MilestonesDeclared,
+
+    #[msg("Error: Invalid stream period!")]
+    InvalidStreamPeriod,
+
+    #[msg("Error: Amount exceeds the tokens claimed!")]
+    ExceedsClaimedTokens,
+
+    #[msg("Error: Invalid transfer fee!")]
+    InvalidTransferFee,
+
+    #[msg("Error: Token extensions require a Token-2022 mint!")]
+    TokenExtensionsUnsupported,
+
+    #[msg("Error: Invalid metadata update!")]
+    InvalidMetadataUpdate,
+
+    #[msg("Error: Token metadata is locked!")]
+    MetadataLocked,
+
+    #[msg("Error: Value required when executed by governance!")]
+    MissingGovernanceValue,
+
+    #[msg("Error: Merkle root is locked once its round has started!")]
+    MerkleRootLocked,
+
+    #[msg("Error: Account already migrated!")]
+    AlreadyMigrated,
+
+    #[msg("Error: Users of a sale tier are allowlisted through its Merkle root!")]
+    InvalidUserType,
+
+    #[msg("Error: Public round is locked once it has started!")]
+    PublicRoundLocked,
 }
@@ -39,6 +39,15 @@ pub struct CreatorAdded {
     pub current_creators_count: u32,
 }
 
+/// Event emitted when the treasury ledger of an existing DAO is initialized
+#[event]
+pub struct TreasuryLedgerInitialized {
+    /// The token identifier of the DAO
+    pub token: String,
+    /// Escrow balance the ledger starts from
+    pub balance: u64,
+}
+
 /// Event emitted when user lists are modified
 #[event]
 pub struct ManageUsers {
@@ -151,6 +160,46 @@ pub struct UpdateSubAdmins {
     pub addresses: Vec<Pubkey>,
 }
 
+/// Event emitted when an approved `UpdateOwner` proposal nominates a new owner
+#[event]
+pub struct OwnershipTransferStarted {
+    /// Current owner
+    pub owner: Pubkey,
+    /// Nominated owner
+    pub pending_owner: Pubkey,
+    /// Timestamp until which the nomination can be accepted
+    pub deadline: i64,
+}
+
+/// Event emitted when the nominated owner accepts the ownership
+#[event]
+pub struct OwnershipTransferAccepted {
+    /// Previous owner
+    pub previous_owner: Pubkey,
+    /// New owner
+    pub owner: Pubkey,
+}
+
+/// Event emitted when the owner cancels a pending ownership transfer
+#[event]
+pub struct OwnershipTransferCancelled {
+    /// Owner who cancelled the transfer
+    pub owner: Pubkey,
+    /// Nominated owner whose nomination was cancelled
+    pub pending_owner: Pubkey,
+}
+
+/// Event emitted when a time-bounded or DAO-scoped sub-admin grant is added
+#[event]
+pub struct SubAdminGranted {
+    /// Address the role is granted to
+    pub address: Pubkey,
+    /// Mint of the DAO the grant is scoped to, if any
+    pub mint: Option<Pubkey>,
+    /// Timestamp at which the grant expires, if any
+    pub expires_at: Option<i64>,
+}
+
 /// Event emitted when fee structure is updated
 #[event]
 pub struct UpdateFees {
@@ -180,6 +229,17 @@ pub struct FeeUpdated {
     pub to: u32,
 }
 
+/// Event emitted when the default fee of a creator is updated
+#[event]
+pub struct CreatorFeeUpdated {
+    /// Creator address
+    pub creator: Pubkey,
+    /// Previous fee percentage
+    pub from: u32,
+    /// New fee percentage
+    pub to: u32,
+}
+
 /// Event emitted when fee collection account is updated
 #[event]
 pub struct FeeAccountUpdated {
@@ -236,6 +296,57 @@ pub struct CreatorUnblocked {
 pub struct UserBlacklisted {
     /// User Address
     pub user: Pubkey,
+    /// Mint of the DAO the block applies to, all DAOs if none
+    pub mint: Option<Pubkey>,
+    /// Timestamp at which the block is lifted, if any
+    pub blocked_until: Option<i64>,
+    /// Reason code of the block
+    pub reason: u16,
+}
+
+/// Event emitted when the Merkle root of a round is published or cleared
+#[event]
+pub struct MerkleRootUpdated {
+    /// Token name
+    pub token: String,
+    /// Round of the allowlist
+    pub user_type: UserType,
+    /// New root, none if cleared
+    pub root: Option<[u8; 32]>,
+}
+
+/// Event emitted when the blacklist policy of a DAO is updated
+#[event]
+pub struct BlacklistPolicyUpdated {
+    /// Mint of the DAO
+    pub token: Pubkey,
+    /// New policy
+    pub policy: BlacklistPolicy,
+}
+
+/// Event emitted when blacklist entries are moved into marker accounts
+#[event]
+pub struct BlacklistMigrated {
+    /// Mint of the DAO of the migrated blacklist, global if none
+    pub mint: Option<Pubkey>,
+    /// Number of entries migrated
+    pub migrated: u32,
+    /// Number of entries left to migrate
+    pub remaining: u32,
+}
+
+/// Event emitted when the details of a creator are rewritten with their current layout
+#[event]
+pub struct CreatorInfoMigrated {
+    /// Creator whose details were migrated
+    pub creator: Pubkey,
+}
+
+/// Event emitted when the global configuration is rewritten with its current layout
+#[event]
+pub struct GlobalConfigMigrated {
+    /// Owner who migrated the configuration
+    pub owner: Pubkey,
 }
 
 /// Event emitted when user is unblocked
@@ -243,6 +354,8 @@ pub struct UserBlacklisted {
 pub struct UserUnblocked {
     /// User Address
     pub user: Pubkey,
+    /// Mint of the DAO the block applied to, all DAOs if none
+    pub mint: Option<Pubkey>,
 }
 
 #[event]
@@ -260,3 +373,288 @@ pub struct WithdrawalToContract {
 /// Event emitted when account is closed
 #[event]
 pub struct AccountReset {}
+
+/// Event emitted when a custom instruction proposal is executed
+#[event]
+pub struct CustomProposalExecuted {
+    /// Proposal Id
+    pub proposal_id: u32,
+    /// Number of instructions invoked
+    pub instructions: u32,
+}
+
+/// Event emitted when token holder governance is initialized for a DAO
+#[event]
+pub struct GovernanceInitialized {
+    /// The token identifier
+    pub token: String,
+    /// Minimum share of the supply that must vote
+    pub quorum_percent: u32,
+    /// Minimum share of the cast votes in favour
+    pub approval_percent: u32,
+    /// Voting period in seconds
+    pub voting_period: i64,
+}
+
+/// Event emitted when tokens are deposited for voting
+#[event]
+pub struct GovernanceDeposit {
+    /// The token identifier
+    pub token: String,
+    /// Address of the voter
+    pub voter: Pubkey,
+    /// Amount deposited
+    pub amount: u64,
+}
+
+/// Event emitted when deposited tokens are withdrawn
+#[event]
+pub struct GovernanceWithdraw {
+    /// The token identifier
+    pub token: String,
+    /// Address of the voter
+    pub voter: Pubkey,
+    /// Amount withdrawn
+    pub amount: u64,
+}
+
+/// Event emitted when a token holder creates a community proposal
+#[event]
+pub struct CommunityProposalCreated {
+    /// The token identifier
+    pub token: String,
+    /// Community proposal Id
+    pub proposal_id: u32,
+    /// Type of the proposal
+    pub proposal_type: ProposalType,
+    /// Address of the proposer
+    pub created_by: Pubkey,
+}
+
+/// Event emitted when a vote is cast on a community proposal
+#[event]
+pub struct VoteCast {
+    /// The token identifier
+    pub token: String,
+    /// Community proposal Id
+    pub proposal_id: u32,
+    /// Address of the voter
+    pub voter: Pubkey,
+    /// Whether the vote is in favour
+    pub support: bool,
+    /// Voting weight applied
+    pub weight: u64,
+}
+
+/// Event emitted when a community proposal is finalized
+#[event]
+pub struct CommunityProposalFinalized {
+    /// The token identifier
+    pub token: String,
+    /// Community proposal Id
+    pub proposal_id: u32,
+    /// Whether the proposal passed
+    pub approved: bool,
+    /// Id of the executable proposal created when passed
+    pub executable_proposal_id: Option<u32>,
+}
+
+/// Event emitted when guardians are modified
+#[event]
+pub struct UpdateGuardians {
+    /// Type of update (Add/Remove)
+    pub update_type: UpdateType,
+    /// List of affected guardian addresses
+    pub addresses: Vec<Pubkey>,
+}
+
+/// Event emitted when actions are paused
+#[event]
+pub struct Paused {
+    /// Paused mint, or none when paused globally
+    pub mint: Option<Pubkey>,
+    /// Guardian that paused
+    pub paused_by: Pubkey,
+}
+
+/// Event emitted when actions are resumed
+#[event]
+pub struct Unpaused {
+    /// Unpaused mint, or none when unpaused globally
+    pub mint: Option<Pubkey>,
+    /// Proposal Id
+    pub proposal_id: u32,
+}
+
+/// Event emitted when the permissions of an address are updated
+#[event]
+pub struct PermissionsUpdated {
+    /// Address the permissions are granted to
+    pub address: Pubkey,
+    /// Previous permission bitmask
+    pub from: u64,
+    /// New permission bitmask
+    pub to: u64,
+}
+
+/// Event emitted when the sale tiers of a DAO are set
+#[event]
+pub struct SaleTiersUpdated {
+    /// Token name
+    pub token: String,
+    /// Tiers of the sale, ordered by time
+    pub tiers: Vec<SaleTier>,
+}
+
+/// Event emitted when the public round of a DAO is configured
+#[event]
+pub struct PublicRoundUpdated {
+    /// Token name
+    pub token: String,
+    /// Maximum commitment of a wallet not listed in `Users`
+    pub per_wallet_cap: u64,
+    /// Whether commitments are limited to one per transaction
+    pub single_commitment: bool,
+}
+
+/// Event emitted when public round is started
+#[event]
+pub struct PublicRoundStarted {
+    /// Token name
+    pub token: String,
+}
+
+/// Event emitted when listed users are updated, removed or moved
+#[event]
+pub struct UsersUpdated {
+    /// The token identifier
+    pub token: String,
+    /// Type of user (VIP/Party) before the update
+    pub user_type: UserType,
+    /// Addresses of the updated users
+    pub addresses: Vec<Pubkey>,
+}
+
+/// Event emitted when the tokenomics bounds of new DAOs are updated
+#[event]
+pub struct TokenomicsBoundsUpdated {
+    /// New bounds, none if lifted
+    pub bounds: Option<TokenomicsBounds>,
+}
+
+/// Event emitted when the funds of a milestone are released to the creators
+#[event]
+pub struct MilestoneReleased {
+    /// Token name
+    pub token: String,
+    /// Index of the milestone
+    pub milestone: u8,
+    /// Amount of SOL released
+    pub amount: u64,
+}
+
+/// Event emitted when a milestone is voted failed and refunds start
+#[event]
+pub struct MilestoneFailed {
+    /// Token name
+    pub token: String,
+    /// Index of the milestone
+    pub milestone: u8,
+    /// Unreleased creator funds, refunded pro rata to committers
+    pub refundable: u64,
+}
+
+/// Event emitted when the stream period of a DAO is updated
+#[event]
+pub struct StreamPeriodUpdated {
+    /// Token name
+    pub token: String,
+    /// Streaming period in seconds, zero if payouts are paid at once
+    pub period: i64,
+}
+
+/// Event emitted when an approved payout is added to a stream
+#[event]
+pub struct PayoutStreamed {
+    /// Recipient of the stream
+    pub recipient: Pubkey,
+    /// Whether the recipient is paid as the creator or as a deployer
+    pub kind: PayoutKind,
+    /// Amount of SOL added
+    pub amount: u64,
+    /// Timestamp the stream ends at
+    pub end_at: i64,
+}
+
+/// Event emitted when a recipient withdraws from a stream
+#[event]
+pub struct StreamWithdrawn {
+    /// Recipient of the stream
+    pub recipient: Pubkey,
+    /// Whether the recipient is paid as the creator or as a deployer
+    pub kind: PayoutKind,
+    /// Amount of SOL withdrawn
+    pub amount: u64,
+}
+
+/// Event emitted when the rest of a stream is cancelled
+#[event]
+pub struct StreamCancelled {
+    /// Recipient of the stream
+    pub recipient: Pubkey,
+    /// Whether the recipient is paid as the creator or as a deployer
+    pub kind: PayoutKind,
+    /// Unvested amount of SOL cancelled
+    pub unvested: u64,
+}
+
+/// Event emitted when a committer burns claimed tokens to restore its refund
+#[event]
+pub struct ClaimedTokensBurned {
+    /// Token name
+    pub token: String,
+    /// Committer burning the tokens
+    pub user: Pubkey,
+    /// Amount of tokens burned
+    pub amount: u64,
+}
+
+/// Event emitted when the royalty withheld on transfers is collected into the DAO treasury
+#[event]
+pub struct TransferFeesCollected {
+    /// Token name
+    pub token: String,
+    /// Amount of tokens collected
+    pub amount: u64,
+}
+
+/// Event emitted when royalties are withdrawn from the DAO treasury through an approved proposal
+#[event]
+pub struct RoyaltiesWithdrawn {
+    /// Token name
+    pub token: String,
+    /// Recipient of the royalties
+    pub recipient: Pubkey,
+    /// Amount of tokens withdrawn, transfer fees included
+    pub amount: u64,
+}
+
+/// Event emitted when the metadata of a DAO token is updated through an approved proposal
+#[event]
+pub struct TokenMetadataUpdated {
+    /// Token name
+    pub token: String,
+    /// Proposal Id
+    pub proposal_id: u32,
+    /// New URI of the token, if updated
+    pub uri: Option<String>,
+}
+
+/// Event emitted when the metadata of a DAO token is locked for good
+#[event]
+pub struct MetadataLocked {
+    /// Token name
+    pub token: String,
+    /// Account locking the metadata
+    pub locked_by: Pubkey,
+}
@@ -29,30 +29,220 @@ pub fn calc_init_user_size() -> usize {
 ///
 /// # Returns
 /// * The total size in bytes needed for storing users after applying changes.
-/// * Accounts for both VIP and party users, along with newly added users.
-///
-/// TODO: Handle non-reallocation of space for some scenarios.
+/// * Accounts for both VIP and party users, along with newly added or removed users.
 pub fn calc_user_size(users: &Users, params: &structs::manage_users::Params) -> usize {
     use UpdateType::*;
 
+    let count = users.vip.len() + users.party.len();
     match params.manage_type {
-        Add => {
-            calc_init_user_size()
-                + (users.vip.len() + users.party.len() + params.users.len()) * size_of::<Pubkey>()
+        Add => calc_users_size(count + params.users.len()),
+        Remove => {
+            let addresses: Vec<Pubkey> = params.users.iter().map(|user| user.address).collect();
+            calc_users_size(count - users.count_listed(params.user_type, &addresses))
         }
-        _ => calc_init_user_size() + (users.vip.len() + users.party.len()) * size_of::<Pubkey>(),
+        _ => calc_users_size(count),
+    }
+}
+
+/// Calculates the required size for user storage after updating listed users.
+///
+/// # Arguments
+/// * `users` - Reference to the existing Users struct.
+/// * `params` - Parameters defining the update.
+///
+/// # Returns
+/// * The total size in bytes needed for storing users after applying the update.
+pub fn calc_updated_user_size(
+    users: &Users,
+    params: &structs::manage_users::UpdateParams,
+) -> usize {
+    let count = users.vip.len() + users.party.len();
+    match &params.update {
+        structs::manage_users::UserUpdate::RemoveByAddress(addresses) => {
+            calc_users_size(count - users.count_listed(params.user_type, addresses))
+        }
+        _ => calc_users_size(count),
+    }
+}
+
+/// Calculates the size of a `Users` account holding the given number of users.
+///
+/// # Arguments
+/// * `count` - Number of VIP and party users.
+///
+/// # Returns
+/// * The total size in bytes, each user taking the size of its `UserDetails`.
+pub fn calc_users_size(count: usize) -> usize {
+    calc_init_user_size() + count * size_of::<UserDetails>()
+}
+
+/// Loads a program account that may not have been created yet.
+///
+/// # Arguments
+/// * `account` - The seeds checked account.
+///
+/// # Returns
+/// * `Result<Option<T>>` - The account data, or none if it was never created.
+pub fn load_optional_account<T>(account: &AccountInfo) -> Result<Option<T>>
+where
+    T: AccountDeserialize + Owner,
+{
+    if account.data_is_empty() {
+        return Ok(None);
+    }
+
+    require_keys_eq!(
+        *account.owner,
+        T::owner(),
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+    let data = account.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+/// Resolves the fee percent applied to a DAO.
+///
+/// The creator's record of the DAO holds its fee, DAOs launched before records were
+/// kept per DAO falling back to the fee of their fund store.
+///
+/// # Arguments
+/// * `creator_dao_info` - The creator's record of the DAO, possibly uninitialised.
+/// * `fund_store_fee_percent` - Fee percent held by the fund store of the DAO.
+///
+/// # Returns
+/// * `u32` - The fee percent of the DAO.
+pub fn load_dao_fee_percent(
+    creator_dao_info: &AccountInfo,
+    fund_store_fee_percent: u32,
+) -> Result<u32> {
+    Ok(load_optional_account::<CreatorDaoInfo>(creator_dao_info)?
+        .map_or(fund_store_fee_percent, |record| record.fee_percent))
+}
+
+/// Derives the blacklist marker of an address, globally or in a DAO.
+///
+/// # Arguments
+/// * `user` - The blocked address.
+/// * `mint` - Mint of the DAO, or none for the global marker.
+///
+/// # Returns
+/// * `(Pubkey, u8)` - The marker address and its bump.
+pub fn find_blacklist_entry_address(user: &Pubkey, mint: Option<&Pubkey>) -> (Pubkey, u8) {
+    match mint {
+        Some(mint) => Pubkey::find_program_address(
+            &[BLACKLIST_ENTRY_TAG, mint.as_ref(), user.as_ref()],
+            &crate::ID,
+        ),
+        None => Pubkey::find_program_address(&[BLACKLIST_ENTRY_TAG, user.as_ref()], &crate::ID),
     }
 }
 
-/// Calculates the required size for blacklist storage after updates.
+/// Checks whether a blacklist marker, or the legacy list of the same scope, blocks
+/// the user at the given time.
+///
+/// Legacy lists are checked until `migrate_blacklist` has drained them into markers.
 ///
 /// # Arguments
-/// * `blacklist` - Reference to the existing Users struct.
+/// * `user` - The address to check.
+/// * `marker` - The seeds checked marker account of the user.
+/// * `legacy_blacklist` - The seeds checked legacy `Blacklist` of the same scope.
+/// * `now` - Current timestamp.
 ///
 /// # Returns
-/// * The total size in bytes needed for storing blacklist users after applying changes.
-pub fn calc_blacklist_size(blacklist: &Blacklist) -> usize {
-    size_of::<Blacklist>() + (blacklist.users.len() + 1) * size_of::<Pubkey>() + 8
+/// * `Result<bool>` - `true` if an active marker or legacy entry blocks the user.
+pub fn is_blocked_by(
+    user: &Pubkey,
+    marker: &AccountInfo,
+    legacy_blacklist: &AccountInfo,
+    now: i64,
+) -> Result<bool> {
+    if load_optional_account::<BlacklistEntry>(marker)?.map_or(false, |entry| entry.is_active(now))
+    {
+        return Ok(true);
+    }
+
+    Ok(load_optional_account::<Blacklist>(legacy_blacklist)?
+        .map_or(false, |blacklist| blacklist.is_blocked(user)))
+}
+
+/// Ensures a user may perform the action under the blacklist policy of the DAO.
+///
+/// # Arguments
+/// * `user` - The address performing the action.
+/// * `blacklist_entry` - Marker of the user blocked in every DAO.
+/// * `dao_blacklist_entry` - Marker of the user blocked in the DAO.
+/// * `legacy_blacklists` - The legacy global and DAO `Blacklist` accounts, in order.
+/// * `blacklist_policy` - Blacklist policy account of the DAO, `RefundsOnly` while absent.
+/// * `action` - The action performed.
+/// * `now` - Current timestamp.
+///
+/// # Returns
+/// * `Result<()>` - An error if the user is blocked and the policy rejects the action.
+pub fn require_allowed_by_blacklist(
+    user: &Pubkey,
+    blacklist_entry: &AccountInfo,
+    dao_blacklist_entry: &AccountInfo,
+    legacy_blacklists: [&AccountInfo; 2],
+    blacklist_policy: &AccountInfo,
+    action: BlacklistAction,
+    now: i64,
+) -> Result<()> {
+    let [blacklist, dao_blacklist] = legacy_blacklists;
+    if !is_blocked_by(user, blacklist_entry, blacklist, now)?
+        && !is_blocked_by(user, dao_blacklist_entry, dao_blacklist, now)?
+    {
+        return Ok(());
+    }
+
+    let policy = load_optional_account::<BlacklistPolicyConfig>(blacklist_policy)?
+        .map_or(BlacklistPolicy::DEFAULT, |config| config.policy);
+    require!(policy.allows(action), CustomError::BlockedAccount);
+
+    Ok(())
+}
+
+/// Ensures none of the users is blocked, globally or in the DAO.
+///
+/// # Arguments
+/// * `users` - The addresses to check.
+/// * `mint` - Mint of the DAO.
+/// * `markers` - The global and DAO blacklist markers of each user, in order.
+/// * `legacy_blacklists` - The legacy global and DAO `Blacklist` accounts, in order.
+/// * `now` - Current timestamp.
+///
+/// # Returns
+/// * `Result<()>` - An error if a marker is missing or mismatched, or a user is blocked.
+pub fn require_not_blacklisted(
+    users: &[Pubkey],
+    mint: &Pubkey,
+    markers: &[AccountInfo],
+    legacy_blacklists: [&AccountInfo; 2],
+    now: i64,
+) -> Result<()> {
+    require!(
+        markers.len() == users.len() * 2,
+        CustomError::AccountMisMatch
+    );
+
+    for (user, markers) in users.iter().zip(markers.chunks(2)) {
+        for ((marker, legacy_blacklist), scope) in markers
+            .iter()
+            .zip(legacy_blacklists)
+            .zip([None, Some(mint)])
+        {
+            require_keys_eq!(
+                marker.key(),
+                find_blacklist_entry_address(user, scope).0,
+                CustomError::AccountMisMatch
+            );
+            require!(
+                !is_blocked_by(user, marker, legacy_blacklist, now)?,
+                CustomError::BlockedAccount
+            );
+        }
+    }
+
+    Ok(())
 }
 
 /// Calculates the size needed for global configuration storage.
@@ -67,12 +257,148 @@ pub fn calc_global_config_size(old: usize, new: usize) -> usize {
     size_of::<GlobalConfig>() + ((old + new) * 32) + 8
 }
 
+/// Resizes a program account, topping up its rent from the payer or refunding
+/// the excess to it.
+///
+/// # Arguments
+/// * `account` - The account to resize, owned by this program.
+/// * `payer` - The account paying for, or refunded, the rent difference.
+/// * `system_program` - The system program.
+/// * `size` - The new size of the account.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    size: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(size);
+    let current = account.lamports();
+
+    if required > current {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    } else {
+        **account.try_borrow_mut_lamports()? -= current - required;
+        **payer.try_borrow_mut_lamports()? += current - required;
+    }
+
+    account.realloc(size, false)?;
+
+    Ok(())
+}
+
+/// Creates a program derived account, even if its address was funded beforehand.
+///
+/// `create_account` fails on an address already holding lamports, which would let
+/// anyone block a predictable PDA by funding it, so the rent is topped up and the
+/// account allocated and assigned instead.
+///
+/// # Arguments
+/// * `account` - The uninitialized PDA to create.
+/// * `payer` - The account paying for the rent.
+/// * `system_program` - The system program.
+/// * `space` - The size of the account.
+/// * `owner` - The program owning the created account.
+/// * `signer_seeds` - The seeds of the PDA, including its bump.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let required = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if required > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        owner,
+    )?;
+
+    Ok(())
+}
+
 /// Calculates the size required for storing creator information.
 ///
+/// # Arguments
+/// * `daos` - Number of DAOs launched by the creator.
+///
 /// # Returns
 /// * The size in bytes required to store creator-related details.
-pub fn calc_creator_info_size() -> usize {
-    size_of::<CreatorInfo>() + 32
+pub fn calc_creator_info_size(daos: usize) -> usize {
+    8 + size_of::<CreatorInfo>() + 32 + daos * size_of::<Pubkey>()
+}
+
+/// Calculates the size required for storing the creator information of a DAO.
+///
+/// # Returns
+/// * The size in bytes required to store the creator's details for one DAO.
+pub fn calc_creator_dao_info_size() -> usize {
+    8 + size_of::<CreatorDaoInfo>() + 32
+}
+
+/// Calculates the size required for storing the fundraising data of a new DAO.
+///
+/// # Arguments
+/// * `params` - Parameters of the DAO.
+///
+/// # Returns
+/// * The total size in bytes needed for storing the fundraising data.
+pub fn calc_fund_data_store_size(params: &structs::create::Params) -> usize {
+    size_of::<FundDataStore>()
+        + 32
+        + params.co_creators.len() * size_of::<CoCreator>()
+        + params.milestones.len() * size_of::<Milestone>()
+}
+
+/// Calculates the size required for storing the sale tiers of a DAO.
+///
+/// # Arguments
+/// * `tiers` - Number of tiers.
+///
+/// # Returns
+/// * The total size in bytes needed for storing the tiers.
+pub fn calc_sale_tiers_size(tiers: usize) -> usize {
+    8 + 4 + tiers * size_of::<SaleTier>()
 }
 
 /// Calculates the size required for storing the creators list.
@@ -86,3 +412,473 @@ pub fn calc_creator_info_size() -> usize {
 pub fn calc_creators_size(old: usize) -> usize {
     size_of::<Creators>() + ((old + 1) * (size_of::<Pubkey>() + size_of::<bool>()))
 }
+
+/// Calculates the size required for storing the instructions of a custom instruction proposal.
+///
+/// # Arguments
+/// * `instructions` - Instructions to be stored.
+///
+/// # Returns
+/// * The total size needed, including the account discriminator and proposal id.
+pub fn calc_proposal_instructions_size(instructions: &[ProposalInstruction]) -> usize {
+    8 + size_of::<u32>()
+        + 4
+        + instructions
+            .iter()
+            .map(|ix| {
+                size_of::<Pubkey>()
+                    + 4
+                    + ix.accounts.len() * (size_of::<Pubkey>() + 2)
+                    + 4
+                    + ix.data.len()
+            })
+            .sum::<usize>()
+}
+
+/// Calculates the size required for storing token holder governance of a DAO.
+///
+/// # Arguments
+/// * `proposals` - The number of community proposals to store.
+///
+/// # Returns
+/// * The total size in bytes, including the account discriminator.
+pub fn calc_dao_governance_size(proposals: usize) -> usize {
+    8 + size_of::<DaoGovernance>() + (proposals * size_of::<CommunityProposal>())
+}
+
+/// Calculates the voting weight of a token holder at a given snapshot.
+///
+/// Community proposals are only created once fundraising is over, after which
+/// unclaimed commitment tokens can only be claimed, never added. Their current
+/// amount is therefore never above the amount at the snapshot, and claimed tokens
+/// deposited after it don't count either.
+///
+/// # Arguments
+/// * `commitments` - Commitments of the DAO, whose unclaimed tokens count towards the weight.
+/// * `voter_record` - Tokens deposited by the holder in the governance vault.
+/// * `address` - Address of the token holder.
+/// * `snapshot` - Timestamp of the snapshot.
+///
+/// # Returns
+/// * The voting weight of the holder.
+pub fn calc_voting_weight(
+    commitments: &Commitments,
+    voter_record: &VoterRecord,
+    address: &Pubkey,
+    snapshot: i64,
+) -> u64 {
+    commitments
+        .get_unclaimed_token_amount(address)
+        .saturating_add(voter_record.weight_at(snapshot))
+}
+
+/// Calculates the size required for storing the pause configuration.
+///
+/// # Arguments
+/// * `pause_config` - Reference to the existing pause configuration.
+/// * `new` - Number of new entries to add.
+///
+/// # Returns
+/// * Total size needed for storing paused mints and guardians.
+pub fn calc_pause_config_size(pause_config: &PauseConfig, new: usize) -> usize {
+    8 + size_of::<PauseConfig>()
+        + (pause_config.paused_mints.len() + pause_config.guardians.len() + new)
+            * size_of::<Pubkey>()
+}
+
+/// Ensures the caller holds the given permission through its role membership or
+/// an active sub-admin grant.
+///
+/// # Arguments
+/// * `role_membership` - Role membership of the caller, if it has one.
+/// * `global_config` - The global configuration holding the sub-admin grants.
+/// * `caller` - The public key of the caller.
+/// * `mint` - Mint of the DAO being acted on, if any.
+/// * `permission` - Permission flags from the `permissions` module.
+/// * `now` - Current timestamp.
+///
+/// # Returns
+/// * `Result<()>` - Returns an error if any of the flags is missing.
+pub fn require_permission(
+    role_membership: Option<&RoleMembership>,
+    global_config: &GlobalConfig,
+    caller: &Pubkey,
+    mint: Option<&Pubkey>,
+    permission: u64,
+    now: i64,
+) -> Result<()> {
+    let granted = role_membership.map_or(0, |membership| membership.permissions)
+        | global_config.grant_permissions(caller, mint, now);
+
+    require!(
+        granted & permission == permission,
+        CustomError::MissingPermission
+    );
+
+    Ok(())
+}
+
+/// Revokes every permission of an address that lost its admin or sub-admin role.
+///
+/// # Arguments
+/// * `role_membership` - The `[ROLE_TAG, address]` account, which may not exist.
+/// * `address` - The address whose role was removed.
+///
+/// # Returns
+/// * `Result<()>` - Returns an error if the account is not the role membership of the address.
+pub fn revoke_role_membership(role_membership: &AccountInfo, address: &Pubkey) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(&[ROLE_TAG, address.as_ref()], &crate::ID);
+    require_keys_eq!(
+        role_membership.key(),
+        expected,
+        CustomError::AccountMisMatch
+    );
+
+    // Nothing to revoke if the role was never migrated
+    if role_membership.owner != &crate::ID {
+        return Ok(());
+    }
+
+    let mut membership =
+        RoleMembership::try_deserialize(&mut &role_membership.try_borrow_data()?[..])?;
+    let from = membership.permissions;
+    membership.permissions = 0;
+    membership.try_serialize(&mut &mut role_membership.try_borrow_mut_data()?[..])?;
+
+    emit!(events::PermissionsUpdated {
+        address: *address,
+        from,
+        to: 0
+    });
+
+    Ok(())
+}
+
+/// Ensures the current instruction is a top-level `commitment`, and the only one of its transaction.
+///
+/// # Arguments
+/// * `instructions` - The instructions sysvar account.
+///
+/// # Returns
+/// * `Result<()>` - Returns an error if the commitment is made through another program,
+///   or alongside another commitment.
+pub fn require_single_commitment(instructions: &AccountInfo) -> Result<()> {
+    let is_commitment = |ix: &Instruction| {
+        ix.program_id == crate::ID
+            && ix
+                .data
+                .starts_with(&crate::instruction::Commitment::DISCRIMINATOR)
+    };
+
+    // A commitment made through another program shows that program at the top level
+    let current = tx_instructions::load_current_index_checked(instructions)? as usize;
+    let current_ix = tx_instructions::load_instruction_at_checked(current, instructions)?;
+    require!(is_commitment(&current_ix), CustomError::MultipleCommitments);
+
+    let mut index = 0;
+    while let Ok(ix) = tx_instructions::load_instruction_at_checked(index, instructions) {
+        require!(
+            index == current || !is_commitment(&ix),
+            CustomError::MultipleCommitments
+        );
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// Calculates the size required for storing the treasury ledger of a DAO.
+///
+/// # Returns
+/// * The total size in bytes needed for storing the totals and the most recent flows.
+pub fn calc_treasury_ledger_size() -> usize {
+    8 + 7 * size_of::<u64>()
+        + size_of::<u32>()
+        + MAX_LEDGER_ENTRIES * (1 + size_of::<u64>() + size_of::<u64>() + size_of::<i64>())
+}
+
+/// Records a SOL flow in the treasury ledger of a DAO, if the DAO has one.
+///
+/// # Arguments
+/// * `treasury_ledger` - The seeds checked treasury ledger account.
+/// * `kind` - Kind of the flow.
+/// * `amount` - Amount of SOL.
+pub fn record_treasury_flow(
+    treasury_ledger: &AccountInfo,
+    kind: LedgerEntryKind,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if let Some(mut ledger) = load_optional_account::<TreasuryLedger>(treasury_ledger)? {
+        ledger.record(kind, amount, Clock::get()?.unix_timestamp);
+        ledger.try_serialize(&mut &mut treasury_ledger.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
+
+/// Transfers an amount from the escrow to the creators, split by the co-creators' shares.
+///
+/// # Arguments
+/// * `fund_store` - Fundraising data of the DAO.
+/// * `withdraw_amount` - The amount to transfer.
+/// * `creator` - Account of the creator the amount was approved for.
+/// * `co_creators` - Accounts of the other co-creators.
+/// * `escrow_sol_account` - Escrow SOL account of the DAO.
+/// * `system_program` - The system program.
+/// * `signer` - Signer seeds of the escrow SOL account.
+pub fn transfer_to_creators<'info>(
+    fund_store: &FundDataStore,
+    withdraw_amount: u64,
+    creator: &AccountInfo<'info>,
+    co_creators: &[AccountInfo<'info>],
+    escrow_sol_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    for (address, amount) in fund_store.split_creator_amount(withdraw_amount, *creator.key)? {
+        // Find the recipient's account among the accounts passed
+        let recipient = if address.eq(creator.key) {
+            creator.clone()
+        } else {
+            co_creators
+                .iter()
+                .find(|account| account.key.eq(&address) && account.is_writable)
+                .ok_or(CustomError::AccountMisMatch)?
+                .clone()
+        };
+
+        // Prepare a Solana system program transfer instruction
+        let cpi_accounts = system_program::Transfer {
+            from: escrow_sol_account.clone(), // Source: Escrow account
+            to: recipient,                    // Destination: Recipient's account
+        };
+
+        // Execute the SOL transfer from the escrow account to the recipient
+        system_program::transfer(
+            CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer),
+            amount, // Amount of SOL to transfer
+        )?;
+
+        // Emit a withdrawal event for logging and tracking purposes
+        emit!(events::TransferredSolToCreator {
+            creator: address,
+            amount
+        });
+    }
+
+    Ok(())
+}
+
+/// Loads the stream of a recipient's payouts, creating the account on its first streamed payout.
+///
+/// # Arguments
+/// * `payout_stream` - Stream of the payouts approved for the recipient, possibly uninitialised.
+/// * `payer` - Account paying for the stream on its creation.
+/// * `system_program` - The system program.
+/// * `signer_seeds` - Seeds of the stream, with its bump.
+///
+/// # Returns
+/// * `Result<PayoutStream>` - The stream, empty on its creation.
+pub fn load_payout_stream<'info>(
+    payout_stream: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+) -> Result<PayoutStream> {
+    if let Some(stream) = load_optional_account::<PayoutStream>(payout_stream)? {
+        return Ok(stream);
+    }
+
+    create_pda_account(
+        payout_stream,
+        payer,
+        system_program,
+        8 + size_of::<PayoutStream>(),
+        &crate::ID,
+        signer_seeds,
+    )?;
+
+    Ok(PayoutStream {
+        recipient: Pubkey::default(),
+        kind: PayoutKind::Creator,
+        released: 0,
+        amount: 0,
+        start_at: 0,
+        end_at: 0,
+        withdrawn: 0,
+    })
+}
+
+/// Loads the creators recorded in the metadata of a DAO token.
+///
+/// Tokens of the legacy token program carry their creators in Metaplex metadata,
+/// while Token-2022 tokens carry them in their native metadata, the mint itself
+/// being passed as metadata account.
+///
+/// # Arguments
+/// * `metadata` - The Metaplex metadata account, or the Token-2022 mint.
+/// * `mint` - Mint of the DAO.
+///
+/// # Returns
+/// * `Result<Option<Vec<Pubkey>>>` - The creator addresses, or none if the metadata records none.
+pub fn load_token_creators(metadata: &AccountInfo, mint: &Pubkey) -> Result<Option<Vec<Pubkey>>> {
+    if metadata.key == mint {
+        require_keys_eq!(
+            *metadata.owner,
+            spl_token_2022::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let data = metadata.try_borrow_data()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let token_metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        return Ok(token_metadata
+            .additional_metadata
+            .iter()
+            .find(|(key, _)| key == CREATORS_METADATA_KEY)
+            .map(|(_, value)| parse_metadata_creators(value)));
+    }
+
+    let metadata = load_optional_account::<MetadataAccount>(metadata)?
+        .ok_or(ErrorCode::AccountNotInitialized)?;
+    require_keys_eq!(metadata.mint, *mint, CustomError::AccountMisMatch);
+    Ok(metadata
+        .creators
+        .as_ref()
+        .map(|creators| creators.iter().map(|creator| creator.address).collect()))
+}
+
+/// Checks whether the metadata of a DAO token can still be updated.
+///
+/// Metaplex metadata is locked once marked immutable, while the native metadata
+/// of Token-2022 tokens is locked once its update authority is removed.
+///
+/// # Arguments
+/// * `metadata` - The Metaplex metadata account, or the Token-2022 mint.
+/// * `mint` - Mint of the DAO.
+///
+/// # Returns
+/// * `Result<bool>` - Whether the metadata is still mutable.
+pub fn is_metadata_mutable(metadata: &AccountInfo, mint: &Pubkey) -> Result<bool> {
+    if metadata.key == mint {
+        require_keys_eq!(
+            *metadata.owner,
+            spl_token_2022::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let data = metadata.try_borrow_data()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let token_metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+        return Ok(Option::<Pubkey>::from(token_metadata.update_authority).is_some());
+    }
+
+    let metadata = load_optional_account::<MetadataAccount>(metadata)?
+        .ok_or(ErrorCode::AccountNotInitialized)?;
+    require_keys_eq!(metadata.mint, *mint, CustomError::AccountMisMatch);
+    Ok(metadata.is_mutable)
+}
+
+/// Calculates the length of a Token-2022 mint once given fields of its native metadata are updated.
+///
+/// # Arguments
+/// * `mint` - The Token-2022 mint storing its metadata.
+/// * `fields` - The fields updated, with their new values.
+///
+/// # Returns
+/// * `Result<usize>` - The length of the mint once updated, never below its current length.
+pub fn calc_native_metadata_len(mint: &AccountInfo, fields: &[(Field, String)]) -> Result<usize> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let mut token_metadata = state.get_variable_len_extension::<TokenMetadata>()?;
+    let old_size = token_metadata.tlv_size_of()?;
+    for (field, value) in fields {
+        token_metadata.update(field.clone(), value.clone());
+    }
+    Ok(mint.data_len() + token_metadata.tlv_size_of()?.saturating_sub(old_size))
+}
+
+/// Calculates the fee withheld when transferring tokens of a DAO mint.
+///
+/// # Arguments
+/// * `mint` - Mint of the DAO, of the legacy token program or Token-2022.
+/// * `amount` - The amount transferred.
+///
+/// # Returns
+/// * `Result<u64>` - The fee withheld, 0 unless the mint has a transfer fee.
+pub fn calc_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    calc_epoch_transfer_fee(mint, Clock::get()?.epoch, amount)
+}
+
+/// Calculates the fee withheld when transferring tokens of a mint during a given epoch.
+///
+/// # Arguments
+/// * `mint` - Mint of the legacy token program or Token-2022.
+/// * `epoch` - The epoch of the transfer.
+/// * `amount` - The amount transferred.
+///
+/// # Returns
+/// * `Result<u64>` - The fee withheld, 0 unless the mint has a transfer fee.
+pub fn calc_epoch_transfer_fee(mint: &AccountInfo, epoch: u64, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(CustomError::InvalidTransferFee)?),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Transfers tokens of a DAO mint, resolving the accounts of its transfer hook if any.
+///
+/// # Arguments
+/// * `token_program` - The token program of the mint.
+/// * `accounts` - The transfer accounts.
+/// * `additional_accounts` - Accounts required by the transfer hook, usually the remaining accounts.
+/// * `amount` - The amount transferred, fees withheld included.
+/// * `decimals` - Decimals of the mint.
+/// * `signer` - Signer seeds of the authority, if a PDA.
+///
+/// # Returns
+/// * `Result<()>` - Ok if the transfer succeeded.
+pub fn transfer_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    accounts: TransferChecked<'info>,
+    additional_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    spl_token_2022::onchain::invoke_transfer_checked(
+        token_program.key,
+        accounts.from,
+        accounts.mint,
+        accounts.to,
+        accounts.authority,
+        additional_accounts,
+        amount,
+        decimals,
+        signer,
+    )
+    .map_err(Into::into)
+}
+
+/// Checks whether an address is the governance PDA, signing the instructions of
+/// approved custom instruction proposals.
+///
+/// Executors signed by the governance PDA are already approved through the custom
+/// instruction proposal, and take the values they apply from their arguments.
+///
+/// # Arguments
+/// * `address` - The address to check.
+///
+/// # Returns
+/// * `bool` - Whether the address is the governance PDA.
+pub fn is_governance(address: &Pubkey) -> bool {
+    Pubkey::find_program_address(&[GOVERNANCE_TAG], &crate::ID)
+        .0
+        .eq(address)
+}
@@ -13,11 +13,15 @@ pub fn handler(ctx: Context<AddCreators>, params: structs::add_creator::Params)
     let creators = &mut ctx.accounts.creators;
     let creator_info = &mut ctx.accounts.creator_info;
 
-    // Ensure that the payer has sub-admin rights before modifying creators
-    require!(
-        global_config.is_sub_admin(ctx.accounts.payer.key),
-        CustomError::Unauthorized
-    );
+    // Ensure that the payer is allowed to add creators before modifying creators
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.payer.key,
+        None,
+        permissions::MANAGE_CREATORS,
+        Clock::get()?.unix_timestamp,
+    )?;
 
     // Ensure that the admin is not going to add as creator
     require!(
@@ -50,6 +54,13 @@ pub struct AddCreators<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
     /// The account storing the list of creators
     #[account(
         mut,
@@ -66,12 +77,12 @@ pub struct AddCreators<'info> {
         init_if_needed,
         seeds = [CREATOR_TAG, params.address.as_ref()],
         bump,
-        space = calc_creator_info_size(),
+        space = calc_creator_info_size(0),
         payer = payer,
     )]
     pub creator_info: Box<Account<'info, CreatorInfo>>,
 
-    /// The payer who is executing the transaction (must have sub-admin rights)
+    /// The payer who is executing the transaction (must hold the `MANAGE_CREATORS` permission)
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -37,13 +37,7 @@ pub fn handler(ctx: Context<BlacklistCreator>, _: String, proposal_id: u32) -> R
     let creators = &mut ctx.accounts.creators;
     creators.block(&creator, true);
 
-    if matches!(
-        fund_store.status,
-        Status::Created
-            | Status::FundraisingVip
-            | Status::FundraisingParty
-            | Status::FundraisingSuccess
-    ) {
+    if fund_store.status.is_open() || fund_store.status == Status::FundraisingSuccess {
         fund_store.update_status(Status::FundraisingFail)?;
     }
     proposal.execution_completed()?;
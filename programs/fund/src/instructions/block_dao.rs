@@ -10,6 +10,9 @@ use super::*;
 /// interact with those accounts during its execution.
 /// * `token`: The token name:
 ///
+/// The escrow left is recorded as a refund pool, committers being refunded pro rata since
+/// part of the escrow may already have been paid out or moved to the liquidity pool.
+///
 /// Returns:
 ///
 /// The `handler` function is returning a `Result<()>`, which indicates that it can return either
@@ -32,12 +35,35 @@ pub fn handler(ctx: Context<BlockDao>, token: String, proposal_id: u32) -> Resul
     );
     require!(proposal.is_approved(), CustomError::NotApproved);
     require!(!proposal.is_executed(), CustomError::AlreadyExecuted);
+    // Ensure the proposal targets the given token
+    require!(
+        proposal.dao_name.as_ref() == Some(&token),
+        CustomError::AccountMisMatch
+    );
     require!(
         global_config.is_owner(&ctx.accounts.payer.key()),
         CustomError::Unauthorized
     );
 
-    fund_store.update_status(Status::Closed)?;
+    // Ensure the DAO is not refunding every committer already, nor closed
+    require!(
+        !matches!(fund_store.status, Status::FundraisingFail | Status::Closed),
+        CustomError::InValidDaoStatus
+    );
+
+    // Refund the escrow left pro rata, part of it may have been paid out already
+    let refundable = ctx
+        .accounts
+        .escrow_sol_account
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    let total_commited_sols = load_optional_account::<Commitments>(&ctx.accounts.commitments)?
+        .map_or(0, |commitments| commitments.total_commited_sols);
+
+    fund_store.close_with_refunds(RefundPool {
+        sol_amount: refundable,
+        total_commited_sols,
+    })?;
     proposal.execution_completed()?;
 
     // Emit an event to notify the system that the dao has been blacklisted
@@ -73,6 +99,20 @@ pub struct BlockDao<'info> {
     )]
     pub fund_data_store: Box<Account<'info, FundDataStore>>,
 
+    /// CHECK: Escrow SOL account where committed SOL is stored
+    #[account(
+        seeds = [ESCROW_TAG, SOL_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_sol_account: AccountInfo<'info>,
+
+    /// CHECK: Commitments of the DAO, absent until commitments are initialised
+    #[account(
+        seeds = [COMMITMENT_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub commitments: AccountInfo<'info>,
+
     /// Mint account associated with the DPIT token.
     /// This is used for identifying the token related to the DAO.
     /// CHECK: This account is used for reference and does not require verification.
@@ -1,57 +1,130 @@
 use super::*;
 
-/// Function to manage (add/remove) users
-/// This function allows creators to add or remove VIP or Party users based on the given parameters.
+/// Function to block a user in every DAO once a `BlocklistUser` proposal is approved
 ///
 /// # Arguments
 /// * `ctx` - The program execution context containing all required accounts.
-/// * `params` - Parameters specifying user management actions (user type, action type, and user list).
+/// * `proposal_id` - Proposal Id.
+/// * `user` - Address blocked by the proposal.
 ///
 /// # Returns
 /// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
-pub fn handler(ctx: Context<BlacklistUser>, proposal_id: u32) -> Result<()> {
-    let proposals_list = &mut ctx.accounts.proposals_list;
+pub fn handler(ctx: Context<BlacklistUser>, proposal_id: u32, user: Pubkey) -> Result<()> {
     let global_config = &ctx.accounts.global_config;
 
-    let proposal = proposals_list
-        .proposals
-        .iter_mut()
-        .find(|p| p.id == proposal_id);
+    require!(
+        global_config.is_owner(&ctx.accounts.executer.key()),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::BlocklistUser)?;
 
-    require!(proposal.is_some(), CustomError::NotFound);
-    let proposal = proposal.unwrap();
+    // Scoped proposals are executed through `blacklist_dao_user`
     require!(
-        proposal.proposal_type == ProposalType::BlocklistUser,
+        proposal.dao_name.is_none(),
         CustomError::InvalidProposalType
     );
-    require!(proposal.is_approved(), CustomError::NotApproved);
-    require!(!proposal.is_executed(), CustomError::AlreadyExecuted);
+
+    let entry = &mut ctx.accounts.blacklist_entry;
+    block(entry, &proposal, user, &ctx.accounts.block_details)?;
+
+    // Emit event to log the user block action
+    emit!(events::UserBlacklisted {
+        user,
+        mint: None,
+        blocked_until: entry.blocked_until,
+        reason: entry.reason
+    });
+
+    Ok(())
+}
+
+/// Function to block a user in a single DAO once a `BlocklistUser` proposal scoped to it is approved
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+/// * `proposal_id` - Proposal Id.
+/// * `user` - Address blocked by the proposal.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn dao_handler(
+    ctx: Context<BlacklistDaoUser>,
+    token: String,
+    proposal_id: u32,
+    user: Pubkey,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
     require!(
         global_config.is_owner(&ctx.accounts.executer.key()),
         CustomError::Unauthorized
     );
 
-    // Marked as execution after check to handle rust borrowing
-    proposal.execution_completed()?;
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::BlocklistUser)?;
 
-    let user = proposal.address.ok_or(CustomError::AddressNotFound)?;
-    upgrade_account_size(
-        ctx.accounts.to_upgrade_account_ctx(),
-        calc_blacklist_size(&ctx.accounts.blacklist),
-    )?;
+    // Ensure the proposal is scoped to this DAO
+    require!(
+        proposal.dao_name.as_ref() == Some(&token),
+        CustomError::InvalidProposalType
+    );
 
-    let blacklist = &mut ctx.accounts.blacklist;
-    blacklist.add_user(user)?;
+    let entry = &mut ctx.accounts.blacklist_entry;
+    block(entry, &proposal, user, &ctx.accounts.block_details)?;
 
     // Emit event to log the user block action
-    emit!(events::UserBlacklisted { user });
+    emit!(events::UserBlacklisted {
+        user,
+        mint: Some(ctx.accounts.mint_account.key()),
+        blocked_until: entry.blocked_until,
+        reason: entry.reason
+    });
+
+    Ok(())
+}
+
+/// Fills the marker of the blocked user from the block details stored with the proposal.
+/// Proposals created before block details existed block permanently.
+fn block(
+    entry: &mut BlacklistEntry,
+    proposal: &ProposalData,
+    user: Pubkey,
+    block_details: &AccountInfo,
+) -> Result<()> {
+    // Ensure the proposal targets the given user
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(&user),
+        CustomError::AccountMisMatch
+    );
+
+    // A marker left over from an expired block is reused
+    require!(
+        entry.address.eq(&Pubkey::default()) || !entry.is_active(Clock::get()?.unix_timestamp),
+        CustomError::DuplicateUser
+    );
+
+    let block_details = load_optional_account::<BlockDetails>(block_details)?;
+
+    entry.address = user;
+    entry.blocked_until = block_details.as_ref().and_then(|d| d.blocked_until);
+    entry.reason = block_details.map_or(0, |d| d.reason);
 
     Ok(())
 }
 
-/// Struct defining the accounts required for managing users
+/// Struct defining the accounts required for blocking users in every DAO
 #[derive(Accounts)]
-#[instruction()]
+#[instruction(proposal_id: u32, user: Pubkey)]
 pub struct BlacklistUser<'info> {
     /// Reference to the global configuration account
     #[account(
@@ -67,13 +140,22 @@ pub struct BlacklistUser<'info> {
     )]
     pub proposals_list: Account<'info, ProposalsList>,
 
-    /// Account storing blacklist users
+    /// Marker of the user blocked in every DAO
     #[account(
-        mut,
-        seeds = [BLACKLIST_TAG],
+        init_if_needed,
+        seeds = [BLACKLIST_ENTRY_TAG, user.as_ref()],
+        bump,
+        payer = executer,
+        space = 8 + size_of::<BlacklistEntry>()
+    )]
+    pub blacklist_entry: Box<Account<'info, BlacklistEntry>>,
+
+    /// CHECK: Block details of the proposal, absent for proposals created before they existed
+    #[account(
+        seeds = [BLOCK_DETAILS_TAG, &proposal_id.to_le_bytes()],
         bump,
     )]
-    pub blacklist: Box<Account<'info, Blacklist>>,
+    pub block_details: AccountInfo<'info>,
 
     /// The executer responsible for the transaction
     #[account(mut)]
@@ -83,19 +165,52 @@ pub struct BlacklistUser<'info> {
     pub system_program: Program<'info, System>,
 }
 
-impl<'info> BlacklistUser<'info> {
-    pub fn to_upgrade_account_ctx(&self) -> Context<'_, '_, '_, 'info, UpgradeAccount<'info>> {
-        let upgrade = UpgradeAccount {
-            account: self.blacklist.to_account_info(),
-            payer: self.executer.clone(),
-            system_program: self.system_program.clone(),
-        };
-
-        Context::new(
-            &crate::ID,
-            Box::leak(Box::new(upgrade)), // Convert into a static reference
-            &[],
-            UpgradeAccountBumps {},
-        )
-    }
+/// Struct defining the accounts required for blocking users in a single DAO
+#[derive(Accounts)]
+#[instruction(token: String, proposal_id: u32, user: Pubkey)]
+pub struct BlacklistDaoUser<'info> {
+    /// Reference to the global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Marker of the user blocked in the DAO
+    #[account(
+        init_if_needed,
+        seeds = [BLACKLIST_ENTRY_TAG, mint_account.key().as_ref(), user.as_ref()],
+        bump,
+        payer = executer,
+        space = 8 + size_of::<BlacklistEntry>()
+    )]
+    pub blacklist_entry: Box<Account<'info, BlacklistEntry>>,
+
+    /// CHECK: Block details of the proposal, absent for proposals created before they existed
+    #[account(
+        seeds = [BLOCK_DETAILS_TAG, &proposal_id.to_le_bytes()],
+        bump,
+    )]
+    pub block_details: AccountInfo<'info>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The executer responsible for the transaction
+    #[account(mut)]
+    pub executer: Signer<'info>,
+
+    /// System program required for allocation and execution of instructions
+    pub system_program: Program<'info, System>,
 }
@@ -15,7 +15,7 @@ pub fn handler(ctx: Context<BurnTokens>, params: structs::burn::Params) -> Resul
     };
 
     // Burn the specified amount of tokens using the Solana Token Program
-    token::burn(
+    token_interface::burn(
         CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
         params.amount, // Amount of tokens to burn
     )?;
@@ -45,7 +45,7 @@ pub struct BurnTokens<'info> {
     #[account(mut)]
     pub authority: Signer<'info>, // The authority that can manage the token accounts
 
-    pub token_program: Program<'info, Token>, // Solana Token Program for handling SPL tokens
+    pub token_program: Interface<'info, TokenInterface>, // Solana Token Program for handling SPL tokens
 
     pub system_program: Program<'info, System>, // Solana System Program
 }
@@ -0,0 +1,85 @@
+use super::*;
+
+/// Function for a committer to burn claimed tokens of a stopped DAO, restoring their share of its refund
+///
+/// Refunds of a closed or refunding DAO are reduced by the share of tokens the
+/// committer already claimed. Burning those tokens back before claiming the
+/// refund restores the full share.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+/// * `amount` - The amount of claimed tokens to burn.
+pub fn handler(ctx: Context<BurnToRefund>, token: String, amount: u64) -> Result<()> {
+    let fund_store = &ctx.accounts.fund_data_store;
+    let caller = ctx.accounts.payer.key;
+
+    require!(
+        matches!(fund_store.status, Status::Closed | Status::Refunding),
+        CustomError::PermissionDenied
+    );
+
+    ctx.accounts
+        .commitments
+        .return_claimed_tokens(caller, amount)?;
+
+    // Burn the returned tokens from the committer's account
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint_account.to_account_info(),
+        from: ctx.accounts.from_ata.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+    };
+
+    token_interface::burn(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        amount,
+    )?;
+
+    emit!(events::ClaimedTokensBurned {
+        token,
+        user: *caller,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct BurnToRefund<'info> {
+    /// Commitments of the DAO
+    #[account(
+        mut,
+        seeds = [COMMITMENT_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub commitments: Box<Account<'info, Commitments>>,
+
+    /// Fund data store of the DAO
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Mint account of the DAO
+    #[account(
+        mut,
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token account of the committer the tokens are burned from
+    #[account(
+        mut,
+        token::mint = mint_account,
+        token::authority = payer,
+    )]
+    pub from_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Committer burning the tokens
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
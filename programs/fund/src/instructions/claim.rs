@@ -1,20 +1,38 @@
 use super::*;
 
 /// Function to claim tokens or SOL based on the fundraising status
-pub fn handler(ctx: Context<Claim>, token: String) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Claim<'info>>, token: String) -> Result<()> {
     let commitments = &mut ctx.accounts.commitments;
-    let fund_store = &ctx.accounts.fund_data_store;
+    let fund_store = &mut ctx.accounts.fund_data_store;
     let amount; // Variable to store claimable amount
     let sol_amount; // Variable to store SOL equivalent amount
 
     let mint_key = ctx.accounts.mint_account.key();
+
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts.pause_config.is_paused(&mint_key),
+        CustomError::Paused
+    );
     let day = Clock::get()
         .map_err(|_| CustomError::TimestampError)?
         .unix_timestamp;
+    let now = day;
 
     use Status::*;
     match fund_store.status {
-        FundraisingFail | Closed => {
+        FundraisingFail | Closed | Refunding => {
+            // Ensure the user is not blacklisted, unless the DAO's policy allows refunds
+            require_allowed_by_blacklist(
+                ctx.accounts.payer.key,
+                &ctx.accounts.blacklist_entry,
+                &ctx.accounts.dao_blacklist_entry,
+                [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+                &ctx.accounts.blacklist_policy,
+                BlacklistAction::ClaimRefund,
+                now,
+            )?;
+
             // Define signer seeds for SOL escrow account
             let seeds = &[
                 ESCROW_TAG,
@@ -30,13 +48,9 @@ pub fn handler(ctx: Context<Claim>, token: String) -> Result<()> {
                 to: ctx.accounts.payer.to_account_info(),
             };
 
-            // Determine the amount of SOL to claim
-            amount = commitments.claim_amount(
-                &ctx.accounts.payer.key,
-                AmountType::Sol,
-                &fund_store.vesting_percent,
-                day,
-            )?;
+            // Determine the refund, less the tokens already claimed and shared out of the pool left
+            amount = commitments
+                .claim_refund(ctx.accounts.payer.key, fund_store.refund_pool.as_mut())?;
             sol_amount = amount;
 
             // Execute the transfer
@@ -48,8 +62,24 @@ pub fn handler(ctx: Context<Claim>, token: String) -> Result<()> {
                 ),
                 amount,
             )?;
+            record_treasury_flow(
+                &ctx.accounts.treasury_ledger,
+                LedgerEntryKind::Refund,
+                amount,
+            )?;
         }
         FundraisingSuccess | Trade => {
+            // Ensure the user is not blacklisted, unless the DAO's policy allows claims
+            require_allowed_by_blacklist(
+                ctx.accounts.payer.key,
+                &ctx.accounts.blacklist_entry,
+                &ctx.accounts.dao_blacklist_entry,
+                [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+                &ctx.accounts.blacklist_policy,
+                BlacklistAction::ClaimTokens,
+                now,
+            )?;
+
             // Define signer seeds for token escrow account
             let seeds = &[
                 ESCROW_TAG,
@@ -60,36 +90,44 @@ pub fn handler(ctx: Context<Claim>, token: String) -> Result<()> {
             let signer = [&seeds[..]];
 
             // Transfer token from escrow account to user ATA account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.escrow_mint_ata.to_account_info(),
+                mint: ctx.accounts.mint_account.to_account_info(),
                 to: ctx.accounts.to_account.to_account_info(),
                 authority: ctx.accounts.escrow_mint_account.to_account_info(),
             };
 
             // Determine the amount of tokens to claim
-            amount = commitments.claim_amount(
+            let mint = ctx.accounts.mint_account.to_account_info();
+            let vested_amount = commitments.claim_amount(
                 &ctx.accounts.payer.key,
                 AmountType::Token,
                 &fund_store.vesting_percent,
                 day,
+                |amount| calc_transfer_fee(&mint, amount),
             )?;
-            sol_amount = (amount as u128 / fund_store.tokens_per_sol as u128) as u64;
 
             // Execute the token transfer
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    cpi_accounts,
-                    &signer,
-                ),
-                amount,
+            transfer_tokens(
+                &ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                ctx.remaining_accounts,
+                vested_amount,
+                ctx.accounts.mint_account.decimals,
+                &signer,
             )?;
+
+            // The user receives the tokens less the fee withheld on transfer, the fees
+            // being charged on what was received
+            amount = vested_amount - calc_transfer_fee(&mint, vested_amount)?;
+            sol_amount = commitments.get_sol_equivalent(ctx.accounts.payer.key, amount);
         }
         _ => return Err(CustomError::PermissionDenied.into()),
     }
 
     // Calculate fees based on the SOL equivalent of the claimed amount
-    let fees = calc_amount(sol_amount as u128, fund_store.fee_percent as u128);
+    let fee_percent = load_dao_fee_percent(&ctx.accounts.creator_dao_info, fund_store.fee_percent)?;
+    let fees = calc_amount(sol_amount as u128, fee_percent as u128);
 
     // Deduct fees if applicable
     if fees > 0 {
@@ -103,6 +141,7 @@ pub fn handler(ctx: Context<Claim>, token: String) -> Result<()> {
             fees,
         )?;
     }
+    record_treasury_flow(&ctx.accounts.treasury_ledger, LedgerEntryKind::Fee, fees)?;
 
     // Emit claim event
     emit!(events::Claim { token, amount });
@@ -111,17 +150,42 @@ pub fn handler(ctx: Context<Claim>, token: String) -> Result<()> {
 }
 
 /// Function to claim tokens or SOL based on the fundraising status for testing purpose
-pub fn test(ctx: Context<Claim>, token: String, day: i64) -> Result<()> {
+pub fn test<'info>(
+    ctx: Context<'_, '_, '_, 'info, Claim<'info>>,
+    token: String,
+    day: i64,
+) -> Result<()> {
     let commitments = &mut ctx.accounts.commitments;
-    let fund_store = &ctx.accounts.fund_data_store;
+    let fund_store = &mut ctx.accounts.fund_data_store;
     let amount; // Variable to store claimable amount
     let sol_amount; // Variable to store SOL equivalent amount
 
     let mint_key = ctx.accounts.mint_account.key();
 
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts.pause_config.is_paused(&mint_key),
+        CustomError::Paused
+    );
+
+    let now = Clock::get()
+        .map_err(|_| CustomError::TimestampError)?
+        .unix_timestamp;
+
     use Status::*;
     match fund_store.status {
-        FundraisingFail | Closed => {
+        FundraisingFail | Closed | Refunding => {
+            // Ensure the user is not blacklisted, unless the DAO's policy allows refunds
+            require_allowed_by_blacklist(
+                ctx.accounts.payer.key,
+                &ctx.accounts.blacklist_entry,
+                &ctx.accounts.dao_blacklist_entry,
+                [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+                &ctx.accounts.blacklist_policy,
+                BlacklistAction::ClaimRefund,
+                now,
+            )?;
+
             // Define signer seeds for SOL escrow account
             let seeds = &[
                 ESCROW_TAG,
@@ -137,13 +201,9 @@ pub fn test(ctx: Context<Claim>, token: String, day: i64) -> Result<()> {
                 to: ctx.accounts.payer.to_account_info(),
             };
 
-            // Determine the amount of SOL to claim
-            amount = commitments.claim_amount(
-                &ctx.accounts.payer.key,
-                AmountType::Sol,
-                &fund_store.vesting_percent,
-                day,
-            )?;
+            // Determine the refund, less the tokens already claimed and shared out of the pool left
+            amount = commitments
+                .claim_refund(ctx.accounts.payer.key, fund_store.refund_pool.as_mut())?;
             sol_amount = amount;
 
             // Execute the transfer
@@ -155,8 +215,24 @@ pub fn test(ctx: Context<Claim>, token: String, day: i64) -> Result<()> {
                 ),
                 amount,
             )?;
+            record_treasury_flow(
+                &ctx.accounts.treasury_ledger,
+                LedgerEntryKind::Refund,
+                amount,
+            )?;
         }
         FundraisingSuccess | Trade => {
+            // Ensure the user is not blacklisted, unless the DAO's policy allows claims
+            require_allowed_by_blacklist(
+                ctx.accounts.payer.key,
+                &ctx.accounts.blacklist_entry,
+                &ctx.accounts.dao_blacklist_entry,
+                [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+                &ctx.accounts.blacklist_policy,
+                BlacklistAction::ClaimTokens,
+                now,
+            )?;
+
             // Define signer seeds for token escrow account
             let seeds = &[
                 ESCROW_TAG,
@@ -167,36 +243,44 @@ pub fn test(ctx: Context<Claim>, token: String, day: i64) -> Result<()> {
             let signer = [&seeds[..]];
 
             // Transfer token from escrow account to user ATA account
-            let cpi_accounts = Transfer {
+            let cpi_accounts = TransferChecked {
                 from: ctx.accounts.escrow_mint_ata.to_account_info(),
+                mint: ctx.accounts.mint_account.to_account_info(),
                 to: ctx.accounts.to_account.to_account_info(),
                 authority: ctx.accounts.escrow_mint_account.to_account_info(),
             };
 
             // Determine the amount of tokens to claim
-            amount = commitments.claim_amount(
+            let mint = ctx.accounts.mint_account.to_account_info();
+            let vested_amount = commitments.claim_amount(
                 &ctx.accounts.payer.key,
                 AmountType::Token,
                 &fund_store.vesting_percent,
                 day,
+                |amount| calc_transfer_fee(&mint, amount),
             )?;
-            sol_amount = (amount as u128 / fund_store.tokens_per_sol as u128) as u64;
 
             // Execute the token transfer
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    cpi_accounts,
-                    &signer,
-                ),
-                amount,
+            transfer_tokens(
+                &ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                ctx.remaining_accounts,
+                vested_amount,
+                ctx.accounts.mint_account.decimals,
+                &signer,
             )?;
+
+            // The user receives the tokens less the fee withheld on transfer, the fees
+            // being charged on what was received
+            amount = vested_amount - calc_transfer_fee(&mint, vested_amount)?;
+            sol_amount = commitments.get_sol_equivalent(ctx.accounts.payer.key, amount);
         }
         _ => return Err(CustomError::PermissionDenied.into()),
     }
 
     // Calculate fees based on the SOL equivalent of the claimed amount
-    let fees = calc_amount(sol_amount as u128, fund_store.fee_percent as u128);
+    let fee_percent = load_dao_fee_percent(&ctx.accounts.creator_dao_info, fund_store.fee_percent)?;
+    let fees = calc_amount(sol_amount as u128, fee_percent as u128);
 
     // Deduct fees if applicable
     if fees > 0 {
@@ -210,6 +294,7 @@ pub fn test(ctx: Context<Claim>, token: String, day: i64) -> Result<()> {
             fees,
         )?;
     }
+    record_treasury_flow(&ctx.accounts.treasury_ledger, LedgerEntryKind::Fee, fees)?;
 
     // Emit claim event
     emit!(events::Claim { token, amount });
@@ -227,6 +312,48 @@ pub struct Claim<'info> {
     )]
     pub fee_account: Box<Account<'info, FeeAccount>>,
 
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    /// CHECK: Marker of the payer blocked in every DAO, absent unless blocked
+    #[account(
+        seeds = [BLACKLIST_ENTRY_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the payer blocked in the DAO, absent unless blocked
+    #[account(
+        seeds = [BLACKLIST_ENTRY_TAG, mint_account.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub dao_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Legacy global blacklist, checked until migrated into markers
+    #[account(
+        seeds = [BLACKLIST_TAG],
+        bump,
+    )]
+    pub blacklist: AccountInfo<'info>,
+
+    /// CHECK: Legacy blacklist of the DAO, checked until migrated into markers
+    #[account(
+        seeds = [BLACKLIST_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_blacklist: AccountInfo<'info>,
+
+    /// CHECK: Blacklist policy of the DAO, absent until set
+    #[account(
+        seeds = [BLACKLIST_POLICY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_policy: AccountInfo<'info>,
+
     /// CHECK: Escrow Sol Account holding SOL for refunds
     #[account(
         mut,
@@ -235,6 +362,14 @@ pub struct Claim<'info> {
     )]
     pub escrow_sol_account: AccountInfo<'info>,
 
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
     /// CHECK: Stores commitments for claimable amounts
     #[account(
         mut,
@@ -243,13 +378,21 @@ pub struct Claim<'info> {
     )]
     pub commitments: Box<Account<'info, Commitments>>,
 
-    /// Account that stores fund-related data
+    /// Account that stores fund-related data, whose refund pool is drawn on refunds
     #[account(
+        mut,
         seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
         bump,
     )]
     pub fund_data_store: Box<Account<'info, FundDataStore>>,
 
+    /// CHECK: Creator's record of the DAO holding its fee, absent for DAOs launched before it
+    #[account(
+        seeds = [CREATOR_TAG, fund_data_store.created_by.as_ref(), mint_account.key().as_ref()],
+        bump,
+    )]
+    pub creator_dao_info: AccountInfo<'info>,
+
     /// CHECK: Escrow account for holding minted tokens
     #[account(
         mut,
@@ -297,7 +440,7 @@ pub struct Claim<'info> {
     pub fees_collection_account: AccountInfo<'info>,
 
     /// Solana Token Program
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// Solana Associated Token Program
     pub associated_token_program: Program<'info, AssociatedToken>,
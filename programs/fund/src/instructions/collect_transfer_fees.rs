@@ -0,0 +1,113 @@
+use super::*;
+
+/// Function to collect the royalty withheld on transfers of a DAO token into the DAO treasury
+///
+/// The fees withheld in the token accounts passed as remaining accounts are first
+/// harvested to the mint, then every fee withheld in the mint is withdrawn to the
+/// treasury token account, held by the escrow SOL account of the DAO, and recorded
+/// in its treasury ledger. Anyone can collect the fees, withdrawn from the treasury
+/// through a `WithdrawRoyalties` proposal.
+///
+/// # Arguments
+/// * `ctx` - The execution context, the token accounts withholding fees being passed as remaining accounts.
+/// * `token` - Token name of the DAO.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectTransferFees<'info>>,
+    token: String,
+) -> Result<()> {
+    let mint = ctx.accounts.mint_account.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // Move the fees withheld in the token accounts to the mint
+    if !ctx.remaining_accounts.is_empty() {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                token_program.clone(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            ctx.remaining_accounts.to_vec(),
+        )?;
+    }
+
+    let seeds = &[MINT_TAG, token.as_bytes(), &[ctx.bumps.mint_account]];
+    let signer = [&seeds[..]];
+
+    // Withdraw the fees withheld in the mint to the treasury, signed by the mint PDA
+    let balance = ctx.accounts.treasury_token_account.amount;
+    withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+        token_program.clone(),
+        WithdrawWithheldTokensFromMint {
+            token_program_id: token_program,
+            mint: mint.clone(),
+            destination: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: mint,
+        },
+        &signer,
+    ))?;
+    ctx.accounts.treasury_token_account.reload()?;
+
+    let amount = ctx.accounts.treasury_token_account.amount - balance;
+    record_treasury_flow(
+        &ctx.accounts.treasury_ledger,
+        LedgerEntryKind::Royalty,
+        amount,
+    )?;
+
+    emit!(events::TransferFeesCollected { token, amount });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for collecting transfer fees.
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct CollectTransferFees<'info> {
+    /// CHECK: Escrow SOL account of the DAO, holding its treasury
+    #[account(
+        seeds = [ESCROW_TAG, SOL_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_sol_account: AccountInfo<'info>,
+
+    /// Treasury token account receiving the fees
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint_account,
+        associated_token::authority = escrow_sol_account,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
+    /// Mint account of the DAO, the withdraw authority of its transfer fees
+    #[account(
+        mut,
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Account paying for the treasury token account, if not created yet
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Solana Token-2022 Program
+    pub token_program: Program<'info, Token2022>,
+
+    /// Program for handling associated token accounts
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
@@ -1,19 +1,108 @@
 use super::*;
 
 /// Function to commit tokens to the fundraising pool
-pub fn handler(ctx: Context<Commitment>, token: String, sol_amount: u64) -> Result<()> {
+///
+/// Users allowlisted through a published Merkle root pass their allocation and
+/// proof, while users stored in `Users` pass none. If the DAO sells in tiers the
+/// commitment goes to the tier open at the time, at its price and caps, the tier
+/// being a round of its own with its own allowlist root. The public round, and
+/// any sale tier without a root, are open to every wallet, up to the round's
+/// per-wallet cap.
+pub fn handler(
+    ctx: Context<Commitment>,
+    token: String,
+    sol_amount: u64,
+    allocation: Option<structs::merkle_allocation::Params>,
+) -> Result<()> {
     let users = &mut ctx.accounts.users;
     let fund_store = &mut ctx.accounts.fund_data_store;
     let caller = &ctx.accounts.payer.key();
 
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts
+            .pause_config
+            .is_paused(&ctx.accounts.mint_account.key()),
+        CustomError::Paused
+    );
+
     // Ensure amount is greater than or equals to 0.1 sols
     require!(sol_amount.ge(&100000000), CustomError::MinimumAmountNotMet);
 
-    // Ensure the user is eligible to commit funds
-    require!(
-        users.is_eligible(caller, fund_store.status),
-        CustomError::InEligible
-    );
+    let now = Clock::get()?.unix_timestamp;
+
+    // Resolve the tier open at the time, if the DAO sells in tiers
+    let mut sale_tiers = load_optional_account::<SaleTiers>(&ctx.accounts.sale_tiers)?;
+    let active_tier = match &sale_tiers {
+        Some(sale_tiers) => Some(
+            sale_tiers
+                .active_tier(now)
+                .ok_or(CustomError::NoActiveSaleTier)?,
+        ),
+        None => None,
+    };
+    let allowlist = load_optional_account::<MerkleAllowlist>(&ctx.accounts.allowlist)?;
+
+    // A sale tier without an allowlist root is open to every wallet, like the public round
+    let is_public_tier = fund_store.status == Status::Fundraising
+        && MerkleAllowlist::current_round(fund_store.status, active_tier)
+            .and_then(|round| allowlist.as_ref()?.get_root(round))
+            .is_none();
+    let is_public = fund_store.status == Status::FundraisingPublic || is_public_tier;
+
+    // Load the public round configuration, required by the public round, applying its anti-bot rule
+    let public_round = if fund_store.status == Status::FundraisingPublic {
+        Some(
+            load_optional_account::<PublicRound>(&ctx.accounts.public_round)?
+                .ok_or(CustomError::PermissionDenied)?,
+        )
+    } else if is_public_tier {
+        load_optional_account::<PublicRound>(&ctx.accounts.public_round)?
+    } else {
+        None
+    };
+    if public_round
+        .as_ref()
+        .map_or(false, |public_round| public_round.single_commitment)
+    {
+        require_single_commitment(&ctx.accounts.instructions)?;
+    }
+
+    // Ensure the user is eligible to commit funds, resolving the proven allocation if any
+    let max_allowable_amount = match &allocation {
+        Some(allocation) => {
+            let allowlist = allowlist.as_ref().ok_or(CustomError::InEligible)?;
+            Some(allowlist.verify(caller, allocation, fund_store.status, active_tier)?)
+        }
+        None => {
+            require!(
+                is_public || users.is_eligible(caller, fund_store.status),
+                CustomError::InEligible
+            );
+            None
+        }
+    };
+
+    // Record the commitment in the active tier, if the DAO sells in tiers
+    let tokens_per_sol = match (sale_tiers.as_mut(), active_tier) {
+        (Some(sale_tiers), Some(tier)) => {
+            let mut tier_commitments = load_tier_commitments(
+                &ctx.accounts.tier_commitments,
+                &ctx.accounts.mint_account.key(),
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                ctx.bumps.tier_commitments,
+            )?;
+            let tokens_per_sol = sale_tiers.commit(tier, &mut tier_commitments, sol_amount)?;
+            sale_tiers
+                .try_serialize(&mut &mut ctx.accounts.sale_tiers.try_borrow_mut_data()?[..])?;
+            tier_commitments.try_serialize(
+                &mut &mut ctx.accounts.tier_commitments.try_borrow_mut_data()?[..],
+            )?;
+            tokens_per_sol
+        }
+        _ => fund_store.tokens_per_sol,
+    };
 
     let commitments = &mut ctx.accounts.commitments;
 
@@ -26,9 +115,16 @@ pub fn handler(ctx: Context<Commitment>, token: String, sol_amount: u64) -> Resu
         CustomError::PermissionDenied
     );
 
-    // Ensure the user is not blacklisted
-    let blacklist = &mut ctx.accounts.blacklist;
-    require!(!blacklist.is_blocked(caller), CustomError::BlockedAccount);
+    // Ensure the user is not blacklisted, unless the DAO's policy allows commitments
+    require_allowed_by_blacklist(
+        ctx.accounts.payer.key,
+        &ctx.accounts.blacklist_entry,
+        &ctx.accounts.dao_blacklist_entry,
+        [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+        &ctx.accounts.blacklist_policy,
+        BlacklistAction::Commit,
+        now,
+    )?;
 
     // Update fundraising status if the goal is reached
     if commitments.total_commited_sols == fund_store.fundraising_goal {
@@ -36,7 +132,8 @@ pub fn handler(ctx: Context<Commitment>, token: String, sol_amount: u64) -> Resu
     }
 
     // Calculate applicable fees
-    let fees = calc_amount(sol_amount as u128, fund_store.fee_percent as u128);
+    let fee_percent = load_dao_fee_percent(&ctx.accounts.creator_dao_info, fund_store.fee_percent)?;
+    let fees = calc_amount(sol_amount as u128, fee_percent as u128);
 
     // Deduct fees if applicable
     if fees > 0 {
@@ -50,16 +147,25 @@ pub fn handler(ctx: Context<Commitment>, token: String, sol_amount: u64) -> Resu
             fees,
         )?;
     }
+    record_treasury_flow(&ctx.accounts.treasury_ledger, LedgerEntryKind::Fee, fees)?;
 
     // Register the commitment event
-    let commit_event = commitments.add(token, *caller, sol_amount, fund_store.tokens_per_sol);
+    let commit_event = commitments.add(token, *caller, sol_amount, tokens_per_sol);
 
     // Get the total committed amount by the user
     let commited_amount = commitments.get_commitment_amount(caller);
 
     // Ensure the committed amount does not exceed the user's max allowable limit
     require!(
-        users.is_max_allowable_amount_reached(caller, commited_amount),
+        max_allowable_amount.map_or_else(
+            || users.is_max_allowable_amount_reached(caller, commited_amount)
+                || (is_public
+                    && public_round
+                        .as_ref()
+                        .map_or(true, |public_round| public_round
+                            .is_within_cap(commited_amount))),
+            |max_allowable_amount| commited_amount <= max_allowable_amount
+        ),
         CustomError::CommitAmountExceeded
     );
 
@@ -73,6 +179,11 @@ pub fn handler(ctx: Context<Commitment>, token: String, sol_amount: u64) -> Resu
         CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts),
         sol_amount,
     )?;
+    record_treasury_flow(
+        &ctx.accounts.treasury_ledger,
+        LedgerEntryKind::Commitment,
+        sol_amount,
+    )?;
 
     // Emit the commit token event
     emit!(commit_event);
@@ -80,6 +191,34 @@ pub fn handler(ctx: Context<Commitment>, token: String, sol_amount: u64) -> Resu
     Ok(())
 }
 
+/// Loads the commitments of a payer in each sale tier, creating the account on the
+/// payer's first tiered commitment.
+fn load_tier_commitments<'info>(
+    tier_commitments: &AccountInfo<'info>,
+    mint: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    bump: u8,
+) -> Result<SaleTierCommitments> {
+    if let Some(tier_commitments) = load_optional_account::<SaleTierCommitments>(tier_commitments)?
+    {
+        return Ok(tier_commitments);
+    }
+
+    create_pda_account(
+        tier_commitments,
+        payer,
+        system_program,
+        8 + size_of::<SaleTierCommitments>(),
+        &crate::ID,
+        &[SALE_TIER_TAG, mint.as_ref(), payer.key.as_ref(), &[bump]],
+    )?;
+
+    Ok(SaleTierCommitments {
+        amounts: [0; MAX_SALE_TIERS],
+    })
+}
+
 #[derive(Accounts)]
 #[instruction(token: String)]
 pub struct Commitment<'info> {
@@ -97,12 +236,81 @@ pub struct Commitment<'info> {
     )]
     pub users: Box<Account<'info, Users>>,
 
-    /// Account storing blacklist users
+    /// CHECK: Merkle roots of the DAO, absent until a root is published
+    #[account(
+        seeds = [ALLOWLIST_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub allowlist: AccountInfo<'info>,
+
+    /// CHECK: Sale tiers of the DAO, absent unless the DAO sells in tiers
+    #[account(
+        mut,
+        seeds = [SALE_TIER_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub sale_tiers: AccountInfo<'info>,
+
+    /// CHECK: Commitments of the payer in each sale tier, only created if the DAO sells in tiers
+    #[account(
+        mut,
+        seeds = [SALE_TIER_TAG, mint_account.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub tier_commitments: AccountInfo<'info>,
+
+    /// CHECK: Public round configuration of the DAO, absent until set
+    #[account(
+        seeds = [PUBLIC_ROUND_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub public_round: AccountInfo<'info>,
+
+    /// CHECK: Instructions sysvar, read by the public round's anti-bot rule
+    #[account(address = tx_instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    /// CHECK: Marker of the payer blocked in every DAO, absent unless blocked
+    #[account(
+        seeds = [BLACKLIST_ENTRY_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the payer blocked in the DAO, absent unless blocked
+    #[account(
+        seeds = [BLACKLIST_ENTRY_TAG, mint_account.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub dao_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Legacy global blacklist, checked until migrated into markers
     #[account(
         seeds = [BLACKLIST_TAG],
         bump,
     )]
-    pub blacklist: Box<Account<'info, Blacklist>>,
+    pub blacklist: AccountInfo<'info>,
+
+    /// CHECK: Legacy blacklist of the DAO, checked until migrated into markers
+    #[account(
+        seeds = [BLACKLIST_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_blacklist: AccountInfo<'info>,
+
+    /// CHECK: Blacklist policy of the DAO, absent until set
+    #[account(
+        seeds = [BLACKLIST_POLICY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_policy: AccountInfo<'info>,
 
     /// CHECK: Escrow SOL account where committed SOL is stored
     #[account(
@@ -112,6 +320,14 @@ pub struct Commitment<'info> {
     )]
     pub escrow_sol_account: AccountInfo<'info>,
 
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
     /// CHECK: Stores commitment details
     #[account(
         mut,
@@ -131,6 +347,13 @@ pub struct Commitment<'info> {
     )]
     pub fund_data_store: Box<Account<'info, FundDataStore>>,
 
+    /// CHECK: Creator's record of the DAO holding its fee, absent for DAOs launched before it
+    #[account(
+        seeds = [CREATOR_TAG, fund_data_store.created_by.as_ref(), mint_account.key().as_ref()],
+        bump,
+    )]
+    pub creator_dao_info: AccountInfo<'info>,
+
     /// CHECK: Mint account for the token being committed
     #[account(
         seeds = [MINT_TAG, token.as_bytes()],
@@ -5,19 +5,30 @@ use super::*;
 /// updates the necessary accounts, and initializes the token metadata.
 pub fn handler(ctx: Context<CreateToken>, params: structs::create::Params) -> Result<()> {
     let creators = &mut ctx.accounts.creators;
-    let creator_info = &mut ctx.accounts.creator_info;
     let caller = ctx.accounts.payer.key;
 
     // Ensure the creator has the necessary rights to create a token
     creators.is_creator(caller, None)?;
 
+    // Ensure no extension is requested, legacy mints supporting none
+    require!(
+        params.token_extensions.is_none(),
+        CustomError::TokenExtensionsUnsupported
+    );
+
     let dao_list = &mut ctx.accounts.dao_list;
     dao_list.add(params.name.to_string());
 
-    let fund_store = &mut ctx.accounts.fund_data_store;
-    // Add the new token details to the fund store
-    fund_store.add(&params, creator_info.fee_percent, *caller)?;
-    creator_info.token = params.name.to_string();
+    register_dao(
+        &mut ctx.accounts.creator_info,
+        &mut ctx.accounts.creator_dao_info,
+        &mut ctx.accounts.fund_data_store,
+        ctx.accounts.global_config.tokenomics_bounds.as_ref(),
+        *caller,
+        ctx.accounts.mint_account.key(),
+        &params,
+    )?;
+    let fund_store = &ctx.accounts.fund_data_store;
 
     // TODO: @Tarun Add fundRaise Date
     // Define metadata for the new token
@@ -26,11 +37,7 @@ pub fn handler(ctx: Context<CreateToken>, params: structs::create::Params) -> Re
         symbol: params.symbol,
         uri: params.uri,
         seller_fee_basis_points: 0,
-        creators: Some(vec![mpl_token_metadata::types::Creator {
-            address: *caller,
-            verified: false,
-            share: 100,
-        }]),
+        creators: Some(fund_store.metadata_creators()),
         collection: None,
         uses: None,
     };
@@ -74,9 +81,290 @@ pub fn handler(ctx: Context<CreateToken>, params: structs::create::Params) -> Re
     Ok(())
 }
 
+/// Function to create a new Token-2022 token
+/// This function ensures the creator has the rights to create a token,
+/// updates the necessary accounts, and initializes the token's native metadata,
+/// the creators being recorded in its `creators` field.
+pub fn handler_token_2022(
+    ctx: Context<CreateToken2022>,
+    params: structs::create::Params,
+) -> Result<()> {
+    let creators = &mut ctx.accounts.creators;
+    let caller = ctx.accounts.payer.key;
+
+    // Ensure the creator has the necessary rights to create a token
+    creators.is_creator(caller, None)?;
+
+    let extensions = params.token_extensions.unwrap_or_default();
+    extensions.validate()?;
+
+    let dao_list = &mut ctx.accounts.dao_list;
+    dao_list.add(params.name.to_string());
+
+    let mint_key = ctx.accounts.mint_account.key();
+    register_dao(
+        &mut ctx.accounts.creator_info,
+        &mut ctx.accounts.creator_dao_info,
+        &mut ctx.accounts.fund_data_store,
+        ctx.accounts.global_config.tokenomics_bounds.as_ref(),
+        *caller,
+        mint_key,
+        &params,
+    )?;
+
+    let creators = format_metadata_creators(&ctx.accounts.fund_data_store.metadata_creators());
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(mint_key))?,
+        mint: mint_key,
+        name: params.name.to_string(),
+        symbol: params.symbol.to_string(),
+        uri: params.uri.to_string(),
+        additional_metadata: vec![(CREATORS_METADATA_KEY.to_string(), creators.clone())],
+    };
+
+    let seeds = &[MINT_TAG, params.name.as_bytes(), &[ctx.bumps.mint_account]];
+    let signer = [&seeds[..]];
+
+    // Create the mint with its extensions, funding the rent of the metadata it stores
+    let mint_info = ctx.accounts.mint_account.to_account_info();
+    ctx.accounts.init_mint(
+        params.decimals,
+        &extensions,
+        token_metadata.tlv_size_of()?,
+        &signer,
+    )?;
+
+    token_metadata_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: mint_info.clone(),
+                mint_authority: ctx.accounts.payer.to_account_info(),
+                mint: mint_info.clone(),
+            },
+        ),
+        token_metadata.name,
+        token_metadata.symbol,
+        token_metadata.uri,
+    )?;
+
+    // Record the creators of the new token
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: mint_info,
+            },
+            &signer,
+        ),
+        Field::Key(CREATORS_METADATA_KEY.to_string()),
+        creators,
+    )?;
+
+    // Initialize the accounts the transfer hook resolves, before the first transfer
+    if extensions.transfer_hook {
+        ctx.accounts.init_extra_account_meta_list()?;
+    }
+
+    // Emit an event to indicate successful token creation
+    emit!(events::Create { name: params.name });
+
+    Ok(())
+}
+
+impl<'info> CreateToken2022<'info> {
+    /// Creates the mint account and initializes it with its extensions
+    ///
+    /// The mint points to itself for its metadata, and its PDA is the authority of its
+    /// transfer fee and transfer hook, if any.
+    ///
+    /// # Arguments
+    /// * `decimals` - Decimals of the mint.
+    /// * `extensions` - Token-2022 extensions of the mint.
+    /// * `metadata_size` - Size of the metadata later stored in the mint, funded upfront.
+    /// * `signer` - Signer seeds of the mint PDA.
+    fn init_mint(
+        &self,
+        decimals: u8,
+        extensions: &structs::token_extensions::Params,
+        metadata_size: usize,
+        signer: &[&[&[u8]]; 1],
+    ) -> Result<()> {
+        let mint = self.mint_account.to_account_info();
+        let token_program = self.token_program.to_account_info();
+        let mint_key = mint.key();
+
+        let mut extension_types = vec![ExtensionType::MetadataPointer];
+        if extensions.transfer_fee_bps > 0 {
+            extension_types.push(ExtensionType::TransferFeeConfig);
+        }
+        if extensions.transfer_hook {
+            extension_types.push(ExtensionType::TransferHook);
+        }
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+            &extension_types,
+        )?;
+
+        // Create the mint even if its address was already sent lamports
+        let payer = self.payer.to_account_info();
+        let system_program = self.system_program.to_account_info();
+        create_pda_account(
+            &mint,
+            &payer,
+            &system_program,
+            space,
+            &spl_token_2022::ID,
+            signer[0],
+        )?;
+
+        // Fund the rent of the metadata written into the mint once initialized
+        let metadata_rent = Rent::get()?
+            .minimum_balance(space + metadata_size)
+            .saturating_sub(mint.lamports());
+        if metadata_rent > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program,
+                    system_program::Transfer {
+                        from: payer,
+                        to: mint.clone(),
+                    },
+                ),
+                metadata_rent,
+            )?;
+        }
+
+        metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            Some(mint_key),
+            Some(mint_key),
+        )?;
+
+        // Withhold the royalty on transfers, withdrawn to the DAO treasury by the mint PDA
+        if extensions.transfer_fee_bps > 0 {
+            transfer_fee_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    TransferFeeInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: mint.clone(),
+                    },
+                ),
+                Some(&mint_key),
+                Some(&mint_key),
+                extensions.transfer_fee_bps,
+                extensions.maximum_transfer_fee,
+            )?;
+        }
+
+        // Check transfers against the blacklist
+        if extensions.transfer_hook {
+            transfer_hook_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    TransferHookInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: mint.clone(),
+                    },
+                ),
+                Some(mint_key),
+                Some(TRANSFER_HOOK_PROGRAM_ID),
+            )?;
+        }
+
+        token_interface::initialize_mint2(
+            CpiContext::new(token_program, InitializeMint2 { mint }),
+            decimals,
+            self.payer.key,
+            Some(self.payer.key),
+        )
+    }
+
+    /// Initializes the list of the accounts the transfer hook resolves on transfers of the mint
+    fn init_extra_account_meta_list(&self) -> Result<()> {
+        let extra_account_meta_list = self
+            .extra_account_meta_list
+            .as_ref()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        let transfer_hook_program = self
+            .transfer_hook_program
+            .as_ref()
+            .ok_or(ErrorCode::AccountNotEnoughKeys)?;
+
+        // The hook builds the list itself, the interface's list being left empty
+        let mut data = INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&0u32.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: TRANSFER_HOOK_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(extra_account_meta_list.key(), false),
+                AccountMeta::new_readonly(self.mint_account.key(), false),
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+            ],
+            data,
+        };
+
+        invoke(
+            &instruction,
+            &[
+                extra_account_meta_list.clone(),
+                self.mint_account.to_account_info(),
+                self.payer.to_account_info(),
+                self.system_program.to_account_info(),
+                transfer_hook_program.clone(),
+            ],
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Registers a new DAO in the fund store and under its creator
+fn register_dao(
+    creator_info: &mut CreatorInfo,
+    creator_dao_info: &mut CreatorDaoInfo,
+    fund_store: &mut FundDataStore,
+    tokenomics_bounds: Option<&TokenomicsBounds>,
+    caller: Pubkey,
+    mint: Pubkey,
+    params: &structs::create::Params,
+) -> Result<()> {
+    // Add the new token details to the fund store, with the creator's default fee
+    fund_store.add(params, creator_info.fee_percent, caller, tokenomics_bounds)?;
+
+    // Record the DAO under the creator
+    creator_info.add_dao(params.name.to_string(), mint);
+
+    creator_dao_info.creator = caller;
+    creator_dao_info.mint = mint;
+    creator_dao_info.token = params.name.to_string();
+    creator_dao_info.fee_percent = creator_info.fee_percent;
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(params: structs::create::Params)]
 pub struct CreateToken<'info> {
+    /// Global configuration holding the tokenomics bounds
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
     /// Account storing creator information
     #[account(
         mut,
@@ -90,9 +378,22 @@ pub struct CreateToken<'info> {
         mut,
         seeds = [CREATOR_TAG, payer.key().as_ref()],
         bump,
+        realloc = calc_creator_info_size(creator_info.daos.len() + 1),
+        realloc::payer = payer,
+        realloc::zero = false,
     )]
     pub creator_info: Box<Account<'info, CreatorInfo>>,
 
+    /// Account holding the creator data of the new DAO
+    #[account(
+        init,
+        seeds = [CREATOR_TAG, payer.key().as_ref(), mint_account.key().as_ref()],
+        bump,
+        space = calc_creator_dao_info_size(),
+        payer = payer,
+    )]
+    pub creator_dao_info: Box<Account<'info, CreatorDaoInfo>>,
+
     /// Mint account for the newly created token
     #[account(
         init,
@@ -115,7 +416,7 @@ pub struct CreateToken<'info> {
         init,
         seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
         bump,
-        space = size_of::<FundDataStore>() + 32,
+        space = calc_fund_data_store_size(&params),
         payer = payer,
     )]
     pub fund_data_store: Box<Account<'info, FundDataStore>>,
@@ -136,7 +437,7 @@ pub struct CreateToken<'info> {
     pub system_program: Program<'info, System>,
 
     /// Solana Token Program
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// Program for handling associated token accounts
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -147,3 +448,99 @@ pub struct CreateToken<'info> {
     /// Rent system variable
     pub rent: Sysvar<'info, Rent>,
 }
+
+#[derive(Accounts)]
+#[instruction(params: structs::create::Params)]
+pub struct CreateToken2022<'info> {
+    /// Global configuration holding the tokenomics bounds
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Account storing creator information
+    #[account(
+        mut,
+        seeds = [CREATOR_TAG],
+        bump,
+    )]
+    pub creators: Box<Account<'info, Creators>>,
+
+    /// Account holding specific creator data
+    #[account(
+        mut,
+        seeds = [CREATOR_TAG, payer.key().as_ref()],
+        bump,
+        realloc = calc_creator_info_size(creator_info.daos.len() + 1),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub creator_info: Box<Account<'info, CreatorInfo>>,
+
+    /// Account holding the creator data of the new DAO
+    #[account(
+        init,
+        seeds = [CREATOR_TAG, payer.key().as_ref(), mint_account.key().as_ref()],
+        bump,
+        space = calc_creator_dao_info_size(),
+        payer = payer,
+    )]
+    pub creator_dao_info: Box<Account<'info, CreatorDaoInfo>>,
+
+    /// CHECK: Mint account for the newly created token, holding its metadata, created with its extensions
+    #[account(
+        mut,
+        seeds = [MINT_TAG, params.name.as_bytes()],
+        bump,
+    )]
+    pub mint_account: AccountInfo<'info>,
+
+    /// Fund data store account to track token information
+    #[account(
+        init,
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+        space = calc_fund_data_store_size(&params),
+        payer = payer,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Token counter account to track the number of tokens created
+    #[account(
+        mut,
+        seeds = [DAO_TAG],
+        bump,
+    )]
+    pub dao_list: Box<Account<'info, DaoList>>,
+
+    /// CHECK: List of the accounts the transfer hook resolves on transfers, initialized
+    /// by the transfer hook program if the mint has a transfer hook
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNT_METAS_TAG, mint_account.key().as_ref()],
+        bump,
+        seeds::program = TRANSFER_HOOK_PROGRAM_ID,
+    )]
+    pub extra_account_meta_list: Option<AccountInfo<'info>>,
+
+    /// CHECK: Transfer hook program, required if the mint has a transfer hook
+    #[account(address = TRANSFER_HOOK_PROGRAM_ID)]
+    pub transfer_hook_program: Option<AccountInfo<'info>>,
+
+    /// Account paying for the transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+
+    /// Solana Token-2022 Program
+    pub token_program: Program<'info, Token2022>,
+
+    /// Program for handling associated token accounts
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Rent system variable
+    pub rent: Sysvar<'info, Rent>,
+}
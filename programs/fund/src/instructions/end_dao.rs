@@ -17,19 +17,21 @@ use super::*;
 /// The `handler` function is returning a `Result<()>`, which indicates that it can return either
 /// `Ok(())` if the operation is successful or an error if there is a problem during execution.
 pub fn handler(ctx: Context<EndDao>, token: String) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
     let fund_store = &mut ctx.accounts.fund_data_store;
 
-    // Ensure the payer has sub-admin rights before proceeding
-    require!(
-        global_config.is_sub_admin(ctx.accounts.payer.key),
-        CustomError::Unauthorized
-    );
+    // Ensure the payer is allowed to end DAOs before proceeding
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.payer.key,
+        Some(&ctx.accounts.mint_account.key()),
+        permissions::END_DAO,
+        Clock::get()?.unix_timestamp,
+    )?;
 
     // Ensure that the fund status is in the correct state for ending the dao
     require!(
-        fund_store.status.eq(&Status::FundraisingVip)
-            || fund_store.status.eq(&Status::FundraisingParty),
+        fund_store.status.is_fundraising(),
         CustomError::PermissionDenied
     );
 
@@ -52,13 +54,20 @@ pub fn handler(ctx: Context<EndDao>, token: String) -> Result<()> {
 #[derive(Accounts)]
 #[instruction(token: String)]
 pub struct EndDao<'info> {
-    /// Reference to the global configuration account
+    /// Global configuration account holding the sub-admin grants
     #[account(
         seeds = [GLOBAL_CONFIG_TAG],
         bump,
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
     /// Fund data store account.
     /// Stores details about the fundraising process, including the current status.
     #[account(
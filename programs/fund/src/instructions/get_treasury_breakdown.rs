@@ -0,0 +1,31 @@
+use super::*;
+
+/// Function to view where the escrowed SOL of a DAO went
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+///
+/// # Returns
+/// * `Result<TreasuryBreakdown>` - The running totals recorded by the treasury ledger.
+pub fn handler(ctx: Context<GetTreasuryBreakdown>, _token: String) -> Result<TreasuryBreakdown> {
+    Ok(ctx.accounts.treasury_ledger.breakdown)
+}
+
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct GetTreasuryBreakdown<'info> {
+    /// Treasury ledger of the DAO
+    #[account(
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: Box<Account<'info, TreasuryLedger>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+}
@@ -0,0 +1,154 @@
+use super::*;
+
+/// Function for a token holder to create a community proposal
+///
+/// Only `RemoveLiquidity`, `TransferSolToCreator` and `FailMilestone` proposals can
+/// be created by token holders, the amount of a `FailMilestone` proposal being the
+/// index of the milestone. The proposer's voting weight, including unclaimed commitment
+/// tokens, must meet the proposal threshold of the DAO.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `params` - The proposal type and amount.
+///
+/// # Returns
+/// * `Result<u32>` - The community proposal Id if successful, otherwise returns an error.
+pub fn handler(
+    ctx: Context<CreateCommunityProposal>,
+    params: structs::community_proposal::Params,
+) -> Result<u32> {
+    let fund_store = &ctx.accounts.fund_data_store;
+    let caller = ctx.accounts.signer.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let address = match params.proposal_type {
+        ProposalType::RemoveLiquidity => {
+            require!(
+                fund_store.status.eq(&Status::Trade),
+                CustomError::DaoNotInTrading
+            );
+            require!(
+                params.amount > 0 && params.amount <= 100000000,
+                CustomError::InvalidGovernanceParams
+            );
+            None
+        }
+        ProposalType::TransferSolToCreator => {
+            require!(
+                fund_store.status.eq(&Status::FundraisingSuccess)
+                    || fund_store.status.eq(&Status::Trade),
+                CustomError::InValidDaoStatus
+            );
+            require!(params.amount > 0, CustomError::AmountCannotBeZero);
+            fund_store.require_no_milestones()?;
+            fund_store.check_creator_withdrawl(params.amount)?;
+            Some(fund_store.created_by)
+        }
+        ProposalType::FailMilestone => {
+            require!(
+                params.amount <= u8::MAX as u64,
+                CustomError::InvalidMilestone
+            );
+            fund_store.check_milestone(params.amount)?;
+            None
+        }
+        _ => return Err(CustomError::InvalidProposalType.into()),
+    };
+
+    let governance = &mut ctx.accounts.dao_governance;
+
+    // Ensure the proposer holds enough voting weight
+    let weight = calc_voting_weight(
+        &ctx.accounts.commitments,
+        &ctx.accounts.voter_record,
+        &caller,
+        now,
+    );
+    let threshold = calc_amount(
+        ctx.accounts.mint_account.supply as u128,
+        governance.proposal_threshold_percent as u128,
+    );
+    require!(
+        weight > 0 && weight >= threshold,
+        CustomError::InsufficientVotingWeight
+    );
+
+    let proposal_id = governance.proposals.len() as u32 + 1;
+    let voting_ends_at = now + governance.voting_period;
+    governance.proposals.push(CommunityProposal {
+        id: proposal_id,
+        created_at: now,
+        created_by: caller,
+        proposal_type: params.proposal_type,
+        address,
+        transfer_amount: Some(params.amount),
+        voting_ends_at,
+        yes_votes: 0,
+        no_votes: 0,
+        status: ProposalStatus::Pending,
+        executable_proposal_id: None,
+        supply: ctx.accounts.mint_account.supply,
+    });
+
+    emit!(events::CommunityProposalCreated {
+        token: params.token,
+        proposal_id,
+        proposal_type: params.proposal_type,
+        created_by: caller,
+    });
+
+    Ok(proposal_id)
+}
+
+#[derive(Accounts)]
+#[instruction(params: structs::community_proposal::Params)]
+pub struct CreateCommunityProposal<'info> {
+    /// Governance account of the DAO
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_TAG, mint_account.key().as_ref()],
+        bump,
+        realloc = calc_dao_governance_size(dao_governance.proposals.len() + 1),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub dao_governance: Box<Account<'info, DaoGovernance>>,
+
+    /// Account tracking the proposer's deposit
+    #[account(
+        init_if_needed,
+        seeds = [VOTER_TAG, mint_account.key().as_ref(), signer.key().as_ref()],
+        bump,
+        payer = signer,
+        space = 8 + size_of::<VoterRecord>()
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    /// Stores commitment details, whose unclaimed tokens count as voting weight
+    #[account(
+        seeds = [COMMITMENT_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub commitments: Box<Account<'info, Commitments>>,
+
+    /// Stores fundraising data
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// CHECK: This is the mint account associated with the DAO
+    #[account(
+        seeds = [MINT_TAG, params.token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token holder creating the proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+/// Function to deposit tokens into the governance vault to gain voting weight
+///
+/// Deposits only count towards proposals created after them, a top up leaving
+/// the amount deposited before it counting towards the proposals in between.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token Name.
+/// * `amount` - Amount of tokens to deposit.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositGovernanceTokens<'info>>,
+    token: String,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, CustomError::AmountCannotBeZero);
+
+    // Transfer tokens from the voter to the governance vault
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.from_account.to_account_info(),
+        mint: ctx.accounts.mint_account.to_account_info(),
+        to: ctx.accounts.governance_vault.to_account_info(),
+        authority: ctx.accounts.voter.to_account_info(),
+    };
+
+    transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint_account.decimals,
+        &[],
+    )?;
+
+    // Only the tokens reaching the vault count, less the fee withheld on transfer
+    let amount = amount - calc_transfer_fee(&ctx.accounts.mint_account.to_account_info(), amount)?;
+
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.deposit(amount, Clock::get()?.unix_timestamp);
+
+    emit!(events::GovernanceDeposit {
+        token,
+        voter: ctx.accounts.voter.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct DepositGovernanceTokens<'info> {
+    /// Governance account of the DAO
+    #[account(
+        seeds = [DAO_GOVERNANCE_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_governance: Box<Account<'info, DaoGovernance>>,
+
+    /// Account tracking the voter's deposit
+    #[account(
+        init_if_needed,
+        seeds = [VOTER_TAG, mint_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+        payer = voter,
+        space = 8 + size_of::<VoterRecord>()
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    /// Vault holding the tokens deposited for voting
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = dao_governance,
+        associated_token::token_program = token_program,
+    )]
+    pub governance_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Voter's associated token account
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = voter,
+        associated_token::token_program = token_program,
+    )]
+    pub from_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is the mint account associated with the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token holder depositing the tokens
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// Solana Token Program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
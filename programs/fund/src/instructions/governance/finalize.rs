@@ -0,0 +1,111 @@
+use super::*;
+
+/// Function to finalize a community proposal once its voting period has ended
+///
+/// A passed proposal is added to the proposals list as approved, with the token
+/// holders as approvers, so it can be executed through the regular executors.
+/// Anyone can finalize a proposal.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token Name.
+/// * `proposal_id` - Community proposal Id.
+///
+/// # Returns
+/// * `Result<Option<u32>>` - The executable proposal Id if the proposal passed.
+pub fn handler(
+    ctx: Context<FinalizeCommunityProposal>,
+    token: String,
+    proposal_id: u32,
+) -> Result<Option<u32>> {
+    let now = Clock::get()?.unix_timestamp;
+    let governance = &mut ctx.accounts.dao_governance;
+
+    let proposal = governance
+        .proposals
+        .iter()
+        .find(|p| p.id == proposal_id)
+        .ok_or(CustomError::NotFound)?;
+
+    require!(
+        proposal.status == ProposalStatus::Pending,
+        CustomError::AlreadyExecuted
+    );
+    require!(
+        now >= proposal.voting_ends_at,
+        CustomError::VotingInProgress
+    );
+
+    let approved = proposal.has_passed(governance);
+
+    let mut executable_proposal_id = None;
+    if approved {
+        let proposals_list = &mut ctx.accounts.proposals_list;
+        let id = proposals_list.proposals.len() as u32 + 1;
+
+        proposals_list.add_proposal(ProposalData {
+            id,
+            created_at: now,
+            created_by: proposal.created_by,
+            proposal_type: proposal.proposal_type,
+            approver_threshold: 0,
+            address: proposal.address,
+            dao_name: Some(token.clone()),
+            transfer_amount: proposal.transfer_amount,
+            executor_type: ExecutorType::Admin,
+            approve_type: ApproverType::TokenHolders,
+            status: ProposalStatus::Approved { timestamp: now },
+            executed_at: None,
+            approvers: vec![],
+        })?;
+
+        executable_proposal_id = Some(id);
+    }
+
+    let proposal = governance.get_proposal_mut(proposal_id)?;
+    proposal.executable_proposal_id = executable_proposal_id;
+    proposal.status = if approved {
+        ProposalStatus::Approved { timestamp: now }
+    } else {
+        ProposalStatus::Rejected { timestamp: now }
+    };
+
+    emit!(events::CommunityProposalFinalized {
+        token,
+        proposal_id,
+        approved,
+        executable_proposal_id,
+    });
+
+    Ok(executable_proposal_id)
+}
+
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct FinalizeCommunityProposal<'info> {
+    /// Governance account of the DAO
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_governance: Box<Account<'info, DaoGovernance>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// CHECK: This is the mint account associated with the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The caller finalizing the proposal
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+/// Function to initialize token holder governance for a DAO
+///
+/// Sets the quorum, approval and proposal thresholds along with the voting period,
+/// and creates the vault holding tokens deposited for voting.
+/// Requires the `GOVERNANCE` permission.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `params` - The governance settings.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(
+    ctx: Context<InitGovernance>,
+    params: structs::init_governance::Params,
+) -> Result<()> {
+    // Ensure that the caller is allowed to initialize governance before proceeding
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.authority.key,
+        Some(&ctx.accounts.mint_account.key()),
+        permissions::GOVERNANCE,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    require!(
+        params.quorum_percent <= 100000000
+            && params.approval_percent > 0
+            && params.approval_percent <= 100000000
+            && params.proposal_threshold_percent <= 100000000
+            && params.voting_period > 0,
+        CustomError::InvalidGovernanceParams
+    );
+
+    let governance = &mut ctx.accounts.dao_governance;
+    governance.quorum_percent = params.quorum_percent;
+    governance.approval_percent = params.approval_percent;
+    governance.proposal_threshold_percent = params.proposal_threshold_percent;
+    governance.voting_period = params.voting_period;
+
+    emit!(events::GovernanceInitialized {
+        token: params.token,
+        quorum_percent: params.quorum_percent,
+        approval_percent: params.approval_percent,
+        voting_period: params.voting_period,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: structs::init_governance::Params)]
+pub struct InitGovernance<'info> {
+    /// Global configuration account holding the sub-admin grants
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, authority.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
+    /// Governance account of the DAO
+    #[account(
+        init,
+        seeds = [DAO_GOVERNANCE_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = authority,
+        space = calc_dao_governance_size(0)
+    )]
+    pub dao_governance: Box<Account<'info, DaoGovernance>>,
+
+    /// Vault holding the tokens deposited for voting
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint_account,
+        associated_token::authority = dao_governance,
+        associated_token::token_program = token_program,
+    )]
+    pub governance_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is the mint account associated with the DAO
+    #[account(
+        seeds = [MINT_TAG, params.token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The signer who must be a sub-admin to authorize this action
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Solana Token Program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Solana Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+pub mod create_proposal;
+pub mod deposit;
+pub mod finalize;
+pub mod init;
+pub mod vote;
+pub mod withdraw;
+
+pub use self::{create_proposal::*, deposit::*, finalize::*, init::*, vote::*, withdraw::*};
@@ -0,0 +1,119 @@
+use super::*;
+
+/// Function for a token holder to vote on a community proposal
+///
+/// The voting weight is taken at the proposal's creation snapshot, and the
+/// voter's deposit stays locked until the voting period ends.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token Name.
+/// * `proposal_id` - Community proposal Id.
+/// * `support` - Whether the vote is in favour.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(
+    ctx: Context<CastVote>,
+    token: String,
+    proposal_id: u32,
+    support: bool,
+) -> Result<()> {
+    let voter = ctx.accounts.voter.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let proposal = ctx.accounts.dao_governance.get_proposal_mut(proposal_id)?;
+    require!(proposal.is_voting_open(now), CustomError::VotingClosed);
+
+    let voter_record = &mut ctx.accounts.voter_record;
+    let weight = calc_voting_weight(
+        &ctx.accounts.commitments,
+        voter_record,
+        &voter,
+        proposal.created_at,
+    );
+    require!(weight > 0, CustomError::InsufficientVotingWeight);
+
+    if support {
+        proposal.yes_votes += weight;
+    } else {
+        proposal.no_votes += weight;
+    }
+
+    // Lock deposited tokens until voting ends
+    voter_record.locked_until = voter_record.locked_until.max(proposal.voting_ends_at);
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal_id = proposal_id;
+    vote_record.voter = voter;
+    vote_record.support = support;
+    vote_record.weight = weight;
+
+    emit!(events::VoteCast {
+        token,
+        proposal_id,
+        voter,
+        support,
+        weight,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token: String, proposal_id: u32)]
+pub struct CastVote<'info> {
+    /// Governance account of the DAO
+    #[account(
+        mut,
+        seeds = [DAO_GOVERNANCE_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_governance: Box<Account<'info, DaoGovernance>>,
+
+    /// Account tracking the voter's deposit
+    #[account(
+        init_if_needed,
+        seeds = [VOTER_TAG, mint_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+        payer = voter,
+        space = 8 + size_of::<VoterRecord>()
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    /// Vote of the voter on the proposal, which can only be created once
+    #[account(
+        init,
+        seeds = [
+            VOTE_TAG,
+            mint_account.key().as_ref(),
+            &proposal_id.to_le_bytes(),
+            voter.key().as_ref()
+        ],
+        bump,
+        payer = voter,
+        space = 8 + size_of::<VoteRecord>()
+    )]
+    pub vote_record: Box<Account<'info, VoteRecord>>,
+
+    /// Stores commitment details, whose unclaimed tokens count as voting weight
+    #[account(
+        seeds = [COMMITMENT_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub commitments: Box<Account<'info, Commitments>>,
+
+    /// CHECK: This is the mint account associated with the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token holder casting the vote
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,126 @@
+use super::*;
+
+/// Function to withdraw tokens deposited in the governance vault
+///
+/// Tokens remain locked until the voting period of every proposal the voter
+/// has voted on has ended.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token Name.
+/// * `amount` - Amount of tokens to withdraw.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawGovernanceTokens<'info>>,
+    token: String,
+    amount: u64,
+) -> Result<()> {
+    let voter_record = &mut ctx.accounts.voter_record;
+
+    require!(amount > 0, CustomError::AmountCannotBeZero);
+    require!(
+        voter_record.deposited_amount >= amount,
+        CustomError::ExceedsWithdrawLimit
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= voter_record.locked_until,
+        CustomError::TokensLocked
+    );
+
+    voter_record.withdraw(amount);
+
+    let mint_key = ctx.accounts.mint_account.key();
+
+    // Define signer seeds for the governance account
+    let seeds = &[
+        DAO_GOVERNANCE_TAG,
+        mint_key.as_ref(),
+        &[ctx.bumps.dao_governance],
+    ];
+    let signer = [&seeds[..]];
+
+    // Transfer tokens from the governance vault back to the voter
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.governance_vault.to_account_info(),
+        mint: ctx.accounts.mint_account.to_account_info(),
+        to: ctx.accounts.to_account.to_account_info(),
+        authority: ctx.accounts.dao_governance.to_account_info(),
+    };
+
+    transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint_account.decimals,
+        &signer,
+    )?;
+
+    emit!(events::GovernanceWithdraw {
+        token,
+        voter: ctx.accounts.voter.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct WithdrawGovernanceTokens<'info> {
+    /// Governance account of the DAO
+    #[account(
+        seeds = [DAO_GOVERNANCE_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_governance: Box<Account<'info, DaoGovernance>>,
+
+    /// Account tracking the voter's deposit
+    #[account(
+        mut,
+        seeds = [VOTER_TAG, mint_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    /// Vault holding the tokens deposited for voting
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = dao_governance,
+        associated_token::token_program = token_program,
+    )]
+    pub governance_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Voter's associated token account
+    #[account(
+        init_if_needed,
+        payer = voter,
+        associated_token::mint = mint_account,
+        associated_token::authority = voter,
+        associated_token::token_program = token_program,
+    )]
+    pub to_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is the mint account associated with the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token holder withdrawing the tokens
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// Solana Token Program
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Solana Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
@@ -35,14 +35,19 @@ pub fn is_admin(ctx: Context<HasRole>, address: &Pubkey) -> Result<bool> {
 /// # Arguments
 /// * `ctx` - The context containing the required accounts.
 /// * `address` - The public key of the user to check.
+/// * `mint` - Mint of the DAO being acted on, if any.
 ///
 /// # Returns
 /// * `Ok(true)` if the address is a sub-admin, otherwise `Ok(false)`.
-pub fn is_sub_admin(ctx: Context<HasRole>, address: &Pubkey) -> Result<bool> {
+pub fn is_sub_admin(
+    ctx: Context<HasRole>,
+    address: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> Result<bool> {
     let global_config = &mut ctx.accounts.global_config;
 
-    // Check if the provided address is listed as a sub-admin in the global configuration.
-    Ok(global_config.is_sub_admin(address))
+    // Check if the provided address is a sub-admin or holds an active grant for the DAO.
+    Ok(global_config.is_sub_admin(address, mint, Clock::get()?.unix_timestamp))
 }
 
 /// Checks if the given address has either admin or sub-admin role.
@@ -50,14 +55,39 @@ pub fn is_sub_admin(ctx: Context<HasRole>, address: &Pubkey) -> Result<bool> {
 /// # Arguments
 /// * `ctx` - The context containing the required accounts.
 /// * `address` - The public key of the user to check.
+/// * `mint` - Mint of the DAO being acted on, if any.
 ///
 /// # Returns
 /// * `Ok(true)` if the address is a sub-admin or sub-admin, otherwise `Ok(false)`.
-pub fn is_either_admin_or_sub_admin(ctx: Context<HasRole>, address: &Pubkey) -> Result<bool> {
+pub fn is_either_admin_or_sub_admin(
+    ctx: Context<HasRole>,
+    address: &Pubkey,
+    mint: Option<&Pubkey>,
+) -> Result<bool> {
     let global_config = &mut ctx.accounts.global_config;
 
     // Check if the provided address is listed as a sub-admin in the global configuration.
-    Ok(global_config.is_sub_admin(address) || global_config.is_admin(address))
+    Ok(
+        global_config.is_sub_admin(address, mint, Clock::get()?.unix_timestamp)
+            || global_config.is_admin(address),
+    )
+}
+
+/// Checks if the given address holds a permission.
+///
+/// # Arguments
+/// * `ctx` - The context containing the required accounts.
+/// * `address` - The public key of the user to check.
+/// * `permission` - Permission flags from the `permissions` module.
+///
+/// # Returns
+/// * `Ok(true)` if every flag is granted to the address, otherwise `Ok(false)`.
+pub fn has_permission(
+    ctx: Context<HasPermission>,
+    _address: &Pubkey,
+    permission: u64,
+) -> Result<bool> {
+    Ok(ctx.accounts.role_membership.has_permission(permission))
 }
 
 /// Struct representing the accounts required for checking admin or sub-admin roles.
@@ -71,3 +101,15 @@ pub struct HasRole<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 }
+
+/// Struct representing the accounts required for checking permissions.
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct HasPermission<'info> {
+    /// The role membership account of the address.
+    #[account(
+        seeds = [ROLE_TAG, address.as_ref()],
+        bump,
+    )]
+    pub role_membership: Box<Account<'info, RoleMembership>>,
+}
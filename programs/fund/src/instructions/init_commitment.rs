@@ -40,6 +40,16 @@ pub struct InitCommitment<'info> {
     )]
     pub commitments: Box<Account<'info, Commitments>>,
 
+    /// Treasury ledger recording the SOL flows of the DAO
+    #[account(
+        init,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = payer,
+        space = calc_treasury_ledger_size()
+    )]
+    pub treasury_ledger: Box<Account<'info, TreasuryLedger>>,
+
     /// CHECK: Escrow SOL Account that will hold the committed SOL funds
     #[account(
         mut,
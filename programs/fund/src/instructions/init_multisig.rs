@@ -4,13 +4,15 @@ use super::*;
 /// This function sets up user accounts and assigns a maximum allowable amount for commitments.
 /// It ensures that only authorized creators can initialize multisig.
 pub fn handler(ctx: Context<InitMultisig>) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
-
-    // Ensure that the caller has sub-admin rights before proceeding
-    require!(
-        global_config.is_sub_admin(&ctx.accounts.payer.key),
-        CustomError::Unauthorized
-    );
+    // Ensure that the caller is allowed to initialize the multisig before proceeding
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.payer.key,
+        None,
+        permissions::MULTISIG,
+        Clock::get()?.unix_timestamp,
+    )?;
     // MAX_PERMITTED_DATA_LENGTH
     // Emit an event indicating that multisig is initiated
     emit!(events::MultisigInitiated {});
@@ -21,13 +23,20 @@ pub fn handler(ctx: Context<InitMultisig>) -> Result<()> {
 #[derive(Accounts)]
 #[instruction()]
 pub struct InitMultisig<'info> {
-    /// Reference to the global configuration account
+    /// Global configuration account holding the sub-admin grants
     #[account(
         seeds = [GLOBAL_CONFIG_TAG],
         bump,
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
     /// Proposal List
     #[account(
         init,
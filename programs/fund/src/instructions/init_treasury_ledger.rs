@@ -0,0 +1,58 @@
+use super::*;
+
+/// Function to initialize the treasury ledger of a DAO created before ledgers were kept
+///
+/// The ledger starts from the escrow balance above its rent-exempt minimum, the flows
+/// before it not being recorded. Anyone can initialize the ledger of a DAO.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+pub fn handler(ctx: Context<InitTreasuryLedger>, token: String) -> Result<()> {
+    let escrow_sol_account = &ctx.accounts.escrow_sol_account;
+    let balance = escrow_sol_account
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(escrow_sol_account.data_len()));
+
+    let treasury_ledger = &mut ctx.accounts.treasury_ledger;
+    treasury_ledger.breakdown.balance = balance;
+
+    emit!(events::TreasuryLedgerInitialized { token, balance });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct InitTreasuryLedger<'info> {
+    /// Treasury ledger recording the SOL flows of the DAO
+    #[account(
+        init,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = payer,
+        space = calc_treasury_ledger_size()
+    )]
+    pub treasury_ledger: Box<Account<'info, TreasuryLedger>>,
+
+    /// CHECK: Escrow SOL Account holding the committed SOL funds
+    #[account(
+        seeds = [ESCROW_TAG, SOL_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_sol_account: SystemAccount<'info>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The payer account funding the ledger
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
@@ -10,6 +10,11 @@ pub fn handler(ctx: Context<Initialize>, fees_collection_account: Pubkey) -> Res
     let global_config = &mut ctx.accounts.global_config;
     global_config.save(caller);
 
+    // Grant every permission to the owner
+    let role_membership = &mut ctx.accounts.role_membership;
+    role_membership.address = caller;
+    role_membership.permissions = permissions::ALL;
+
     // Set the fees collection account in the fee account
     let fee_account = &mut ctx.accounts.fee_account;
     fee_account.fees_collection_account = fees_collection_account;
@@ -56,6 +61,16 @@ pub struct Initialize<'info> {
     )]
     pub dao_list: Box<Account<'info, DaoList>>,
 
+    /// Role membership of the owner
+    #[account(
+        init,
+        seeds = [ROLE_TAG, authority.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + size_of::<RoleMembership>()
+    )]
+    pub role_membership: Box<Account<'info, RoleMembership>>,
+
     /// The account that pays for the contract initialization
     #[account(mut)]
     pub authority: Signer<'info>,
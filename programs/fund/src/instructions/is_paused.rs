@@ -0,0 +1,20 @@
+use super::*;
+
+/// Function to check wheather actions on the mint are paused
+pub fn handler(ctx: Context<IsPaused>, mint: Pubkey) -> Result<()> {
+    // Ensure neither the mint nor the whole program is paused
+    let pause_config = &ctx.accounts.pause_config;
+    require!(!pause_config.is_paused(&mint), CustomError::Paused);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct IsPaused<'info> {
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+}
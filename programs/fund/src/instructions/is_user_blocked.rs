@@ -1,20 +1,59 @@
 use super::*;
 
-/// Function to check wheather the user is blocked
-pub fn handler(ctx: Context<IsUserBlocked>, address: Pubkey) -> Result<()> {
-    // Ensure the user is not blacklisted
-    let blacklist = &mut ctx.accounts.blacklist;
-    require!(!blacklist.is_blocked(&address), CustomError::BlockedAccount);
-
-    Ok(())
+/// Function to check wheather the user is blocked from the action, globally or in the DAO of the mint
+pub fn handler(
+    ctx: Context<IsUserBlocked>,
+    address: Pubkey,
+    _mint: Pubkey,
+    action: BlacklistAction,
+) -> Result<()> {
+    // Ensure the user is not blacklisted, unless the DAO's policy allows the action
+    require_allowed_by_blacklist(
+        &address,
+        &ctx.accounts.blacklist_entry,
+        &ctx.accounts.dao_blacklist_entry,
+        [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+        &ctx.accounts.blacklist_policy,
+        action,
+        Clock::get()?.unix_timestamp,
+    )
 }
 
 #[derive(Accounts)]
+#[instruction(address: Pubkey, mint: Pubkey)]
 pub struct IsUserBlocked<'info> {
-    /// Account storing blacklist users
+    /// CHECK: Marker of the user blocked in every DAO, absent unless blocked
+    #[account(
+        seeds = [BLACKLIST_ENTRY_TAG, address.as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the user blocked in the DAO, absent unless blocked
+    #[account(
+        seeds = [BLACKLIST_ENTRY_TAG, mint.as_ref(), address.as_ref()],
+        bump,
+    )]
+    pub dao_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Legacy global blacklist, checked until migrated into markers
     #[account(
         seeds = [BLACKLIST_TAG],
         bump,
     )]
-    pub blacklist: Box<Account<'info, Blacklist>>,
+    pub blacklist: AccountInfo<'info>,
+
+    /// CHECK: Legacy blacklist of the DAO, checked until migrated into markers
+    #[account(
+        seeds = [BLACKLIST_TAG, mint.as_ref()],
+        bump,
+    )]
+    pub dao_blacklist: AccountInfo<'info>,
+
+    /// CHECK: Blacklist policy of the DAO, absent until set
+    #[account(
+        seeds = [BLACKLIST_POLICY_TAG, mint.as_ref()],
+        bump,
+    )]
+    pub blacklist_policy: AccountInfo<'info>,
 }
@@ -1,7 +1,10 @@
 use super::*;
 
-/// Function to update the main admin of the contract
+/// Function to nominate a new main admin of the contract
 /// Only the current admin is authorized to make this change
+///
+/// The nominated key becomes the owner only once it calls `accept_ownership`
+/// within `OWNERSHIP_ACCEPTANCE_PERIOD`.
 pub fn update_owner(ctx: Context<UpdateGlobalConfig>, proposal_id: u32) -> Result<()> {
     let proposals_list = &mut ctx.accounts.proposals_list;
     let global_config = &mut ctx.accounts.global_config;
@@ -26,10 +29,75 @@ pub fn update_owner(ctx: Context<UpdateGlobalConfig>, proposal_id: u32) -> Resul
     require!(proposal.is_approved(), CustomError::NotApproved);
     require!(!proposal.is_executed(), CustomError::AlreadyExecuted);
 
-    // Update owner and mark as executed
-    global_config.set_owner(proposal.address.clone().unwrap());
+    let pending_owner = proposal.address.ok_or(CustomError::AddressNotFound)?;
+    let deadline = Clock::get()?.unix_timestamp + OWNERSHIP_ACCEPTANCE_PERIOD;
+
+    // Nominate the new owner and mark as executed
+    global_config.set_pending_owner(pending_owner, deadline);
     proposal.execution_completed()?;
 
+    emit!(events::OwnershipTransferStarted {
+        owner: global_config.owner,
+        pending_owner,
+        deadline
+    });
+
+    Ok(())
+}
+
+/// Function for the nominated owner to accept the ownership before the deadline
+///
+/// The new owner receives every permission, and the previous owner loses its role
+/// membership unless it remains an admin.
+pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let signer = ctx.accounts.signer.key();
+
+    let previous_owner = global_config.accept_owner(&signer, Clock::get()?.unix_timestamp)?;
+
+    if !global_config.is_admin(&previous_owner) {
+        revoke_role_membership(&ctx.accounts.previous_role_membership, &previous_owner)?;
+    }
+
+    let role_membership = &mut ctx.accounts.role_membership;
+    let from = role_membership.permissions;
+    role_membership.address = signer;
+    role_membership.permissions = permissions::ALL;
+
+    emit!(events::PermissionsUpdated {
+        address: signer,
+        from,
+        to: permissions::ALL
+    });
+
+    emit!(events::OwnershipTransferAccepted {
+        previous_owner,
+        owner: signer
+    });
+
+    Ok(())
+}
+
+/// Function to cancel a pending ownership transfer
+/// Only the current admin is authorized to perform this action
+pub fn cancel_ownership_transfer(ctx: Context<UpdateOwnership>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    require!(
+        global_config.is_owner(&ctx.accounts.signer.key()),
+        CustomError::Unauthorized
+    );
+
+    let pending_owner = global_config
+        .pending_owner
+        .take()
+        .ok_or(CustomError::NoPendingOwner)?;
+
+    emit!(events::OwnershipTransferCancelled {
+        owner: global_config.owner,
+        pending_owner: pending_owner.address
+    });
+
     Ok(())
 }
 
@@ -74,7 +142,7 @@ pub fn add_admin(ctx: Context<UpdateMaintainers>, proposal_id: u32) -> Result<()
     Ok(())
 }
 
-pub fn remove_admin(ctx: Context<UpdateGlobalConfig>, proposal_id: u32) -> Result<()> {
+pub fn remove_admin(ctx: Context<RemoveAdmin>, proposal_id: u32) -> Result<()> {
     let proposals_list = &mut ctx.accounts.proposals_list;
     let global_config = &mut ctx.accounts.global_config;
     let proposal = proposals_list
@@ -94,9 +162,13 @@ pub fn remove_admin(ctx: Context<UpdateGlobalConfig>, proposal_id: u32) -> Resul
     );
     require!(proposal.is_approved(), CustomError::NotApproved);
 
-    global_config.remove_admins(vec![proposal.address.clone().unwrap()]);
+    let address = proposal.address.ok_or(CustomError::AddressNotFound)?;
+    global_config.remove_admins(vec![address]);
     proposal.execution_completed()?;
 
+    // A removed admin must not keep the permissions of its role membership
+    revoke_role_membership(&ctx.accounts.role_membership, &address)?;
+
     Ok(())
 }
 
@@ -162,49 +234,122 @@ pub fn remove_deployer(ctx: Context<UpdateGlobalConfig>, proposal_id: u32) -> Re
     Ok(())
 }
 
-/// Function to add new sub-admins
+/// Function to add a new sub-admin once an `AddSubAdmin` proposal is approved
 /// Only the main admin is authorized to perform this action
-pub fn add_sub_admins(ctx: Context<UpdateSubAdmins>, addresses: Vec<Pubkey>) -> Result<()> {
-    let caller = ctx.accounts.authority.to_account_info().key();
+pub fn add_sub_admin(ctx: Context<UpdateSubAdmins>, proposal_id: u32) -> Result<()> {
     let global_config = &mut ctx.accounts.global_config;
 
     // Ensure the caller is the current admin
-    require!(global_config.is_owner(&caller), CustomError::Unauthorized);
+    require!(
+        global_config.is_owner(&ctx.accounts.authority.key()),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::AddSubAdmin)?;
+    let address = proposal.address.ok_or(CustomError::AddressNotFound)?;
 
-    // Add the new sub-admin addresses to the global configuration
-    global_config.add_sub_admins(addresses.clone());
+    // Add the new sub-admin address to the global configuration
+    global_config.add_sub_admins(vec![address]);
+    ctx.accounts.resize_global_config()?;
 
-    // Emit an event indicating the sub-admins have been added
+    // Emit an event indicating the sub-admin has been added
     emit!(events::UpdateSubAdmins {
         update_type: UpdateType::Add,
-        addresses
+        addresses: vec![address]
     });
 
     Ok(())
 }
 
-/// Function to remove existing sub-admins
+/// Function to remove an existing sub-admin once a `RemoveSubAdmin` proposal is approved
 /// Only the main admin is authorized to perform this action
-pub fn remove_sub_admins(ctx: Context<UpdateSubAdmins>, addresses: Vec<Pubkey>) -> Result<()> {
-    let caller = ctx.accounts.authority.to_account_info().key();
+pub fn remove_sub_admin(ctx: Context<RemoveSubAdmin>, proposal_id: u32) -> Result<()> {
     let global_config = &mut ctx.accounts.global_config;
 
     // Ensure the caller is the current admin
-    require!(global_config.is_owner(&caller), CustomError::Unauthorized);
+    require!(
+        global_config.is_owner(&ctx.accounts.authority.key()),
+        CustomError::Unauthorized
+    );
 
-    // Remove the specified sub-admin addresses from the global configuration
-    global_config.remove_sub_admins(addresses.clone());
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::RemoveSubAdmin)?;
+    let address = proposal.address.ok_or(CustomError::AddressNotFound)?;
 
-    // Emit an event indicating the sub-admins have been removed
+    // Remove the sub-admin address and any of its grants from the global configuration
+    global_config.remove_sub_admins(vec![address]);
+    global_config.remove_sub_admin_grants(&[address]);
+    ctx.accounts.resize_global_config()?;
+    revoke_role_membership(&ctx.accounts.role_membership, &address)?;
+
+    // Emit an event indicating the sub-admin has been removed
     emit!(events::UpdateSubAdmins {
         update_type: UpdateType::Remove,
-        addresses
+        addresses: vec![address]
     });
 
     Ok(())
 }
 
-/// Accounts struct for adding/removing sub-admins
+/// Function to grant a time-bounded or DAO-scoped sub-admin role once a `GrantSubAdmin`
+/// proposal is approved
+/// Only the main admin is authorized to perform this action
+pub fn grant_sub_admin(ctx: Context<GrantSubAdmin>, proposal_id: u32) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    // Ensure the caller is the current admin
+    require!(
+        global_config.is_owner(&ctx.accounts.authority.key()),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::GrantSubAdmin)?;
+    let address = proposal.address.ok_or(CustomError::AddressNotFound)?;
+
+    // The grant is scoped to the mint of the DAO named in the proposal, which must exist
+    if let Some(token) = proposal.dao_name.as_ref() {
+        require!(
+            ctx.accounts.dao_list.tokens.contains(token),
+            CustomError::NotFound
+        );
+    }
+    let mint = proposal
+        .dao_name
+        .map(|token| Pubkey::find_program_address(&[MINT_TAG, token.as_bytes()], &crate::ID).0);
+
+    let now = Clock::get()?.unix_timestamp;
+    let expires_at = proposal
+        .transfer_amount
+        .map(|days| now + days as i64 * 60 * 60 * 24);
+
+    global_config.add_sub_admin_grant(
+        RoleGrant {
+            address,
+            mint,
+            expires_at,
+        },
+        now,
+    );
+    ctx.accounts.resize_global_config()?;
+
+    emit!(events::SubAdminGranted {
+        address,
+        mint,
+        expires_at
+    });
+
+    Ok(())
+}
+
+/// Accounts struct for adding sub-admins
 #[derive(Accounts)]
 #[instruction()]
 pub struct UpdateMaintainers<'info> {
@@ -238,26 +383,113 @@ pub struct UpdateMaintainers<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Accounts struct for adding/removing sub-admins
+impl<'info> UpdateSubAdmins<'info> {
+    /// Resizes the global configuration to its updated lists
+    fn resize_global_config(&self) -> Result<()> {
+        resize_account(
+            &self.global_config.to_account_info(),
+            &self.authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            calc_global_config_size(self.global_config.get_len(), 0),
+        )
+    }
+}
+
+/// Accounts struct for adding sub-admins
+///
+/// The global configuration is resized by the handlers once updated, so removals
+/// don't grow it.
 #[derive(Accounts)]
-#[instruction(addresses: Vec<Pubkey>)]
+#[instruction()]
 pub struct UpdateSubAdmins<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveSubAdmin<'info> {
+    /// Resizes the global configuration to its updated lists
+    fn resize_global_config(&self) -> Result<()> {
+        resize_account(
+            &self.global_config.to_account_info(),
+            &self.authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            calc_global_config_size(self.global_config.get_len(), 0),
+        )
+    }
+}
+
+/// Accounts struct for removing a sub-admin and revoking its role membership
+#[derive(Accounts)]
+#[instruction()]
+pub struct RemoveSubAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
     #[account(
         mut,
         seeds = [GLOBAL_CONFIG_TAG],
         bump,
-        realloc = calc_global_config_size(global_config.get_len(), addresses.len()),
-        realloc::payer = authority,
-        realloc::zero = false,
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// CHECK: The `[ROLE_TAG, address]` membership of the removed sub-admin, checked and
+    /// revoked by the handler if it exists
+    #[account(mut)]
+    pub role_membership: AccountInfo<'info>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+/// Accounts struct for removing an admin and revoking its role membership
+#[derive(Accounts)]
+#[instruction()]
+pub struct RemoveAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: The `[ROLE_TAG, address]` membership of the removed admin, checked and
+    /// revoked by the handler if it exists
+    #[account(mut)]
+    pub role_membership: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+}
+
 /// Accounts struct for updating the main admin
 #[derive(Accounts)]
 #[instruction()]
@@ -279,3 +511,95 @@ pub struct UpdateGlobalConfig<'info> {
     #[account(mut)]
     pub executor: Signer<'info>,
 }
+
+/// Accounts struct for accepting an ownership transfer
+#[derive(Accounts)]
+#[instruction()]
+pub struct AcceptOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: The `[ROLE_TAG, owner]` membership of the previous owner, checked and
+    /// revoked by the handler if it exists
+    #[account(mut)]
+    pub previous_role_membership: AccountInfo<'info>,
+
+    /// Role membership of the new owner
+    #[account(
+        init_if_needed,
+        seeds = [ROLE_TAG, signer.key().as_ref()],
+        bump,
+        payer = signer,
+        space = 8 + size_of::<RoleMembership>()
+    )]
+    pub role_membership: Box<Account<'info, RoleMembership>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for cancelling an ownership transfer
+#[derive(Accounts)]
+#[instruction()]
+pub struct UpdateOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    pub signer: Signer<'info>,
+}
+
+impl<'info> GrantSubAdmin<'info> {
+    /// Resizes the global configuration to its updated grants
+    fn resize_global_config(&self) -> Result<()> {
+        resize_account(
+            &self.global_config.to_account_info(),
+            &self.authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            calc_global_config_size(self.global_config.get_len(), 0),
+        )
+    }
+}
+
+/// Accounts struct for granting time-bounded or DAO-scoped sub-admin roles
+///
+/// The global configuration is resized by the handler once updated, as a grant may
+/// replace an existing one.
+#[derive(Accounts)]
+#[instruction()]
+pub struct GrantSubAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// List of the DAOs a grant can be scoped to
+    #[account(
+        seeds = [DAO_TAG],
+        bump,
+    )]
+    pub dao_list: Box<Account<'info, DaoList>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
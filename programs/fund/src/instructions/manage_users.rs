@@ -12,22 +12,15 @@ use super::*;
 pub fn handler(ctx: Context<ManageUsers>, params: structs::manage_users::Params) -> Result<()> {
     let creators = &ctx.accounts.creators;
     let users = &mut ctx.accounts.users;
-    let blacklist = &ctx.accounts.blacklist;
     let fund_store = &ctx.accounts.fund_data_store;
 
     // Ensure the caller has creator rights
     creators.is_creator(
         &ctx.accounts.payer.key(),
-        ctx.accounts.metadata.creators.clone(),
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
     )?;
 
-    require!(
-        matches!(
-            fund_store.status,
-            Status::Created | Status::FundraisingVip | Status::FundraisingParty
-        ),
-        CustomError::PermissionDenied
-    );
+    require!(fund_store.status.is_open(), CustomError::PermissionDenied);
 
     // Convert the input parameters into an event structure for logging
     let event = params.to_events();
@@ -35,14 +28,28 @@ pub fn handler(ctx: Context<ManageUsers>, params: structs::manage_users::Params)
     use UpdateType::*;
     use UserType::*;
     match params.manage_type {
-        Add => match params.user_type {
-            Vip => {
-                users.add_vip_users(params.users, &blacklist)?;
-            }
-            Party => {
-                users.add_party_users(params.users, &blacklist)?;
+        Add => {
+            // Ensure none of the users is blacklisted, globally or in this DAO. The markers
+            // of each user are passed as remaining accounts.
+            let addresses: Vec<Pubkey> = params.users.iter().map(|user| user.address).collect();
+            require_not_blacklisted(
+                &addresses,
+                &ctx.accounts.mint_account.key(),
+                ctx.remaining_accounts,
+                [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+                Clock::get()?.unix_timestamp,
+            )?;
+
+            match params.user_type {
+                Vip => {
+                    users.add_vip_users(params.users)?;
+                }
+                Party => {
+                    users.add_party_users(params.users)?;
+                }
+                Tier(_) => return Err(CustomError::InvalidUserType.into()),
             }
-        },
+        }
         Remove => match params.user_type {
             Vip => {
                 users.remove_vip_users(params.users);
@@ -50,6 +57,7 @@ pub fn handler(ctx: Context<ManageUsers>, params: structs::manage_users::Params)
             Party => {
                 users.remove_party_users(params.users);
             }
+            Tier(_) => return Err(CustomError::InvalidUserType.into()),
         },
         _ => return Err(CustomError::PermissionDenied.into()),
     }
@@ -60,6 +68,54 @@ pub fn handler(ctx: Context<ManageUsers>, params: structs::manage_users::Params)
     Ok(())
 }
 
+/// Function to update users already listed
+/// This function allows creators to change the caps of VIP or Party users, remove them by
+/// address, or move them to the other user type.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `params` - Parameters specifying the user type and the update to apply.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn update(
+    ctx: Context<UpdateUsers>,
+    params: structs::manage_users::UpdateParams,
+) -> Result<()> {
+    let creators = &ctx.accounts.creators;
+    let users = &mut ctx.accounts.users;
+    let fund_store = &ctx.accounts.fund_data_store;
+
+    // Ensure the caller has creator rights
+    creators.is_creator(
+        &ctx.accounts.payer.key(),
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
+    )?;
+
+    require!(fund_store.status.is_open(), CustomError::PermissionDenied);
+
+    // Users of a sale tier are never stored
+    require!(
+        !matches!(params.user_type, UserType::Tier(_)),
+        CustomError::InvalidUserType
+    );
+
+    // Convert the input parameters into an event structure for logging
+    let event = params.to_events();
+
+    use structs::manage_users::UserUpdate::*;
+    match params.update {
+        UpdateCap(updated) => users.update_caps(params.user_type, updated)?,
+        RemoveByAddress(addresses) => users.remove_by_address(params.user_type, &addresses),
+        MoveTier(addresses) => users.move_tier(params.user_type, &addresses)?,
+    }
+
+    // Emit event to log the update
+    emit!(event);
+
+    Ok(())
+}
+
 /// Struct defining the accounts required for managing users
 #[derive(Accounts)]
 #[instruction(params: structs::manage_users::Params)]
@@ -90,12 +146,68 @@ pub struct ManageUsers<'info> {
     )]
     pub fund_data_store: Box<Account<'info, FundDataStore>>,
 
-    /// Account storing blacklist users
+    /// CHECK: This is the mint account used for referencing the token
+    #[account(
+        seeds = [MINT_TAG, params.token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
+    #[account()]
+    pub metadata: AccountInfo<'info>,
+
+    /// CHECK: Legacy global blacklist, checked until migrated into markers
     #[account(
         seeds = [BLACKLIST_TAG],
         bump,
     )]
-    pub blacklist: Box<Account<'info, Blacklist>>,
+    pub blacklist: AccountInfo<'info>,
+
+    /// CHECK: Legacy blacklist of the DAO, checked until migrated into markers
+    #[account(
+        seeds = [BLACKLIST_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_blacklist: AccountInfo<'info>,
+
+    /// The payer responsible for the transaction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program required for allocation and execution of instructions
+    pub system_program: Program<'info, System>,
+}
+
+/// Struct defining the accounts required for updating listed users
+#[derive(Accounts)]
+#[instruction(params: structs::manage_users::UpdateParams)]
+pub struct UpdateUsers<'info> {
+    /// Account storing creator information, used to verify permissions
+    #[account(
+        seeds = [CREATOR_TAG],
+        bump,
+    )]
+    pub creators: Box<Account<'info, Creators>>,
+
+    /// Account storing user information, shrunk when users are removed
+    #[account(
+        mut,
+        seeds = [USER_TAG, mint_account.key().as_ref()],
+        bump,
+        realloc = calc_updated_user_size(&users, &params),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub users: Box<Account<'info, Users>>,
+
+    /// Fund data store account.
+    /// Stores details about the fundraising process, including the current status.
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
 
     /// CHECK: This is the mint account used for referencing the token
     #[account(
@@ -104,9 +216,9 @@ pub struct ManageUsers<'info> {
     )]
     pub mint_account: Box<InterfaceAccount<'info, Mint>>,
 
-    /// CHECK: Metadata account for the token
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
     #[account()]
-    pub metadata: Box<Account<'info, MetadataAccount>>,
+    pub metadata: AccountInfo<'info>,
 
     /// The payer responsible for the transaction
     #[account(mut)]
@@ -0,0 +1,167 @@
+use super::*;
+
+/// Function to move the entries of the global blacklist into marker accounts
+///
+/// Users are migrated in order, one per marker passed as remaining accounts, so
+/// large lists can be drained over several transactions. Only the owner or an
+/// admin can migrate.
+pub fn migrate_blacklist<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateBlacklist<'info>>,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    require_admin(&accounts.global_config, accounts.payer.key)?;
+
+    let migrated = migrate(
+        &mut accounts.blacklist,
+        None,
+        ctx.remaining_accounts,
+        &accounts.payer,
+        &accounts.system_program,
+    )?;
+
+    emit!(events::BlacklistMigrated {
+        mint: None,
+        migrated,
+        remaining: accounts.blacklist.users.len() as u32,
+    });
+
+    Ok(())
+}
+
+/// Function to move the entries of a DAO blacklist into marker accounts
+pub fn migrate_dao_blacklist<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateDaoBlacklist<'info>>,
+    _token: String,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    require_admin(&accounts.global_config, accounts.payer.key)?;
+
+    let mint = accounts.mint_account.key();
+    let migrated = migrate(
+        &mut accounts.dao_blacklist,
+        Some(&mint),
+        ctx.remaining_accounts,
+        &accounts.payer,
+        &accounts.system_program,
+    )?;
+
+    emit!(events::BlacklistMigrated {
+        mint: Some(mint),
+        migrated,
+        remaining: accounts.dao_blacklist.users.len() as u32,
+    });
+
+    Ok(())
+}
+
+/// Ensures the caller is the owner or an admin.
+fn require_admin(global_config: &GlobalConfig, caller: &Pubkey) -> Result<()> {
+    require!(
+        global_config.is_owner(caller) || global_config.is_admin(caller),
+        CustomError::Unauthorized
+    );
+
+    Ok(())
+}
+
+/// Creates the markers of the next entries of the blacklist and removes them from it.
+fn migrate<'info>(
+    blacklist: &mut Blacklist,
+    mint: Option<&Pubkey>,
+    markers: &[AccountInfo<'info>],
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<u32> {
+    let entries = blacklist.take_entries(markers.len());
+    let space = 8 + size_of::<BlacklistEntry>();
+
+    for (entry, marker) in entries.iter().zip(markers) {
+        let (address, bump) = find_blacklist_entry_address(&entry.address, mint);
+        require_keys_eq!(marker.key(), address, CustomError::AccountMisMatch);
+
+        // Skip users blocked again as markers since
+        if !marker.data_is_empty() {
+            continue;
+        }
+
+        let bump = [bump];
+        let seeds: Vec<&[u8]> = match mint {
+            Some(mint) => vec![
+                BLACKLIST_ENTRY_TAG,
+                mint.as_ref(),
+                entry.address.as_ref(),
+                &bump,
+            ],
+            None => vec![BLACKLIST_ENTRY_TAG, entry.address.as_ref(), &bump],
+        };
+
+        create_pda_account(
+            marker,
+            &payer.to_account_info(),
+            &system_program.to_account_info(),
+            space,
+            &crate::ID,
+            &seeds,
+        )?;
+
+        entry.try_serialize(&mut &mut marker.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(entries.len() as u32)
+}
+
+/// Accounts struct for migrating the global blacklist
+#[derive(Accounts)]
+pub struct MigrateBlacklist<'info> {
+    /// Account storing blacklist users
+    #[account(
+        mut,
+        seeds = [BLACKLIST_TAG],
+        bump,
+    )]
+    pub blacklist: Box<Account<'info, Blacklist>>,
+
+    /// Reference to the global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for migrating the blacklist of a DAO
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct MigrateDaoBlacklist<'info> {
+    /// Account storing the users blacklisted in the DAO
+    #[account(
+        mut,
+        seeds = [BLACKLIST_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub dao_blacklist: Box<Account<'info, Blacklist>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Reference to the global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+/// Function to rewrite the details of a creator with their current layout
+///
+/// Legacy details were overwritten with the name of each new DAO without being
+/// resized, leaving stale bytes where the index of DAOs is now read. The legacy
+/// fields are decoded on their own and the account resized and rewritten, before
+/// the creator can launch or be updated again. Only accounts still sized with the
+/// legacy layout can be migrated, so anyone can pay for the migration.
+///
+/// # Arguments
+/// * `ctx` - The execution context containing all relevant accounts.
+/// * `creator` - Address of the creator.
+pub fn handler(ctx: Context<MigrateCreatorInfo>, creator: Pubkey) -> Result<()> {
+    let account = &ctx.accounts.creator_info;
+    require!(
+        account.data_len() == CreatorInfo::LEGACY_SIZE,
+        CustomError::AlreadyMigrated
+    );
+    let creator_info = CreatorInfo::from_legacy(&account.try_borrow_data()?)?;
+
+    resize_account(
+        account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        calc_creator_info_size(0),
+    )?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    creator_info.try_serialize(&mut &mut data[..])?;
+
+    emit!(events::CreatorInfoMigrated { creator });
+
+    Ok(())
+}
+
+/// Accounts struct for migrating the details of a creator
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct MigrateCreatorInfo<'info> {
+    /// CHECK: Details of the creator, decoded with the legacy layout by the handler
+    #[account(
+        mut,
+        seeds = [CREATOR_TAG, creator.as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub creator_info: AccountInfo<'info>,
+
+    /// Account paying for the growth of the account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
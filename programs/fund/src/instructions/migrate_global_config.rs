@@ -0,0 +1,44 @@
+use super::*;
+
+/// Function to rewrite the global configuration with its current layout
+///
+/// Lists of the legacy configuration were never shrunk on removals, leaving stale
+/// bytes where the appended fields are now read. The legacy fields are decoded on
+/// their own, the appended fields reset and the rest of the account zeroed, so the
+/// configuration can be loaded again. Only the owner can migrate, once after the
+/// upgrade, as grants, nominations and bounds set since are reset too.
+pub fn handler(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+    let account = &ctx.accounts.global_config;
+    let global_config = GlobalConfig::from_legacy(&account.try_borrow_data()?)?;
+
+    require!(
+        global_config.is_owner(ctx.accounts.owner.key),
+        CustomError::Unauthorized
+    );
+
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    global_config.try_serialize(&mut &mut data[..])?;
+
+    emit!(events::GlobalConfigMigrated {
+        owner: global_config.owner,
+    });
+
+    Ok(())
+}
+
+/// Accounts struct for migrating the global configuration
+#[derive(Accounts)]
+pub struct MigrateGlobalConfig<'info> {
+    /// CHECK: Global configuration, decoded with the legacy layout by the handler
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+        owner = crate::ID,
+    )]
+    pub global_config: AccountInfo<'info>,
+
+    /// Owner of the configuration
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+/// Function to release the creator funds of a milestone once a `ReleaseMilestone`
+/// proposal has been approved
+///
+/// The milestone's share of the creators' withdrawable raise is split between the
+/// DAO's co-creators like a regular creator withdrawal, the last milestone releasing
+/// whatever remains.
+///
+/// # Arguments
+/// * `ctx` - The execution context, the co-creators other than `creator_address` being passed as remaining accounts.
+/// * `params` - Token name and proposal Id.
+pub fn release_milestone<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferSolToCreator<'info>>,
+    params: structs::transfer::Params,
+) -> Result<()> {
+    let fund_store = &mut ctx.accounts.fund_data_store;
+    let proposals_list = &mut ctx.accounts.proposals_list;
+
+    // Ensure the caller is allowed to execute treasury transfers before proceeding
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.payer.key,
+        Some(&ctx.accounts.mint_account.key()),
+        permissions::TREASURY,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts
+            .pause_config
+            .is_paused(&ctx.accounts.mint_account.key()),
+        CustomError::Paused
+    );
+
+    let proposal =
+        proposals_list.perform_execution(params.proposal_id, &ProposalType::ReleaseMilestone)?;
+
+    // Ensure the proposal targets the given token and creator
+    require!(
+        proposal
+            .dao_name
+            .ok_or(CustomError::NotFound)?
+            .eq(&params.token),
+        CustomError::AccountMisMatch
+    );
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(ctx.accounts.creator_address.key),
+        CustomError::AccountMisMatch
+    );
+
+    let milestone = proposal.transfer_amount.unwrap_or_default();
+    let amount = fund_store.release_milestone(milestone)?;
+
+    ctx.accounts
+        .pay_creators(ctx.remaining_accounts, amount, ctx.bumps.escrow_sol_account)?;
+
+    emit!(events::MilestoneReleased {
+        token: params.token,
+        milestone: milestone as u8,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Function to fail a milestone once a `FailMilestone` proposal has been approved
+/// Only the owner is authorized to execute the proposal
+///
+/// The milestone and the ones after it are marked failed and the DAO moves to
+/// `Refunding`, the creator funds not released yet being refunded to committers
+/// pro rata to their commitments.
+///
+/// # Arguments
+/// * `ctx` - The execution context containing all relevant accounts.
+/// * `token` - Token name of the DAO.
+/// * `proposal_id` - Proposal Id.
+pub fn fail_milestone(ctx: Context<FailMilestone>, token: String, proposal_id: u32) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure valid executor
+    require!(
+        global_config.is_owner(ctx.accounts.authority.key),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::FailMilestone)?;
+
+    // Ensure the proposal targets the given token
+    require!(
+        proposal.dao_name.ok_or(CustomError::NotFound)?.eq(&token),
+        CustomError::AccountMisMatch
+    );
+
+    // Refund the unreleased creator funds, out of the escrow balance above its rent-exempt minimum
+    let escrow_balance = ctx
+        .accounts
+        .escrow_sol_account
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+
+    let milestone = proposal.transfer_amount.unwrap_or_default();
+    let refundable = ctx.accounts.fund_data_store.fail_milestone(
+        milestone,
+        escrow_balance,
+        ctx.accounts.commitments.total_commited_sols,
+    )?;
+
+    emit!(events::MilestoneFailed {
+        token,
+        milestone: milestone as u8,
+        refundable,
+    });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for failing a milestone.
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct FailMilestone<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// CHECK: Escrow SOL account where committed SOL is stored
+    #[account(
+        seeds = [ESCROW_TAG, SOL_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_sol_account: AccountInfo<'info>,
+
+    /// Commitments of the DAO
+    #[account(
+        seeds = [COMMITMENT_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub commitments: Box<Account<'info, Commitments>>,
+
+    /// Fund data store of the DAO
+    #[account(
+        mut,
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub authority: Signer<'info>,
+}
@@ -29,7 +29,7 @@ pub fn handler(ctx: Context<MintToken>, params: structs::mint::Params) -> Result
     };
 
     // Execute the mint instruction with PDA signer
-    token::mint_to(
+    token_interface::mint_to(
         CpiContext::new_with_signer(cpi_program, cpi_accounts, &signer),
         params.amount,
     )?;
@@ -86,7 +86,7 @@ pub struct MintToken<'info> {
     pub authority: Signer<'info>,
 
     /// The SPL Token program account
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The Solana System program account
     pub system_program: Program<'info, System>,
@@ -115,7 +115,7 @@ impl<'info> MintToken<'info> {
         // Remove mint authority to prevent further minting
         set_authority(
             cpi_ctx,
-            spl_token::instruction::AuthorityType::MintTokens,
+            token_interface::spl_token_2022::instruction::AuthorityType::MintTokens,
             None,
         )?;
 
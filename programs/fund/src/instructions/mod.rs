@@ -5,39 +5,67 @@ pub mod block_creator;
 pub mod block_dao;
 pub mod block_user;
 pub mod burn;
+pub mod burn_to_refund;
 pub mod claim;
+pub mod collect_transfer_fees;
 pub mod commitment;
 pub mod create;
 pub mod end_dao;
+pub mod get_treasury_breakdown;
+pub mod governance;
 pub mod has_role;
 pub mod init_commitment;
 pub mod init_creators;
 pub mod init_multisig;
+pub mod init_treasury_ledger;
 pub mod init_users;
 pub mod initialize;
+pub mod is_paused;
 pub mod is_user_blocked;
 pub mod maintainers;
 pub mod manage_users;
+pub mod migrate_blacklist;
+pub mod migrate_creator_info;
+pub mod migrate_global_config;
+pub mod milestones;
 pub mod mint;
 pub mod move_to_lp;
+pub mod pause;
 pub mod proposals;
 pub mod reset;
+pub mod roles;
+pub mod set_blacklist_policy;
+pub mod set_merkle_root;
+pub mod set_public_round;
+pub mod set_sale_tiers;
+pub mod set_tokenomics_bounds;
 pub mod start_dao;
 pub mod start_party_round;
+pub mod start_public_round;
+pub mod streams;
+pub mod token_metadata;
 pub mod transfer_sol_to_creator;
 pub mod transfer_sol_to_deployer;
 pub mod unblock_creator;
 pub mod unblock_user;
+pub mod update_creator_fee;
 pub mod update_fee_account;
 pub mod update_fees;
 pub mod update_status;
 pub mod upgrade;
+pub mod withdraw_royalties;
 
 pub use self::{
-    add_creator::*, block_creator::*, block_dao::*, block_user::*, burn::*, claim::*,
-    commitment::*, create::*, end_dao::*, has_role::*, init_commitment::*, init_creators::*,
-    init_multisig::*, init_users::*, initialize::*, is_user_blocked::*, maintainers::*,
-    manage_users::*, mint::*, move_to_lp::*, proposals::*, reset::*, start_dao::*,
-    start_party_round::*, transfer_sol_to_creator::*, transfer_sol_to_deployer::*,
-    unblock_creator::*, update_fee_account::*, update_fees::*, update_status::*, upgrade::*,
+    add_creator::*, block_creator::*, block_dao::*, block_user::*, burn::*, burn_to_refund::*,
+    claim::*, collect_transfer_fees::*, commitment::*, create::*, end_dao::*,
+    get_treasury_breakdown::*, governance::*, has_role::*, init_commitment::*, init_creators::*,
+    init_multisig::*, init_treasury_ledger::*, init_users::*, initialize::*, is_paused::*,
+    is_user_blocked::*, maintainers::*, manage_users::*, migrate_blacklist::*,
+    migrate_creator_info::*, migrate_global_config::*, milestones::*, mint::*, move_to_lp::*,
+    pause::*, proposals::*, reset::*, roles::*, set_blacklist_policy::*, set_merkle_root::*,
+    set_public_round::*, set_sale_tiers::*, set_tokenomics_bounds::*, start_dao::*,
+    start_party_round::*, start_public_round::*, streams::*, token_metadata::*,
+    transfer_sol_to_creator::*, transfer_sol_to_deployer::*, unblock_creator::*, unblock_user::*,
+    update_creator_fee::*, update_fee_account::*, update_fees::*, update_status::*, upgrade::*,
+    withdraw_royalties::*,
 };
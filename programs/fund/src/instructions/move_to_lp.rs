@@ -2,7 +2,10 @@ use std::str::FromStr;
 
 use super::*;
 
-pub fn handler(ctx: Context<MoveToLP>, token: String) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MoveToLP<'info>>,
+    token: String,
+) -> Result<()> {
     let global_config = &ctx.accounts.global_config;
     let fund_store = &mut ctx.accounts.fund_data_store;
 
@@ -25,13 +28,25 @@ pub fn handler(ctx: Context<MoveToLP>, token: String) -> Result<()> {
         CustomError::PermissionDenied
     );
 
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts
+            .pause_config
+            .is_paused(&ctx.accounts.mint_account.key()),
+        CustomError::Paused
+    );
+
     // Update status as Trade
     fund_store.update_status(Status::Trade)?;
 
     let mint_key = ctx.accounts.mint_account.key();
 
+    // Pair the liquidity at the price the tokens were sold at
+    let tokens_per_sol = load_optional_account::<SaleTiers>(&ctx.accounts.sale_tiers)?
+        .and_then(|sale_tiers| sale_tiers.average_tokens_per_sol())
+        .unwrap_or(fund_store.tokens_per_sol);
     let (token_amount, sol_amount) =
-        fund_store.calc_lp_pairs(ctx.accounts.mint_account.supply as u128);
+        fund_store.calc_lp_pairs(ctx.accounts.mint_account.supply as u128, tokens_per_sol);
 
     // Define signer seeds for the escrow SOL account
     let seeds = &[
@@ -57,6 +72,11 @@ pub fn handler(ctx: Context<MoveToLP>, token: String) -> Result<()> {
         ),
         sol_amount, // Amount of SOL to transfer
     )?;
+    record_treasury_flow(
+        &ctx.accounts.treasury_ledger,
+        LedgerEntryKind::MoveToLp,
+        sol_amount,
+    )?;
 
     // Define signer seeds for the escrow token account
     let seeds = &[
@@ -68,20 +88,21 @@ pub fn handler(ctx: Context<MoveToLP>, token: String) -> Result<()> {
     let signer = [&seeds[..]];
 
     // Prepare an SPL token transfer instruction
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.escrow_mint_ata.to_account_info(), // Source: Escrow's token ATA
-        to: ctx.accounts.to_ata.to_account_info(),            // Destination: Recipient's ATA
+        mint: ctx.accounts.mint_account.to_account_info(),
+        to: ctx.accounts.to_ata.to_account_info(), // Destination: Recipient's ATA
         authority: ctx.accounts.escrow_mint_account.to_account_info(), // Authority over the escrow tokens
     };
 
     // Execute the token transfer from escrow to recipient's associated token account (ATA)
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts,
-            &signer,
-        ),
+    transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        ctx.remaining_accounts,
         token_amount, // Amount of tokens to transfer
+        ctx.accounts.mint_account.decimals,
+        &signer,
     )?;
 
     // Emit a withdrawal event for logging and tracking purposes
@@ -104,6 +125,13 @@ pub struct MoveToLP<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
     /// The escrow account storing SOL before it is transferred
     /// CHECK: Escrow SOL account where committed SOL is stored
     #[account(
@@ -113,6 +141,14 @@ pub struct MoveToLP<'info> {
     )]
     pub escrow_sol_account: AccountInfo<'info>,
 
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
     /// The fund data store tracking fundraising status
     #[account(
         mut,
@@ -121,6 +157,13 @@ pub struct MoveToLP<'info> {
     )]
     pub fund_data_store: Box<Account<'info, FundDataStore>>,
 
+    /// CHECK: Sale tiers of the DAO, absent unless the DAO sells in tiers
+    #[account(
+        seeds = [SALE_TIER_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub sale_tiers: AccountInfo<'info>,
+
     /// The escrow account that holds the minted tokens
     /// CHECK: Escrow account for holding minted tokens
     #[account(
@@ -160,7 +203,7 @@ pub struct MoveToLP<'info> {
     pub caller_program: UncheckedAccount<'info>,
 
     /// The SPL Token program
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// The SPL Associated Token program
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -0,0 +1,212 @@
+use super::*;
+
+/// Function to initialize the emergency pause configuration
+/// Only the main admin is authorized to perform this action
+pub fn init_pause_config(ctx: Context<InitPauseConfig>) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_owner(&ctx.accounts.payer.key()),
+        CustomError::Unauthorized
+    );
+
+    Ok(())
+}
+
+/// Function to add new guardians
+/// Only the main admin is authorized to perform this action
+pub fn add_guardians(ctx: Context<UpdateGuardians>, addresses: Vec<Pubkey>) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure the caller is the current admin
+    require!(
+        global_config.is_owner(&ctx.accounts.authority.key()),
+        CustomError::Unauthorized
+    );
+
+    ctx.accounts.pause_config.add_guardians(addresses.clone());
+
+    // Emit an event indicating the guardians have been added
+    emit!(events::UpdateGuardians {
+        update_type: UpdateType::Add,
+        addresses
+    });
+
+    Ok(())
+}
+
+/// Function to remove existing guardians
+/// Only the main admin is authorized to perform this action
+pub fn remove_guardians(ctx: Context<UpdateGuardians>, addresses: Vec<Pubkey>) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure the caller is the current admin
+    require!(
+        global_config.is_owner(&ctx.accounts.authority.key()),
+        CustomError::Unauthorized
+    );
+
+    ctx.accounts
+        .pause_config
+        .remove_guardians(addresses.clone());
+
+    // Emit an event indicating the guardians have been removed
+    emit!(events::UpdateGuardians {
+        update_type: UpdateType::Remove,
+        addresses
+    });
+
+    Ok(())
+}
+
+/// Function to pause all DAOs, or a single mint when given
+/// Guardians and the main admin can pause instantly, unpausing requires a proposal
+pub fn pause(ctx: Context<Pause>, mint: Option<Pubkey>) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+    let pause_config = &mut ctx.accounts.pause_config;
+    let caller = ctx.accounts.authority.key();
+
+    require!(
+        pause_config.is_guardian(&caller) || global_config.is_owner(&caller),
+        CustomError::Unauthorized
+    );
+
+    pause_config.pause(mint);
+
+    emit!(events::Paused {
+        mint,
+        paused_by: caller
+    });
+
+    Ok(())
+}
+
+/// Function to resume all DAOs, or a single mint, once an `Unpause` proposal is approved
+/// Only the main admin is authorized to perform this action
+pub fn unpause(ctx: Context<Unpause>, proposal_id: u32) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_owner(&ctx.accounts.executor.key()),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::Unpause)?;
+
+    ctx.accounts.pause_config.unpause(proposal.address)?;
+
+    emit!(events::Unpaused {
+        mint: proposal.address,
+        proposal_id
+    });
+
+    Ok(())
+}
+
+/// Accounts struct for initializing the pause configuration
+#[derive(Accounts)]
+#[instruction()]
+pub struct InitPauseConfig<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        init,
+        seeds = [PAUSE_TAG],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<PauseConfig>()
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for adding/removing guardians
+#[derive(Accounts)]
+#[instruction(addresses: Vec<Pubkey>)]
+pub struct UpdateGuardians<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PAUSE_TAG],
+        bump,
+        realloc = calc_pause_config_size(&pause_config, addresses.len()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for pausing
+#[derive(Accounts)]
+#[instruction(mint: Option<Pubkey>)]
+pub struct Pause<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PAUSE_TAG],
+        bump,
+        realloc = calc_pause_config_size(&pause_config, pause_config.new_entries(mint.as_ref())),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for unpausing
+#[derive(Accounts)]
+#[instruction()]
+pub struct Unpause<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    #[account(
+        mut,
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+}
@@ -29,6 +29,7 @@ pub fn handler(ctx: Context<ApproveProposal>, proposal_id: u32) -> Result<(bool,
                 CustomError::AccountMisMatch
             );
         }
+        ApproverType::TokenHolders => return Err(CustomError::TokenHolderProposal.into()),
     }
 
     require!(!proposal.is_executed(), CustomError::AlreadyExecuted);
@@ -5,18 +5,16 @@ pub fn handler(ctx: Context<CreateDaoProposal>, token: String) -> Result<u32> {
     let fund_store = &mut ctx.accounts.fund_data_store;
 
     require!(
-        global_config.is_sub_admin(ctx.accounts.signer.key),
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
         CustomError::Unauthorized
     );
 
     require!(
-        matches!(
-            fund_store.status,
-            Status::Created
-                | Status::FundraisingVip
-                | Status::FundraisingParty
-                | Status::FundraisingSuccess
-        ),
+        fund_store.status.is_open() || fund_store.status == Status::FundraisingSuccess,
         CustomError::PermissionDenied
     );
 
@@ -1,21 +1,82 @@
 use super::*;
 
-pub fn handler(ctx: Context<CreateProposal>, user_address: Pubkey) -> Result<u32> {
+pub fn handler(
+    ctx: Context<CreateBlockUserProposal>,
+    params: structs::block_user::Params,
+) -> Result<u32> {
     let global_config = &ctx.accounts.global_config;
+    let now = Clock::get()?.unix_timestamp;
 
     require!(
-        global_config.is_sub_admin(ctx.accounts.signer.key),
+        global_config.is_sub_admin(ctx.accounts.signer.key, None, now),
         CustomError::Unauthorized
     );
 
-    create_proposal(
-        ctx,
-        ProposalType::BlocklistUser,
-        Some(user_address),
-        1,
-        None,
-        None,
-        ExecutorType::Admin,
-        ApproverType::Admin,
-    )
+    require!(
+        params.blocked_until.map_or(true, |until| until > now),
+        CustomError::InvalidBlockDuration
+    );
+
+    let proposal_id = ctx.accounts.proposals_list.proposals.len() as u32 + 1;
+
+    // Store the block details alongside the proposal
+    let block_details = &mut ctx.accounts.block_details;
+    block_details.proposal_id = proposal_id;
+    block_details.blocked_until = params.blocked_until;
+    block_details.reason = params.reason;
+
+    // The DAO scope is carried as the token name
+    let proposal = ProposalData {
+        id: proposal_id,
+        created_at: now,
+        created_by: ctx.accounts.signer.key(),
+        proposal_type: ProposalType::BlocklistUser,
+        approver_threshold: 1,
+        address: Some(params.address),
+        dao_name: params.token,
+        transfer_amount: None,
+        executor_type: ExecutorType::Admin,
+        approve_type: ApproverType::Admin,
+        status: ProposalStatus::Pending,
+        executed_at: None,
+        approvers: vec![],
+    };
+
+    ctx.accounts.proposals_list.add_proposal(proposal)?;
+
+    Ok(proposal_id)
+}
+
+#[derive(Accounts)]
+pub struct CreateBlockUserProposal<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Account storing the block details applied on execution
+    #[account(
+        init,
+        seeds = [
+            BLOCK_DETAILS_TAG,
+            &(proposals_list.proposals.len() as u32 + 1).to_le_bytes()
+        ],
+        bump,
+        payer = signer,
+        space = 8 + size_of::<BlockDetails>(),
+    )]
+    pub block_details: Box<Account<'info, BlockDetails>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
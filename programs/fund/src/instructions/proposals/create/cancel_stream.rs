@@ -0,0 +1,30 @@
+use super::*;
+
+pub fn handler(
+    ctx: Context<CreateDaoProposal>,
+    token: String,
+    recipient: Pubkey,
+    kind: PayoutKind,
+) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    create_dao_proposal(
+        ctx,
+        ProposalType::CancelStream,
+        Some(recipient),
+        1,
+        Some(token),
+        Some(kind as u64),
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
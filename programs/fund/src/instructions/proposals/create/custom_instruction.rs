@@ -0,0 +1,79 @@
+use super::*;
+
+pub fn handler(
+    ctx: Context<CreateCustomInstructionProposal>,
+    instructions: Vec<ProposalInstruction>,
+) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(ctx.accounts.signer.key, None, Clock::get()?.unix_timestamp),
+        CustomError::Unauthorized
+    );
+
+    require!(!instructions.is_empty(), CustomError::EmptyInstructions);
+
+    let proposal_id = ctx.accounts.proposals_list.proposals.len() as u32 + 1;
+
+    // Store the instructions alongside the proposal
+    let proposal_instructions = &mut ctx.accounts.proposal_instructions;
+    proposal_instructions.proposal_id = proposal_id;
+    proposal_instructions.instructions = instructions;
+
+    // The instructions account is unique per proposal, which keeps otherwise
+    // identical custom proposals from being flagged as duplicates
+    let proposal = ProposalData {
+        id: proposal_id,
+        created_at: Clock::get()?.unix_timestamp,
+        created_by: ctx.accounts.signer.key(),
+        proposal_type: ProposalType::CustomInstruction,
+        approver_threshold: 3,
+        address: Some(proposal_instructions.key()),
+        dao_name: None,
+        transfer_amount: None,
+        executor_type: ExecutorType::Owner,
+        approve_type: ApproverType::Admin,
+        status: ProposalStatus::Pending,
+        executed_at: None,
+        approvers: vec![],
+    };
+
+    ctx.accounts.proposals_list.add_proposal(proposal)?;
+
+    Ok(proposal_id)
+}
+
+#[derive(Accounts)]
+#[instruction(instructions: Vec<ProposalInstruction>)]
+pub struct CreateCustomInstructionProposal<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Account storing the instructions to be invoked on execution
+    #[account(
+        init,
+        seeds = [
+            PROPOSAL_INSTRUCTIONS_TAG,
+            &(proposals_list.proposals.len() as u32 + 1).to_le_bytes()
+        ],
+        bump,
+        payer = signer,
+        space = calc_proposal_instructions_size(&instructions),
+    )]
+    pub proposal_instructions: Box<Account<'info, ProposalInstructions>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+pub fn handler(ctx: Context<CreateDaoProposal>, token: String, milestone: u8) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+    let fund_store = &ctx.accounts.fund_data_store;
+
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    // Ensure the milestone is the next one to deliver
+    fund_store.check_milestone(milestone as u64)?;
+
+    create_dao_proposal(
+        ctx,
+        ProposalType::FailMilestone,
+        None,
+        1,
+        Some(token),
+        Some(milestone as u64),
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
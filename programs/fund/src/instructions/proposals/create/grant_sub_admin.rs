@@ -0,0 +1,29 @@
+use super::*;
+
+pub fn handler(
+    ctx: Context<CreateProposal>,
+    address: Pubkey,
+    token: Option<String>,
+    duration_days: Option<u64>,
+) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(ctx.accounts.signer.key, None, Clock::get()?.unix_timestamp),
+        CustomError::Unauthorized
+    );
+
+    require!(duration_days != Some(0), CustomError::InvalidGrantDuration);
+
+    // The DAO scope is carried as the token name and the duration as the amount
+    create_proposal(
+        ctx,
+        ProposalType::GrantSubAdmin,
+        Some(address),
+        1,
+        token,
+        duration_days,
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
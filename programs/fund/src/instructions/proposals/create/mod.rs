@@ -2,18 +2,36 @@ use super::*;
 
 pub mod add_admin;
 pub mod add_deployer;
+pub mod add_sub_admin;
 pub mod block_creator;
 pub mod block_dao;
 pub mod block_user;
+pub mod cancel_stream;
+pub mod custom_instruction;
+pub mod fail_milestone;
+pub mod grant_sub_admin;
 pub mod publish_to_amm;
+pub mod release_milestone;
 pub mod remove_admin;
 pub mod remove_deployer;
 pub mod remove_liquidity;
+pub mod remove_sub_admin;
+pub mod set_blacklist_policy;
+pub mod set_permissions;
 pub mod transfer_sol_to_creator;
 pub mod trasnfer_sol_to_deployer;
 pub mod unblock_creator;
 pub mod unblock_user;
+pub mod unpause;
+pub mod update_fee_account;
+pub mod update_fees;
 pub mod update_owner;
+pub mod update_token_metadata;
+pub mod update_trade_fee_account;
+pub mod update_trade_fees;
+pub mod withdraw_royalties;
+
+pub use self::{block_user::*, custom_instruction::*, update_token_metadata::*};
 
 fn create_proposal(
     ctx: Context<CreateProposal>,
@@ -182,9 +200,9 @@ pub struct CreateCreatorProposal<'info> {
     )]
     pub creators: Box<Account<'info, Creators>>,
 
-    /// CHECK: Metadata account for the token
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
     #[account()]
-    pub metadata: Box<Account<'info, MetadataAccount>>,
+    pub metadata: AccountInfo<'info>,
 
     #[account(
         mut,
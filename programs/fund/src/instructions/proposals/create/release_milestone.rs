@@ -0,0 +1,27 @@
+use super::*;
+
+pub fn handler(ctx: Context<CreateCreatorProposal>, token: String, milestone: u8) -> Result<u32> {
+    let creators = &ctx.accounts.creators;
+    let fund_store = &mut ctx.accounts.fund_data_store;
+    let caller = &ctx.accounts.signer.key();
+    creators.is_creator(
+        caller,
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
+    )?;
+
+    require!(fund_store.created_by.eq(caller), CustomError::Unauthorized);
+
+    // Ensure the milestone is the next one to deliver
+    fund_store.check_milestone(milestone as u64)?;
+
+    create_creator_proposal(
+        ctx,
+        ProposalType::ReleaseMilestone,
+        Some(*caller),
+        1,
+        Some(token),
+        Some(milestone as u64),
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
@@ -5,7 +5,11 @@ pub fn handler(ctx: Context<CreateDaoProposal>, token: String, percent: u32) ->
     let fund_store = &mut ctx.accounts.fund_data_store;
 
     require!(
-        global_config.is_sub_admin(ctx.accounts.signer.key),
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
         CustomError::Unauthorized
     );
 
@@ -0,0 +1,30 @@
+use super::*;
+
+pub fn handler(
+    ctx: Context<CreateDaoProposal>,
+    token: String,
+    policy: BlacklistPolicy,
+) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    // The policy is carried as its code
+    create_dao_proposal(
+        ctx,
+        ProposalType::SetBlacklistPolicy,
+        None,
+        1,
+        Some(token),
+        Some(policy as u64),
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
@@ -0,0 +1,21 @@
+use super::*;
+
+pub fn handler(ctx: Context<CreateProposal>, address: Pubkey, permissions: u64) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(ctx.accounts.signer.key, None, Clock::get()?.unix_timestamp),
+        CustomError::Unauthorized
+    );
+
+    create_proposal(
+        ctx,
+        ProposalType::SetPermissions,
+        Some(address),
+        1,
+        None,
+        Some(permissions),
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
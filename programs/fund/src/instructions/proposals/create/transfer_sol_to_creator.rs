@@ -4,7 +4,10 @@ pub fn handler(ctx: Context<CreateCreatorProposal>, token: String, amount: u64)
     let creators = &ctx.accounts.creators;
     let fund_store = &mut ctx.accounts.fund_data_store;
     let caller = &ctx.accounts.signer.key();
-    creators.is_creator(caller, ctx.accounts.metadata.creators.clone())?;
+    creators.is_creator(
+        caller,
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
+    )?;
 
     require!(
         fund_store.status.eq(&Status::FundraisingSuccess) || fund_store.status.eq(&Status::Trade),
@@ -13,6 +16,9 @@ pub fn handler(ctx: Context<CreateCreatorProposal>, token: String, amount: u64)
 
     require!(fund_store.created_by.eq(caller), CustomError::Unauthorized);
 
+    // Creator funds gated by milestones are released through them only
+    fund_store.require_no_milestones()?;
+
     fund_store.check_creator_withdrawl(amount)?;
 
     create_creator_proposal(
@@ -11,7 +11,7 @@ pub fn handler(
 
     creators.is_creator(
         &ctx.accounts.signer.key(),
-        ctx.accounts.metadata.creators.clone(),
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
     )?;
 
     require!(
@@ -4,7 +4,7 @@ pub fn handler(ctx: Context<CreateProposal>, address: Pubkey) -> Result<u32> {
     let global_config = &ctx.accounts.global_config;
 
     require!(
-        global_config.is_sub_admin(ctx.accounts.signer.key),
+        global_config.is_sub_admin(ctx.accounts.signer.key, None, Clock::get()?.unix_timestamp),
         CustomError::Unauthorized
     );
 
@@ -1,19 +1,24 @@
 use super::*;
 
-pub fn handler(ctx: Context<CreateProposal>, address: Pubkey) -> Result<u32> {
+pub fn handler(
+    ctx: Context<CreateProposal>,
+    address: Pubkey,
+    token: Option<String>,
+) -> Result<u32> {
     let global_config = &ctx.accounts.global_config;
 
     require!(
-        global_config.is_sub_admin(ctx.accounts.signer.key),
+        global_config.is_sub_admin(ctx.accounts.signer.key, None, Clock::get()?.unix_timestamp),
         CustomError::Unauthorized
     );
 
+    // The DAO scope is carried as the token name
     create_proposal(
         ctx,
         ProposalType::UnblockUser,
         Some(address),
         1,
-        None,
+        token,
         None,
         ExecutorType::Admin,
         ApproverType::Admin,
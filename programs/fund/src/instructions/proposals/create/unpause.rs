@@ -0,0 +1,21 @@
+use super::*;
+
+pub fn handler(ctx: Context<CreateProposal>, mint: Option<Pubkey>) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(ctx.accounts.signer.key, None, Clock::get()?.unix_timestamp),
+        CustomError::Unauthorized
+    );
+
+    create_proposal(
+        ctx,
+        ProposalType::Unpause,
+        mint,
+        3,
+        None,
+        None,
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
@@ -0,0 +1,30 @@
+use super::*;
+
+pub fn handler(
+    ctx: Context<CreateDaoProposal>,
+    token: String,
+    creator_address: Pubkey,
+    fee_percent: u32,
+) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    create_dao_proposal(
+        ctx,
+        ProposalType::UpdateFees,
+        Some(creator_address),
+        3,
+        Some(token),
+        Some(fee_percent.into()),
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
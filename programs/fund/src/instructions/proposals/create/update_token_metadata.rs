@@ -0,0 +1,109 @@
+use super::*;
+
+pub fn handler(
+    ctx: Context<CreateUpdateTokenMetadataProposal>,
+    token: String,
+    params: structs::metadata_update::Params,
+) -> Result<u32> {
+    let creators = &ctx.accounts.creators;
+    let caller = &ctx.accounts.signer.key();
+    let mint = ctx.accounts.mint_account.key();
+    creators.is_creator(caller, load_token_creators(&ctx.accounts.metadata, &mint)?)?;
+
+    require!(
+        ctx.accounts.fund_data_store.created_by.eq(caller),
+        CustomError::Unauthorized
+    );
+
+    require!(
+        is_metadata_mutable(&ctx.accounts.metadata, &mint)?,
+        CustomError::MetadataLocked
+    );
+
+    params.validate()?;
+
+    let proposal_id = ctx.accounts.proposals_list.proposals.len() as u32 + 1;
+
+    // Store the changes alongside the proposal
+    let metadata_update = &mut ctx.accounts.metadata_update;
+    metadata_update.proposal_id = proposal_id;
+    metadata_update.mint = mint;
+    metadata_update.name = params.name;
+    metadata_update.symbol = params.symbol;
+    metadata_update.uri = params.uri;
+    metadata_update.collection = params.collection;
+
+    // The changes account is unique per proposal, which keeps successive
+    // updates of the same token from being flagged as duplicates
+    let proposal = ProposalData {
+        id: proposal_id,
+        created_at: Clock::get()?.unix_timestamp,
+        created_by: *caller,
+        proposal_type: ProposalType::UpdateTokenMetadata,
+        approver_threshold: 1,
+        address: Some(metadata_update.key()),
+        dao_name: Some(token),
+        transfer_amount: None,
+        executor_type: ExecutorType::Admin,
+        approve_type: ApproverType::Admin,
+        status: ProposalStatus::Pending,
+        executed_at: None,
+        approvers: vec![],
+    };
+
+    ctx.accounts.proposals_list.add_proposal(proposal)?;
+
+    Ok(proposal_id)
+}
+
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct CreateUpdateTokenMetadataProposal<'info> {
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Account storing creator information
+    #[account(
+        seeds = [CREATOR_TAG],
+        bump,
+    )]
+    pub creators: Box<Account<'info, Creators>>,
+
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
+    #[account()]
+    pub metadata: AccountInfo<'info>,
+
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Account storing the metadata changes applied on execution
+    #[account(
+        init,
+        seeds = [
+            METADATA_UPDATE_TAG,
+            &(proposals_list.proposals.len() as u32 + 1).to_le_bytes()
+        ],
+        bump,
+        payer = signer,
+        space = 8 + MetadataUpdate::INIT_SPACE,
+    )]
+    pub metadata_update: Box<Account<'info, MetadataUpdate>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
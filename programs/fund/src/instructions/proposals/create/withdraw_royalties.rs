@@ -0,0 +1,32 @@
+use super::*;
+
+pub fn handler(
+    ctx: Context<CreateDaoProposal>,
+    token: String,
+    recipient: Pubkey,
+    amount: u64,
+) -> Result<u32> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    require!(amount > 0, CustomError::AmountCannotBeZero);
+
+    create_dao_proposal(
+        ctx,
+        ProposalType::WithdrawRoyalties,
+        Some(recipient),
+        3,
+        Some(token),
+        Some(amount),
+        ExecutorType::Admin,
+        ApproverType::Admin,
+    )
+}
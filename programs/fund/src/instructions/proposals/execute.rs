@@ -0,0 +1,101 @@
+use super::*;
+
+/// Function to execute an approved custom instruction proposal
+///
+/// Every stored instruction is invoked in order with the governance PDA as signer.
+/// All accounts referenced by the instructions, including the invoked programs,
+/// must be passed as remaining accounts.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `proposal_id` - Proposal Id.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    proposal_id: u32,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure valid executor
+    require!(
+        global_config.is_owner(&ctx.accounts.executor.key()),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::CustomInstruction)?;
+
+    // Ensure the instructions belong to this proposal
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(&ctx.accounts.proposal_instructions.key()),
+        CustomError::AccountMisMatch
+    );
+
+    // Persist the execution mark before invoking, so an instruction calling back
+    // into this program sees the proposal as executed
+    ctx.accounts.proposals_list.exit(&crate::ID)?;
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.governance.to_account_info());
+
+    let seeds = &[GOVERNANCE_TAG, &[ctx.bumps.governance]];
+    let signer = [&seeds[..]];
+
+    let instructions = &ctx.accounts.proposal_instructions.instructions;
+    for instruction in instructions.iter() {
+        invoke_signed(&instruction.to_instruction(), &account_infos, &signer)?;
+    }
+
+    // Pick up any changes made to the proposals by the invoked instructions
+    ctx.accounts.proposals_list.reload()?;
+
+    emit!(events::CustomProposalExecuted {
+        proposal_id,
+        instructions: instructions.len() as u32,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u32)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Account storing the instructions of the proposal
+    #[account(
+        seeds = [PROPOSAL_INSTRUCTIONS_TAG, &proposal_id.to_le_bytes()],
+        bump,
+    )]
+    pub proposal_instructions: Box<Account<'info, ProposalInstructions>>,
+
+    /// CHECK: Governance PDA signing the proposal's instructions
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_TAG],
+        bump,
+    )]
+    pub governance: AccountInfo<'info>,
+
+    /// The executor responsible for the transaction
+    #[account(mut)]
+    pub executor: Signer<'info>,
+}
@@ -2,7 +2,9 @@ use super::*;
 
 pub mod approve;
 pub mod create;
+pub mod execute;
 pub mod get_proposal_data;
 pub mod reject;
+pub mod view;
 
-pub use self::{approve::*, create::*, get_proposal_data::*};
+pub use self::{approve::*, create::*, execute::*, get_proposal_data::*, view::*};
@@ -15,6 +15,10 @@ pub fn handler(ctx: Context<ApproveProposal>, proposal_id: u32) -> Result<bool>
         .find(|p| p.id == proposal_id)
         .ok_or(CustomError::NotFound)?;
 
+    require!(
+        proposal.approve_type != ApproverType::TokenHolders,
+        CustomError::TokenHolderProposal
+    );
     require!(!proposal.is_executed(), CustomError::AlreadyExecuted);
 
     proposal.rejected()?;
@@ -0,0 +1,44 @@
+use super::*;
+
+/// Function to list proposal ids matching the given filters
+///
+/// Results are paginated with `offset` and `limit`, and the page size is capped
+/// at `MAX_PROPOSALS_PAGE_SIZE` to stay within the return data limit.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `params` - The filters and pagination settings.
+///
+/// # Returns
+/// * `Result<Vec<u32>>` - Ids of the matching proposals.
+pub fn list_proposals(
+    ctx: Context<ViewProposals>,
+    params: structs::list_proposals::Params,
+) -> Result<Vec<u32>> {
+    Ok(ctx.accounts.proposals_list.list_proposals(&params))
+}
+
+/// Function to read a proposal without marking it as executed
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `proposal_id` - Proposal Id.
+///
+/// # Returns
+/// * `Result<ProposalData>` - The proposal if found, otherwise returns an error.
+pub fn peek_proposal(ctx: Context<ViewProposals>, proposal_id: u32) -> Result<ProposalData> {
+    let proposal = ctx.accounts.proposals_list.get_proposal(proposal_id)?;
+
+    Ok(proposal.clone())
+}
+
+/// Struct defining the accounts required for reading proposals
+#[derive(Accounts)]
+#[instruction()]
+pub struct ViewProposals<'info> {
+    #[account(
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+}
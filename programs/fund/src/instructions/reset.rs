@@ -1,11 +1,43 @@
 use super::*;
 
-pub fn handler(ctx: Context<ResetAccount>) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
-    require!(
-        global_config.is_admin(&ctx.accounts.receiver.key()),
-        CustomError::Unauthorized
-    );
+/// Function to reset a program account, draining its lamports to the receiver
+///
+/// When the account belongs to a DAO, its mint is given along with the DAO's treasury
+/// ledger, which records the drain.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `mint` - Mint of the DAO the account belongs to, if any.
+pub fn handler(ctx: Context<ResetAccount>, mint: Option<Pubkey>) -> Result<()> {
+    // Ensure the receiver is allowed to reset accounts
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.receiver.key,
+        None,
+        permissions::RESET,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Ensure the ledger given is the one of the DAO
+    let treasury_ledger = match mint {
+        Some(mint) => {
+            let treasury_ledger = ctx
+                .accounts
+                .treasury_ledger
+                .as_ref()
+                .ok_or(CustomError::AccountMisMatch)?;
+            let (expected, _) =
+                Pubkey::find_program_address(&[TREASURY_TAG, mint.as_ref()], &crate::ID);
+            require_keys_eq!(
+                treasury_ledger.key(),
+                expected,
+                CustomError::AccountMisMatch
+            );
+            Some(treasury_ledger)
+        }
+        None => None,
+    };
 
     let account = &ctx.accounts.account;
     let receiver = &ctx.accounts.receiver;
@@ -18,6 +50,13 @@ pub fn handler(ctx: Context<ResetAccount>) -> Result<()> {
     // Mark account as empty by resizing data to 0
     account.realloc(0, false)?;
 
+    // Record the drain in the DAO's ledger, unless the ledger itself was reset
+    if let Some(treasury_ledger) = treasury_ledger {
+        if treasury_ledger.key() != account.key() {
+            record_treasury_flow(treasury_ledger, LedgerEntryKind::Reset, lamports)?;
+        }
+    }
+
     // Emit when an account is reset
     emit!(events::AccountReset {});
 
@@ -26,17 +65,28 @@ pub fn handler(ctx: Context<ResetAccount>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct ResetAccount<'info> {
-    /// Reference to the global configuration account
+    /// Global configuration account holding the sub-admin grants
     #[account(
         seeds = [GLOBAL_CONFIG_TAG],
         bump,
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, receiver.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
     /// CHECK: Account going to be reset
     #[account(mut)]
     pub account: AccountInfo<'info>,
 
+    /// CHECK: Treasury ledger of the DAO the account belongs to, checked against the mint given
+    #[account(mut)]
+    pub treasury_ledger: Option<AccountInfo<'info>>,
+
     #[account(mut)]
     pub receiver: Signer<'info>,
 }
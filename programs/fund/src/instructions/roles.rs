@@ -0,0 +1,130 @@
+use super::*;
+
+/// Function to create the role membership of an existing admin or sub-admin
+///
+/// Admins receive every permission and sub-admins the operational permissions
+/// they held before role memberships existed. Time-bounded or scoped grants
+/// do not receive a membership. Later changes go through
+/// `SetPermissions` proposals.
+pub fn migrate_role(ctx: Context<MigrateRole>, address: Pubkey) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    let permissions = if global_config.is_owner(&address) || global_config.is_admin(&address) {
+        permissions::ALL
+    } else if global_config.sub_admins.contains(&address) {
+        permissions::SUB_ADMIN
+    } else {
+        return Err(CustomError::NotFound.into());
+    };
+
+    let role_membership = &mut ctx.accounts.role_membership;
+    role_membership.address = address;
+    role_membership.permissions = permissions;
+
+    emit!(events::PermissionsUpdated {
+        address,
+        from: 0,
+        to: permissions
+    });
+
+    Ok(())
+}
+
+/// Function to set the permissions of an address once a `SetPermissions` proposal is approved
+/// Only the main admin is authorized to perform this action
+pub fn set_permissions(
+    ctx: Context<SetPermissions>,
+    proposal_id: u32,
+    address: Pubkey,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    require!(
+        global_config.is_owner(&ctx.accounts.executor.key()),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::SetPermissions)?;
+
+    // Ensure the proposal targets the given address
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(&address),
+        CustomError::AccountMisMatch
+    );
+
+    let role_membership = &mut ctx.accounts.role_membership;
+    let from = role_membership.permissions;
+    role_membership.address = address;
+    role_membership.permissions = proposal.transfer_amount.unwrap_or_default();
+
+    emit!(events::PermissionsUpdated {
+        address,
+        from,
+        to: role_membership.permissions
+    });
+
+    Ok(())
+}
+
+/// Accounts struct for migrating a legacy role
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct MigrateRole<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        init,
+        seeds = [ROLE_TAG, address.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + size_of::<RoleMembership>()
+    )]
+    pub role_membership: Box<Account<'info, RoleMembership>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for setting permissions
+#[derive(Accounts)]
+#[instruction(proposal_id: u32, address: Pubkey)]
+pub struct SetPermissions<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    #[account(
+        init_if_needed,
+        seeds = [ROLE_TAG, address.as_ref()],
+        bump,
+        payer = executor,
+        space = 8 + size_of::<RoleMembership>()
+    )]
+    pub role_membership: Box<Account<'info, RoleMembership>>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
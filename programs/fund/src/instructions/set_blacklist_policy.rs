@@ -0,0 +1,84 @@
+use super::*;
+
+/// Function to set the blacklist policy of a DAO once a `SetBlacklistPolicy` proposal is approved
+/// Only the owner is authorized to execute the proposal
+///
+/// # Arguments
+/// * `ctx` - The execution context containing all relevant accounts.
+/// * `token` - Token name of the DAO.
+/// * `proposal_id` - Proposal Id.
+pub fn handler(ctx: Context<SetBlacklistPolicy>, token: String, proposal_id: u32) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure valid executor
+    require!(
+        global_config.is_owner(ctx.accounts.authority.key),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::SetBlacklistPolicy)?;
+
+    // Ensure the proposal targets the given token
+    require!(
+        proposal.dao_name.ok_or(CustomError::NotFound)?.eq(&token),
+        CustomError::AccountMisMatch
+    );
+
+    let policy = proposal
+        .transfer_amount
+        .and_then(BlacklistPolicy::from_code)
+        .ok_or(CustomError::InvalidBlacklistPolicy)?;
+
+    let blacklist_policy = &mut ctx.accounts.blacklist_policy;
+    blacklist_policy.policy = policy;
+
+    emit!(events::BlacklistPolicyUpdated {
+        token: ctx.accounts.mint_account.key(),
+        policy,
+    });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for setting the blacklist policy of a DAO.
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct SetBlacklistPolicy<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Account storing the blacklist policy of the DAO
+    #[account(
+        init_if_needed,
+        seeds = [BLACKLIST_POLICY_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + size_of::<BlacklistPolicyConfig>()
+    )]
+    pub blacklist_policy: Box<Account<'info, BlacklistPolicyConfig>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+/// Function to publish or clear the Merkle root of a round's allowlist
+///
+/// Users allowlisted through the root commit by proving their leaf, so large
+/// rounds do not need to be stored in `Users`. A root can only be changed before
+/// its round starts, the round of a sale tier starting when the tier opens.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+/// * `user_type` - Round the root applies to.
+/// * `root` - The root to publish, or none to clear it.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(
+    ctx: Context<SetMerkleRoot>,
+    token: String,
+    user_type: UserType,
+    root: Option<[u8; 32]>,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+    let fund_store = &ctx.accounts.fund_data_store;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Ensure the caller is a sub-admin of this DAO
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            now,
+        ),
+        CustomError::Unauthorized
+    );
+
+    // Ensure the root is not replaced once its round has started
+    let sale_tiers = load_optional_account::<SaleTiers>(&ctx.accounts.sale_tiers)?;
+    require!(
+        !MerkleAllowlist::has_started(user_type, fund_store.status, sale_tiers.as_ref(), now),
+        CustomError::MerkleRootLocked
+    );
+
+    ctx.accounts.allowlist.set_root(user_type, root)?;
+
+    emit!(events::MerkleRootUpdated {
+        token,
+        user_type,
+        root
+    });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for publishing Merkle roots
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct SetMerkleRoot<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Account storing the Merkle roots of the DAO
+    #[account(
+        init_if_needed,
+        seeds = [ALLOWLIST_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = signer,
+        space = 8 + size_of::<MerkleAllowlist>()
+    )]
+    pub allowlist: Box<Account<'info, MerkleAllowlist>>,
+
+    /// Fund data store account.
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// CHECK: Sale tiers of the DAO, absent unless the DAO sells in tiers
+    #[account(
+        seeds = [SALE_TIER_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub sale_tiers: AccountInfo<'info>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+/// Function to configure the public round of a DAO
+///
+/// The public round opens the remaining allocation to every wallet, each
+/// capped at `per_wallet_cap` unless listed in `Users` with a higher cap. The
+/// configuration can only be changed before the public round starts.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+/// * `per_wallet_cap` - Maximum commitment of a wallet not listed in `Users`.
+/// * `single_commitment` - Whether a transaction may hold only one top-level commitment.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(
+    ctx: Context<SetPublicRound>,
+    token: String,
+    per_wallet_cap: u64,
+    single_commitment: bool,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+    let fund_store = &ctx.accounts.fund_data_store;
+
+    // Ensure the caller is a sub-admin of this DAO
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    // Ensure the configuration is not changed once the public round has started
+    require!(
+        !PublicRound::has_started(fund_store.status),
+        CustomError::PublicRoundLocked
+    );
+
+    require!(per_wallet_cap > 0, CustomError::AmountCannotBeZero);
+
+    let public_round = &mut ctx.accounts.public_round;
+    public_round.per_wallet_cap = per_wallet_cap;
+    public_round.single_commitment = single_commitment;
+
+    emit!(events::PublicRoundUpdated {
+        token,
+        per_wallet_cap,
+        single_commitment
+    });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for configuring the public round
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct SetPublicRound<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Account storing the public round configuration of the DAO
+    #[account(
+        init_if_needed,
+        seeds = [PUBLIC_ROUND_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = signer,
+        space = 8 + size_of::<PublicRound>()
+    )]
+    pub public_round: Box<Account<'info, PublicRound>>,
+
+    /// Fund data store account.
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+/// Function to set the sale tiers of a DAO before it starts
+///
+/// A DAO with tiers raises through them instead of the VIP, party and public rounds.
+/// Each tier is a round of its own, open between its start and end times, at the
+/// tier's price and caps, to the users allowlisted through the tier's Merkle root,
+/// or to every wallet if the tier has none.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+/// * `tiers` - The tiers of the sale, ordered by time.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(
+    ctx: Context<SetSaleTiers>,
+    token: String,
+    tiers: Vec<structs::sale_tier::Params>,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+    let fund_store = &ctx.accounts.fund_data_store;
+
+    // Ensure the caller is a sub-admin of this DAO
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    // Tiers are fixed once the DAO has started
+    require!(
+        fund_store.status.eq(&Status::Created),
+        CustomError::PermissionDenied
+    );
+
+    let tokens_for_sale = fund_store.fundraising_goal as u128 * fund_store.tokens_per_sol as u128;
+    ctx.accounts.sale_tiers.set(&tiers, tokens_for_sale)?;
+
+    emit!(events::SaleTiersUpdated {
+        token,
+        tiers: ctx.accounts.sale_tiers.tiers.clone(),
+    });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for setting sale tiers
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct SetSaleTiers<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Account storing the sale tiers of the DAO
+    #[account(
+        init_if_needed,
+        seeds = [SALE_TIER_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = signer,
+        space = calc_sale_tiers_size(MAX_SALE_TIERS)
+    )]
+    pub sale_tiers: Box<Account<'info, SaleTiers>>,
+
+    /// Fund data store account.
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,49 @@
+use super::*;
+
+/// Function to set the bounds on the tokenomics of new DAOs
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `bounds` - The bounds to apply, or none to lift them.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(ctx: Context<SetTokenomicsBounds>, bounds: Option<TokenomicsBounds>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    // Ensure the caller is an admin
+    require!(
+        global_config.is_admin(ctx.accounts.signer.key),
+        CustomError::Unauthorized
+    );
+
+    require!(
+        bounds.as_ref().map_or(true, TokenomicsBounds::is_valid),
+        CustomError::InvalidTokenomics
+    );
+
+    global_config.tokenomics_bounds = bounds;
+
+    emit!(events::TokenomicsBoundsUpdated { bounds });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for setting the tokenomics bounds
+#[derive(Accounts)]
+pub struct SetTokenomicsBounds<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+        realloc = calc_global_config_size(global_config.get_len(), 0),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -17,14 +17,17 @@ use super::*;
 
 /// Handles the start of a DAO by updating the fund status and emitting an event.
 pub fn handler(ctx: Context<StartDao>, token: String) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
     let fund_store = &mut ctx.accounts.fund_data_store;
 
-    // Ensure the payer has sub-admin rights before proceeding
-    require!(
-        global_config.is_sub_admin(ctx.accounts.payer.key),
-        CustomError::Unauthorized
-    );
+    // Ensure the payer is allowed to start DAOs before proceeding
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.payer.key,
+        Some(&ctx.accounts.mint_account.key()),
+        permissions::START_DAO,
+        Clock::get()?.unix_timestamp,
+    )?;
 
     // Ensure that the fund status is in the correct state for starting a DAO
     require!(
@@ -32,8 +35,12 @@ pub fn handler(ctx: Context<StartDao>, token: String) -> Result<()> {
         CustomError::PermissionDenied
     );
 
-    // Update the fund status to indicate VIP fundraising has started
-    fund_store.update_status(Status::FundraisingVip)?;
+    // Update the fund status to indicate the tiered sale, or else VIP fundraising, has started
+    if load_optional_account::<SaleTiers>(&ctx.accounts.sale_tiers)?.is_some() {
+        fund_store.update_status(Status::Fundraising)?;
+    } else {
+        fund_store.update_status(Status::FundraisingVip)?;
+    }
 
     // Emit an event to notify the system that the DAO has been started
     emit!(events::DaoStarted { token: token });
@@ -45,13 +52,20 @@ pub fn handler(ctx: Context<StartDao>, token: String) -> Result<()> {
 #[derive(Accounts)]
 #[instruction(token: String)]
 pub struct StartDao<'info> {
-    /// Reference to the global configuration account
+    /// Global configuration account holding the sub-admin grants
     #[account(
         seeds = [GLOBAL_CONFIG_TAG],
         bump,
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
     /// Fund data store account.
     /// Stores details about the fundraising process, including the current status.
     #[account(
@@ -61,6 +75,13 @@ pub struct StartDao<'info> {
     )]
     pub fund_data_store: Box<Account<'info, FundDataStore>>,
 
+    /// CHECK: Sale tiers of the DAO, absent unless the DAO sells in tiers
+    #[account(
+        seeds = [SALE_TIER_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub sale_tiers: AccountInfo<'info>,
+
     /// Mint account associated with the DPIT token.
     /// This is used for identifying the token related to the DAO.
     /// CHECK: This account is used for reference and does not require verification.
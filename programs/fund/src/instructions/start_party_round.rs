@@ -26,7 +26,7 @@ pub fn handler(ctx: Context<StartPartRound>, token: String) -> Result<()> {
     // Ensure that the caller is an authorized creator before proceeding
     creators.is_creator(
         &ctx.accounts.payer.key(),
-        ctx.accounts.metadata.creators.clone(),
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
     )?;
 
     // Ensure that the fund status is in the correct state for starting party round
@@ -56,9 +56,9 @@ pub struct StartPartRound<'info> {
     )]
     pub creators: Box<Account<'info, Creators>>,
 
-    /// CHECK: Metadata account for the token
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
     #[account()]
-    pub metadata: Box<Account<'info, MetadataAccount>>,
+    pub metadata: AccountInfo<'info>,
 
     /// Fund data store account.
     /// Stores details about the fundraising process, including the current status.
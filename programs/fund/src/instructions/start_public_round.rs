@@ -0,0 +1,81 @@
+use super::*;
+
+/// Starts the public round of a DAO, following its party round.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(ctx: Context<StartPublicRound>, token: String) -> Result<()> {
+    let creators = &ctx.accounts.creators;
+    let fund_store = &mut ctx.accounts.fund_data_store;
+
+    // Ensure that the caller is an authorized creator before proceeding
+    creators.is_creator(
+        &ctx.accounts.payer.key(),
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
+    )?;
+
+    // Ensure that the fund status is in the correct state for starting public round
+    require!(
+        fund_store.status.eq(&Status::FundraisingParty),
+        CustomError::PermissionDenied
+    );
+
+    // Update the fund status to indicate public round fundraising has started
+    fund_store.update_status(Status::FundraisingPublic)?;
+
+    // Emit an event to notify the system that the public round has started
+    emit!(events::PublicRoundStarted { token });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for starting the public round.
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct StartPublicRound<'info> {
+    /// Creators account.
+    /// Stores information about the DAO creators and their permissions.
+    #[account(
+        seeds = [CREATOR_TAG],
+        bump,
+    )]
+    pub creators: Box<Account<'info, Creators>>,
+
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
+    #[account()]
+    pub metadata: AccountInfo<'info>,
+
+    /// Public round configuration, which must be set before the round starts.
+    #[account(
+        seeds = [PUBLIC_ROUND_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub public_round: Box<Account<'info, PublicRound>>,
+
+    /// Fund data store account.
+    /// Stores details about the fundraising process, including the current status.
+    #[account(
+        mut,
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Mint account associated with the DPIT token.
+    /// This is used for identifying the token related to the DAO.
+    /// CHECK: This account is used for reference and does not require verification.
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The payer (signer) initiating the public round.
+    /// Must be an authorized creator to perform this action.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
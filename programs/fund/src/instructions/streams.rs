@@ -0,0 +1,331 @@
+use super::*;
+
+/// Function to set the period over which approved creator and deployer payouts of a DAO are streamed
+///
+/// Payouts approved afterwards are added to the recipient's stream, the ones
+/// approved while the period is zero being paid at once.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+/// * `period` - Streaming period in seconds, zero to pay approved payouts at once.
+pub fn set_stream_period(ctx: Context<SetStreamPeriod>, token: String, period: i64) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure the caller is a sub-admin of this DAO
+    require!(
+        global_config.is_sub_admin(
+            ctx.accounts.signer.key,
+            Some(&ctx.accounts.mint_account.key()),
+            Clock::get()?.unix_timestamp,
+        ),
+        CustomError::Unauthorized
+    );
+
+    require!(period >= 0, CustomError::InvalidStreamPeriod);
+
+    ctx.accounts.stream_config.period = period;
+
+    emit!(events::StreamPeriodUpdated { token, period });
+
+    Ok(())
+}
+
+/// Function to withdraw the vested amount of a payout stream to its recipient
+///
+/// Anyone can trigger the withdrawal, unless the DAO failed, was closed or is
+/// refunding its committers. Creator streams are split between the
+/// DAO's co-creators by their shares, the co-creators other than the recipient
+/// being passed as remaining accounts.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `token` - Token name of the DAO.
+/// * `kind` - Whether the recipient is paid as the creator or as a deployer.
+pub fn withdraw_stream<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawStream<'info>>,
+    _token: String,
+    kind: PayoutKind,
+) -> Result<()> {
+    let mint_key = ctx.accounts.mint_account.key();
+
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts.pause_config.is_paused(&mint_key),
+        CustomError::Paused
+    );
+
+    // Ensure the DAO is not refunding its committers, streams stopping with the payouts
+    require!(
+        !matches!(
+            ctx.accounts.fund_data_store.status,
+            Status::FundraisingFail | Status::Closed | Status::Refunding
+        ),
+        CustomError::InValidDaoStatus
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let amount = ctx.accounts.payout_stream.withdraw(now)?;
+
+    // Define signer seeds for the escrow SOL account
+    let seeds = &[
+        ESCROW_TAG,
+        SOL_TAG,
+        mint_key.as_ref(),
+        &[ctx.bumps.escrow_sol_account],
+    ];
+    let signer = [&seeds[..]];
+
+    let ledger_entry_kind = match kind {
+        PayoutKind::Creator => {
+            transfer_to_creators(
+                &ctx.accounts.fund_data_store,
+                amount,
+                &ctx.accounts.recipient,
+                ctx.remaining_accounts,
+                &ctx.accounts.escrow_sol_account,
+                &ctx.accounts.system_program.to_account_info(),
+                &signer,
+            )?;
+            LedgerEntryKind::CreatorPayout
+        }
+        PayoutKind::Deployer => {
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.escrow_sol_account.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            };
+
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    cpi_accounts,
+                    &signer,
+                ),
+                amount,
+            )?;
+            LedgerEntryKind::DeployerPayout
+        }
+    };
+    record_treasury_flow(&ctx.accounts.treasury_ledger, ledger_entry_kind, amount)?;
+
+    emit!(events::StreamWithdrawn {
+        recipient: ctx.accounts.recipient.key(),
+        kind,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Function to cancel the rest of a payout stream once a `CancelStream` proposal is approved
+/// Only the owner is authorized to execute the proposal
+///
+/// The amount vested so far remains withdrawable, the unvested amount being
+/// returned to the creators' or deployers' withdrawable share.
+///
+/// # Arguments
+/// * `ctx` - The execution context containing all relevant accounts.
+/// * `token` - Token name of the DAO.
+/// * `proposal_id` - Proposal Id.
+/// * `kind` - Whether the recipient is paid as the creator or as a deployer.
+pub fn cancel_stream(
+    ctx: Context<CancelStream>,
+    token: String,
+    proposal_id: u32,
+    kind: PayoutKind,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure valid executor
+    require!(
+        global_config.is_owner(ctx.accounts.authority.key),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::CancelStream)?;
+
+    // Ensure the proposal targets the given token and stream
+    require!(
+        proposal.dao_name.ok_or(CustomError::NotFound)?.eq(&token),
+        CustomError::AccountMisMatch
+    );
+    let recipient = ctx.accounts.recipient.key;
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(recipient),
+        CustomError::AccountMisMatch
+    );
+    require!(
+        proposal.transfer_amount.and_then(PayoutKind::from_code) == Some(kind),
+        CustomError::AccountMisMatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let unvested = ctx.accounts.payout_stream.cancel(now);
+
+    let fund_store = &mut ctx.accounts.fund_data_store;
+    match kind {
+        PayoutKind::Creator => fund_store.return_creator_amount(unvested),
+        PayoutKind::Deployer => fund_store.return_deployer_amount(recipient, unvested),
+    }
+
+    emit!(events::StreamCancelled {
+        recipient: *recipient,
+        kind,
+        unvested,
+    });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for setting the stream period of a DAO.
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct SetStreamPeriod<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Account storing the stream period of the DAO
+    #[account(
+        init_if_needed,
+        seeds = [STREAM_TAG, mint_account.key().as_ref()],
+        bump,
+        payer = signer,
+        space = 8 + size_of::<StreamConfig>()
+    )]
+    pub stream_config: Box<Account<'info, StreamConfig>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Struct defining the accounts required for withdrawing from a payout stream.
+#[derive(Accounts)]
+#[instruction(token: String, kind: PayoutKind)]
+pub struct WithdrawStream<'info> {
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    /// Stream of the payouts approved for the recipient
+    #[account(
+        mut,
+        seeds = [
+            STREAM_TAG,
+            kind.seed(),
+            mint_account.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump,
+    )]
+    pub payout_stream: Box<Account<'info, PayoutStream>>,
+
+    /// CHECK: Escrow SOL account where committed SOL is stored
+    #[account(
+        mut,
+        seeds = [ESCROW_TAG, SOL_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_sol_account: AccountInfo<'info>,
+
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
+    /// Fund data store of the DAO
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Recipient of the stream, checked through the stream seeds
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Struct defining the accounts required for cancelling a payout stream.
+#[derive(Accounts)]
+#[instruction(token: String, proposal_id: u32, kind: PayoutKind)]
+pub struct CancelStream<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Stream of the payouts approved for the recipient
+    #[account(
+        mut,
+        seeds = [
+            STREAM_TAG,
+            kind.seed(),
+            mint_account.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump,
+    )]
+    pub payout_stream: Box<Account<'info, PayoutStream>>,
+
+    /// Fund data store of the DAO
+    #[account(
+        mut,
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Recipient of the stream, checked through the stream seeds
+    pub recipient: AccountInfo<'info>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,348 @@
+use super::*;
+
+/// Function to update the metadata of a DAO token once an `UpdateTokenMetadata`
+/// proposal has been approved
+///
+/// The changes stored with the proposal are applied to the Metaplex metadata of
+/// legacy tokens, or to the native metadata of Token-2022 tokens, signed by the
+/// mint PDA as update authority. The executor tops up the rent of Token-2022 mints
+/// growing with their metadata, and is refunded the rent of the changes account,
+/// closed once applied.
+///
+/// # Arguments
+/// * `ctx` - The execution context containing all relevant accounts.
+/// * `token` - Token name of the DAO.
+/// * `proposal_id` - Proposal Id.
+pub fn update_token_metadata(
+    ctx: Context<UpdateTokenMetadata>,
+    token: String,
+    proposal_id: u32,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure valid executor
+    require!(
+        global_config.is_admin(ctx.accounts.authority.key),
+        CustomError::Unauthorized
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::UpdateTokenMetadata)?;
+
+    // Ensure the proposal targets the given token and changes
+    require!(
+        proposal.dao_name.ok_or(CustomError::NotFound)?.eq(&token),
+        CustomError::AccountMisMatch
+    );
+    let metadata_update = &ctx.accounts.metadata_update;
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(&metadata_update.key()),
+        CustomError::AccountMisMatch
+    );
+    let mint = ctx.accounts.mint_account.key();
+    require_keys_eq!(metadata_update.mint, mint, CustomError::AccountMisMatch);
+
+    require!(
+        is_metadata_mutable(&ctx.accounts.metadata, &mint)?,
+        CustomError::MetadataLocked
+    );
+
+    let seeds = &[MINT_TAG, token.as_bytes(), &[ctx.bumps.mint_account]];
+    let signer = [&seeds[..]];
+
+    if ctx.accounts.metadata.key() == mint {
+        ctx.accounts.update_native_metadata(&signer)?;
+    } else {
+        ctx.accounts.update_metaplex_metadata(&signer)?;
+    }
+
+    emit!(events::TokenMetadataUpdated {
+        token,
+        proposal_id,
+        uri: ctx.accounts.metadata_update.uri.clone(),
+    });
+
+    Ok(())
+}
+
+/// Function to lock the metadata of a graduated DAO token for good
+/// Only the creator of the DAO or the owner is authorized to lock the metadata
+///
+/// Metaplex metadata is marked immutable, while the update authority of the
+/// native metadata of Token-2022 tokens is removed.
+///
+/// # Arguments
+/// * `ctx` - The execution context containing all relevant accounts.
+/// * `token` - Token name of the DAO.
+pub fn lock_metadata(ctx: Context<LockMetadata>, token: String) -> Result<()> {
+    let caller = ctx.accounts.signer.key;
+
+    // Ensure the caller is the creator or the owner
+    require!(
+        ctx.accounts.fund_data_store.created_by.eq(caller)
+            || ctx.accounts.global_config.is_owner(caller),
+        CustomError::Unauthorized
+    );
+
+    // Ensure the DAO has graduated
+    require!(
+        ctx.accounts.fund_data_store.status.eq(&Status::Trade),
+        CustomError::DaoNotInTrading
+    );
+
+    let mint = ctx.accounts.mint_account.to_account_info();
+    require!(
+        is_metadata_mutable(&ctx.accounts.metadata, mint.key)?,
+        CustomError::MetadataLocked
+    );
+
+    let seeds = &[MINT_TAG, token.as_bytes(), &[ctx.bumps.mint_account]];
+    let signer = [&seeds[..]];
+
+    if ctx.accounts.metadata.key == mint.key {
+        token_metadata_update_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateAuthority {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint.clone(),
+                    current_authority: mint,
+                    new_authority: ctx.accounts.signer.to_account_info(),
+                },
+                &signer,
+            ),
+            OptionalNonZeroPubkey::default(),
+        )?;
+    } else {
+        update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                UpdateMetadataAccountsV2 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    update_authority: mint,
+                },
+                &signer,
+            ),
+            None,
+            None,
+            None,
+            Some(false),
+        )?;
+    }
+
+    emit!(events::MetadataLocked {
+        token,
+        locked_by: *caller,
+    });
+
+    Ok(())
+}
+
+impl<'info> UpdateTokenMetadata<'info> {
+    /// Applies the proposed changes to the Metaplex metadata of a legacy token
+    ///
+    /// The fields left unchanged are carried over from the current metadata, the
+    /// collection being recorded unverified.
+    fn update_metaplex_metadata(&self, signer: &[&[&[u8]]]) -> Result<()> {
+        let current = load_optional_account::<MetadataAccount>(&self.metadata)?
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+        require_keys_eq!(
+            current.mint,
+            self.mint_account.key(),
+            CustomError::AccountMisMatch
+        );
+
+        let update = &self.metadata_update;
+        let data = DataV2 {
+            name: update
+                .name
+                .clone()
+                .unwrap_or_else(|| current.name.trim_end_matches('\0').to_string()),
+            symbol: update
+                .symbol
+                .clone()
+                .unwrap_or_else(|| current.symbol.trim_end_matches('\0').to_string()),
+            uri: update
+                .uri
+                .clone()
+                .unwrap_or_else(|| current.uri.trim_end_matches('\0').to_string()),
+            seller_fee_basis_points: current.seller_fee_basis_points,
+            creators: current.creators.clone(),
+            collection: update
+                .collection
+                .map(|key| Collection {
+                    verified: false,
+                    key,
+                })
+                .or_else(|| current.collection.clone()),
+            uses: current.uses.clone(),
+        };
+
+        update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                self.token_metadata_program.to_account_info(),
+                UpdateMetadataAccountsV2 {
+                    metadata: self.metadata.to_account_info(),
+                    update_authority: self.mint_account.to_account_info(),
+                },
+                signer,
+            ),
+            None,
+            Some(data),
+            None,
+            None,
+        )
+    }
+
+    /// Applies the proposed changes to the native metadata of a Token-2022 token
+    ///
+    /// The mint is first topped up to stay rent exempt once its metadata grows.
+    fn update_native_metadata(&self, signer: &[&[&[u8]]]) -> Result<()> {
+        let update = &self.metadata_update;
+        let fields: Vec<(Field, String)> = [
+            (Field::Name, update.name.clone()),
+            (Field::Symbol, update.symbol.clone()),
+            (Field::Uri, update.uri.clone()),
+            (
+                Field::Key(COLLECTION_METADATA_KEY.to_string()),
+                update.collection.map(|key| key.to_string()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(field, value)| value.map(|value| (field, value)))
+        .collect();
+
+        let mint = self.mint_account.to_account_info();
+
+        // Fund the rent of the metadata once updated
+        let top_up = Rent::get()?
+            .minimum_balance(calc_native_metadata_len(&mint, &fields)?)
+            .saturating_sub(mint.lamports());
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.authority.to_account_info(),
+                        to: mint.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        for (field, value) in fields {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        token_program_id: self.token_program.to_account_info(),
+                        metadata: mint.clone(),
+                        update_authority: mint.clone(),
+                    },
+                    signer,
+                ),
+                field,
+                value,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Struct defining the accounts required for updating the metadata of a DAO token.
+#[derive(Accounts)]
+#[instruction(token: String, proposal_id: u32)]
+pub struct UpdateTokenMetadata<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Account storing the metadata changes of the proposal, closed once applied
+    #[account(
+        mut,
+        seeds = [METADATA_UPDATE_TAG, &proposal_id.to_le_bytes()],
+        bump,
+        close = authority,
+    )]
+    pub metadata_update: Box<Account<'info, MetadataUpdate>>,
+
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
+    #[account(mut)]
+    pub metadata: AccountInfo<'info>,
+
+    /// Mint account of the DAO, the update authority of its metadata
+    #[account(
+        mut,
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Admin executing the proposal, paying for the metadata growth of Token-2022 mints
+    /// and refunded the rent of the changes account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Metaplex Token Metadata Program
+    pub token_metadata_program: Program<'info, Metadata>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
+
+/// Struct defining the accounts required for locking the metadata of a DAO token.
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct LockMetadata<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        seeds = [FUND_DATA_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub fund_data_store: Box<Account<'info, FundDataStore>>,
+
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
+    #[account(mut)]
+    pub metadata: AccountInfo<'info>,
+
+    /// Mint account of the DAO, the update authority of its metadata
+    #[account(
+        mut,
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Creator of the DAO or owner locking the metadata
+    pub signer: Signer<'info>,
+
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Metaplex Token Metadata Program
+    pub token_metadata_program: Program<'info, Metadata>,
+}
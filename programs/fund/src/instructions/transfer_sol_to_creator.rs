@@ -1,22 +1,50 @@
 use super::*;
 
-pub fn handler(
-    ctx: Context<TransferSolToCreator>,
+/// Function to withdraw creator SOL from the escrow once a `TransferSolToCreator`
+/// proposal has been approved
+///
+/// The amount is split between the DAO's co-creators by their shares, the
+/// co-creators other than `creator_address` being passed as remaining accounts.
+/// If the DAO streams its payouts, the amount is added to the creator's stream
+/// instead, to be withdrawn as it vests.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferSolToCreator<'info>>,
     params: structs::transfer::Params,
 ) -> Result<()> {
-    let global_config = &ctx.accounts.global_config;
     let fund_store = &mut ctx.accounts.fund_data_store;
     let proposals_list = &mut ctx.accounts.proposals_list;
 
-    // Ensure the caller has sub-admin rights before proceeding
+    // Ensure the caller is allowed to execute treasury transfers before proceeding
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.payer.key,
+        Some(&ctx.accounts.mint_account.key()),
+        permissions::TREASURY,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Ensure the DAO is not paused
     require!(
-        global_config.is_sub_admin(ctx.accounts.payer.key),
-        CustomError::Unauthorized
+        !ctx.accounts
+            .pause_config
+            .is_paused(&ctx.accounts.mint_account.key()),
+        CustomError::Paused
     );
 
     let proposal = proposals_list
         .perform_execution(params.proposal_id, &ProposalType::TransferSolToCreator)?;
 
+    // Ensure the proposal targets the given token
+    require!(
+        proposal
+            .dao_name
+            .as_ref()
+            .ok_or(CustomError::NotFound)?
+            .eq(&params.token),
+        CustomError::AccountMisMatch
+    );
+
     let creator_address = ctx.accounts.creator_address.key;
     // Ensure valid creator
     require!(
@@ -32,58 +60,80 @@ pub fn handler(
         CustomError::DaoNotInTrading
     );
 
+    // Creator funds gated by milestones are released through them only
+    fund_store.require_no_milestones()?;
+
     let withdraw_amount = proposal.transfer_amount.unwrap_or_default();
     require!(withdraw_amount > 0, CustomError::AmountCannotBeZero);
 
     fund_store.check_creator_withdrawl(withdraw_amount)?;
-    fund_store.update_creator_amount(withdraw_amount)?;
 
-    let mint_key = ctx.accounts.mint_account.key();
-
-    // Define signer seeds for the escrow SOL account
-    let seeds = &[
-        ESCROW_TAG,
-        SOL_TAG,
-        mint_key.as_ref(),
-        &[ctx.bumps.escrow_sol_account],
-    ];
-    let signer = [&seeds[..]];
-
-    // Prepare a Solana system program transfer instruction
-    let cpi_accounts = system_program::Transfer {
-        from: ctx.accounts.escrow_sol_account.to_account_info(), // Source: Escrow account
-        to: ctx.accounts.creator_address.to_account_info(),      // Destination: Recipient's account
-    };
-
-    // Execute the SOL transfer from the escrow account to the recipient
-    system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            cpi_accounts,
-            &signer,
-        ),
-        withdraw_amount, // Amount of SOL to transfer
-    )?;
+    // Ensure the creator is one of the co-creators, before the amount is streamed to it
+    fund_store.split_creator_amount(withdraw_amount, *creator_address)?;
 
-    // Emit a withdrawal event for logging and tracking purposes
-    emit!(events::TransferredSolToCreator {
-        creator: *creator_address,
-        amount: withdraw_amount
-    });
+    fund_store.update_creator_amount(withdraw_amount)?;
 
-    Ok(())
+    // Stream the amount if the DAO streams its payouts, otherwise pay it at once
+    let period = load_optional_account::<StreamConfig>(&ctx.accounts.stream_config)?
+        .map_or(0, |stream_config| stream_config.period);
+    if period > 0 {
+        let mint_key = ctx.accounts.mint_account.key();
+        let payout_stream = &ctx.accounts.payout_stream;
+        let mut stream = load_payout_stream(
+            payout_stream,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &[
+                STREAM_TAG,
+                PayoutKind::Creator.seed(),
+                mint_key.as_ref(),
+                creator_address.as_ref(),
+                &[ctx.bumps.payout_stream],
+            ],
+        )?;
+        stream.add(
+            *creator_address,
+            PayoutKind::Creator,
+            withdraw_amount,
+            Clock::get()?.unix_timestamp,
+            period,
+        );
+        stream.try_serialize(&mut &mut payout_stream.try_borrow_mut_data()?[..])?;
+
+        emit!(events::PayoutStreamed {
+            recipient: *creator_address,
+            kind: PayoutKind::Creator,
+            amount: withdraw_amount,
+            end_at: stream.end_at,
+        });
+
+        return Ok(());
+    }
+
+    ctx.accounts.pay_creators(
+        ctx.remaining_accounts,
+        withdraw_amount,
+        ctx.bumps.escrow_sol_account,
+    )
 }
 
 #[derive(Accounts)]
 #[instruction(params: structs::transfer::Params)]
 pub struct TransferSolToCreator<'info> {
-    /// Global configuration storing administrative permissions
+    /// Global configuration account holding the sub-admin grants
     #[account(
         seeds = [GLOBAL_CONFIG_TAG],
         bump,
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
     #[account(
         mut,
         seeds = [PROPOSAL_TAG],
@@ -91,6 +141,13 @@ pub struct TransferSolToCreator<'info> {
     )]
     pub proposals_list: Account<'info, ProposalsList>,
 
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
     /// The escrow account storing SOL before it is transferred
     /// CHECK: Escrow SOL account where committed SOL is stored
     #[account(
@@ -100,6 +157,34 @@ pub struct TransferSolToCreator<'info> {
     )]
     pub escrow_sol_account: AccountInfo<'info>,
 
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
+    /// CHECK: Stream period of the DAO, absent unless payouts are streamed
+    #[account(
+        seeds = [STREAM_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub stream_config: AccountInfo<'info>,
+
+    /// CHECK: Stream of the payouts approved for the creator, created on its first streamed payout
+    #[account(
+        mut,
+        seeds = [
+            STREAM_TAG,
+            PayoutKind::Creator.seed(),
+            mint_account.key().as_ref(),
+            creator_address.key().as_ref()
+        ],
+        bump,
+    )]
+    pub payout_stream: AccountInfo<'info>,
+
     /// The fund data store tracking fundraising status
     #[account(
         mut,
@@ -131,3 +216,40 @@ pub struct TransferSolToCreator<'info> {
     /// The Solana System program
     pub system_program: Program<'info, System>,
 }
+
+impl<'info> TransferSolToCreator<'info> {
+    /// Pays an amount from the escrow to the creators, split by the co-creators' shares.
+    ///
+    /// # Arguments
+    /// * `co_creators` - Accounts of the co-creators other than `creator_address`.
+    /// * `withdraw_amount` - The amount to pay.
+    /// * `escrow_bump` - Bump of the escrow SOL account.
+    pub fn pay_creators(
+        &self,
+        co_creators: &[AccountInfo<'info>],
+        withdraw_amount: u64,
+        escrow_bump: u8,
+    ) -> Result<()> {
+        let mint_key = self.mint_account.key();
+
+        // Define signer seeds for the escrow SOL account
+        let seeds = &[ESCROW_TAG, SOL_TAG, mint_key.as_ref(), &[escrow_bump]];
+        let signer = [&seeds[..]];
+
+        transfer_to_creators(
+            &self.fund_data_store,
+            withdraw_amount,
+            &self.creator_address,
+            co_creators,
+            &self.escrow_sol_account,
+            &self.system_program.to_account_info(),
+            &signer,
+        )?;
+
+        record_treasury_flow(
+            &self.treasury_ledger,
+            LedgerEntryKind::CreatorPayout,
+            withdraw_amount,
+        )
+    }
+}
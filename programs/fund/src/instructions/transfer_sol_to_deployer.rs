@@ -3,6 +3,8 @@ use super::*;
 /// Function to transfer tokens or SOL from the escrow account to a recipient.
 /// This function ensures that only authorized sub-admins can perform transfers
 /// and that the DAO fundraising goal has been met before transferring funds.
+/// If the DAO streams its payouts, the amount is added to the deployer's stream
+/// instead, to be withdrawn as it vests.
 ///
 /// # Arguments
 /// * `ctx` - The execution context containing all relevant accounts
@@ -22,12 +24,30 @@ pub fn handler(
     // Ensure the caller has creator's rights
     creator.is_creator(
         ctx.accounts.payer.key,
-        ctx.accounts.metadata.creators.clone(),
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
     )?;
 
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts
+            .pause_config
+            .is_paused(&ctx.accounts.mint_account.key()),
+        CustomError::Paused
+    );
+
     let proposal = proposals_list
         .perform_execution(params.proposal_id, &ProposalType::TransferSolToDeployer)?;
 
+    // Ensure the proposal targets the given token
+    require!(
+        proposal
+            .dao_name
+            .as_ref()
+            .ok_or(CustomError::NotFound)?
+            .eq(&params.token),
+        CustomError::AccountMisMatch
+    );
+
     // Ensure that the DAO has reached the fundraising success status
     require!(
         fund_store.status.eq(&Status::FundraisingSuccess) || fund_store.status.eq(&Status::Trade),
@@ -73,6 +93,43 @@ pub fn handler(
     let fund_store = &mut ctx.accounts.fund_data_store;
     fund_store.update_deployer_amount(*deployer_address, amount)?;
 
+    // Stream the amount if the DAO streams its payouts, otherwise pay it at once
+    let period = load_optional_account::<StreamConfig>(&ctx.accounts.stream_config)?
+        .map_or(0, |stream_config| stream_config.period);
+    if period > 0 {
+        let mint_key = ctx.accounts.mint_account.key();
+        let payout_stream = &ctx.accounts.payout_stream;
+        let mut stream = load_payout_stream(
+            payout_stream,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &[
+                STREAM_TAG,
+                PayoutKind::Deployer.seed(),
+                mint_key.as_ref(),
+                deployer_address.as_ref(),
+                &[ctx.bumps.payout_stream],
+            ],
+        )?;
+        stream.add(
+            *deployer_address,
+            PayoutKind::Deployer,
+            amount,
+            Clock::get()?.unix_timestamp,
+            period,
+        );
+        stream.try_serialize(&mut &mut payout_stream.try_borrow_mut_data()?[..])?;
+
+        emit!(events::PayoutStreamed {
+            recipient: *deployer_address,
+            kind: PayoutKind::Deployer,
+            amount,
+            end_at: stream.end_at,
+        });
+
+        return Ok(());
+    }
+
     // Execute the SOL transfer from escrow to recipient
     system_program::transfer(
         CpiContext::new_with_signer(
@@ -82,6 +139,11 @@ pub fn handler(
         ),
         amount,
     )?;
+    record_treasury_flow(
+        &ctx.accounts.treasury_ledger,
+        LedgerEntryKind::DeployerPayout,
+        amount,
+    )?;
 
     // Emit a transfer event for logging purposes
     emit!(events::TransferredSolToDeployer {
@@ -109,6 +171,13 @@ pub struct TransferSolToDeployer<'info> {
     )]
     pub proposals_list: Account<'info, ProposalsList>,
 
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
     /// The escrow account storing SOL before it is transferred
     /// CHECK: Escrow SOL account where committed SOL is stored
     #[account(
@@ -118,6 +187,34 @@ pub struct TransferSolToDeployer<'info> {
     )]
     pub escrow_sol_account: AccountInfo<'info>,
 
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
+    /// CHECK: Stream period of the DAO, absent unless payouts are streamed
+    #[account(
+        seeds = [STREAM_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub stream_config: AccountInfo<'info>,
+
+    /// CHECK: Stream of the payouts approved for the deployer, created on its first streamed payout
+    #[account(
+        mut,
+        seeds = [
+            STREAM_TAG,
+            PayoutKind::Deployer.seed(),
+            mint_account.key().as_ref(),
+            deployer_address.key().as_ref()
+        ],
+        bump,
+    )]
+    pub payout_stream: AccountInfo<'info>,
+
     /// The fund data store tracking fundraising status
     #[account(
         mut,
@@ -134,9 +231,9 @@ pub struct TransferSolToDeployer<'info> {
     )]
     pub mint_account: Box<InterfaceAccount<'info, Mint>>,
 
-    /// CHECK: Metadata account for the token
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
     #[account()]
-    pub metadata: Box<Account<'info, MetadataAccount>>,
+    pub metadata: AccountInfo<'info>,
 
     /// The recipient's account that will receive the SOL or tokens
     /// CHECK: This is the user's main account.
@@ -1,15 +1,65 @@
 use super::*;
 
-/// Function to manage (add/remove) users
-/// This function allows creators to add or remove VIP or Party users based on the given parameters.
+/// Function to unblock a user in every DAO once an `UnblockUser` proposal is approved
+///
+/// The marker of the user is closed and its rent returned to the executer.
+///
+/// # Arguments
+/// * `ctx` - The program execution context containing all required accounts.
+/// * `proposal_id` - Proposal Id.
+/// * `user` - Address unblocked by the proposal.
+///
+/// # Returns
+/// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
+pub fn handler(ctx: Context<UnblockUser>, proposal_id: u32, user: Pubkey) -> Result<()> {
+    let proposals_list = &mut ctx.accounts.proposals_list;
+    let global_config = &ctx.accounts.global_config;
+
+    // Ensure valid executer
+    require!(
+        global_config.is_owner(&ctx.accounts.executer.key()),
+        CustomError::Unauthorized
+    );
+
+    let proposal = proposals_list.perform_execution(proposal_id, &ProposalType::UnblockUser)?;
+
+    // Scoped proposals are executed through `unblock_dao_user`
+    require!(
+        proposal.dao_name.is_none(),
+        CustomError::InvalidProposalType
+    );
+
+    // Ensure the proposal targets the given user
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(&user),
+        CustomError::AccountMisMatch
+    );
+
+    // Emit event to log the user block action
+    emit!(events::UserUnblocked { user, mint: None });
+
+    Ok(())
+}
+
+/// Function to unblock a user in a single DAO once an `UnblockUser` proposal scoped to it is approved
 ///
 /// # Arguments
 /// * `ctx` - The program execution context containing all required accounts.
-/// * `params` - Parameters specifying user management actions (user type, action type, and user list).
+/// * `token` - Token name of the DAO.
+/// * `proposal_id` - Proposal Id.
+/// * `user` - Address unblocked by the proposal.
 ///
 /// # Returns
 /// * `Result<()>` - Returns an Ok result if successful, otherwise returns an error.
-pub fn handler(ctx: Context<BlacklistUser>, proposal_id: u32) -> Result<()> {
+pub fn dao_handler(
+    ctx: Context<UnblockDaoUser>,
+    token: String,
+    proposal_id: u32,
+    user: Pubkey,
+) -> Result<()> {
     let proposals_list = &mut ctx.accounts.proposals_list;
     let global_config = &ctx.accounts.global_config;
 
@@ -21,13 +71,97 @@ pub fn handler(ctx: Context<BlacklistUser>, proposal_id: u32) -> Result<()> {
 
     let proposal = proposals_list.perform_execution(proposal_id, &ProposalType::UnblockUser)?;
 
-    let user = proposal.address.ok_or(CustomError::AddressNotFound)?;
+    // Ensure the proposal is scoped to this DAO
+    require!(
+        proposal.dao_name == Some(token),
+        CustomError::InvalidProposalType
+    );
 
-    let blacklist = &mut ctx.accounts.blacklist;
-    blacklist.remove_user(user)?;
+    // Ensure the proposal targets the given user
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(&user),
+        CustomError::AccountMisMatch
+    );
 
     // Emit event to log the user block action
-    emit!(events::UserUnblocked { user });
+    emit!(events::UserUnblocked {
+        user,
+        mint: Some(ctx.accounts.mint_account.key())
+    });
 
     Ok(())
 }
+
+/// Struct defining the accounts required for unblocking users in every DAO
+#[derive(Accounts)]
+#[instruction(proposal_id: u32, user: Pubkey)]
+pub struct UnblockUser<'info> {
+    /// Reference to the global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Marker of the user blocked in every DAO
+    #[account(
+        mut,
+        seeds = [BLACKLIST_ENTRY_TAG, user.as_ref()],
+        bump,
+        close = executer,
+    )]
+    pub blacklist_entry: Box<Account<'info, BlacklistEntry>>,
+
+    /// The executer responsible for the transaction
+    #[account(mut)]
+    pub executer: Signer<'info>,
+}
+
+/// Struct defining the accounts required for unblocking users in a single DAO
+#[derive(Accounts)]
+#[instruction(token: String, proposal_id: u32, user: Pubkey)]
+pub struct UnblockDaoUser<'info> {
+    /// Reference to the global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Marker of the user blocked in the DAO
+    #[account(
+        mut,
+        seeds = [BLACKLIST_ENTRY_TAG, mint_account.key().as_ref(), user.as_ref()],
+        bump,
+        close = executer,
+    )]
+    pub blacklist_entry: Box<Account<'info, BlacklistEntry>>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The executer responsible for the transaction
+    #[account(mut)]
+    pub executer: Signer<'info>,
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+/// Function to update the default fee percentage of a creator
+///
+/// The default fee is applied to the DAOs the creator launches afterwards, the fees
+/// of its existing DAOs being changed through `update_fees`.
+///
+/// # Parameters
+/// - `ctx`: Execution context containing all necessary accounts
+/// - `params`: Struct containing the address of the creator and its new fee percentage
+///
+/// # Returns
+/// - `Result<()>`: Indicates success or failure of the operation
+pub fn handler(ctx: Context<UpdateCreatorFee>, params: structs::add_creator::Params) -> Result<()> {
+    // Ensure that the payer is allowed to manage creators before updating the fee
+    require_permission(
+        ctx.accounts.role_membership.as_deref(),
+        &ctx.accounts.global_config,
+        ctx.accounts.payer.key,
+        None,
+        permissions::MANAGE_CREATORS,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Ensure the address belongs to a registered creator
+    require!(
+        ctx.accounts.creators.in_creator_list(&params.address),
+        CustomError::NotFound
+    );
+
+    let creator_info = &mut ctx.accounts.creator_info;
+    let from = creator_info.fee_percent;
+    creator_info.fee_percent = params.fee_percent;
+
+    // Emit an event indicating the creator's default fee has been updated
+    emit!(events::CreatorFeeUpdated {
+        creator: params.address,
+        from,
+        to: params.fee_percent,
+    });
+
+    Ok(())
+}
+
+/// Account structure for updating the default fee of a creator
+#[derive(Accounts)]
+#[instruction(params: structs::add_creator::Params)]
+pub struct UpdateCreatorFee<'info> {
+    /// Reference to the global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Role membership of the signer, if it has one
+    #[account(
+        seeds = [ROLE_TAG, payer.key().as_ref()],
+        bump,
+    )]
+    pub role_membership: Option<Account<'info, RoleMembership>>,
+
+    /// The account storing the list of creators
+    #[account(
+        seeds = [CREATOR_TAG],
+        bump,
+    )]
+    pub creators: Box<Account<'info, Creators>>,
+
+    /// Account containing the creator's default fee percentage
+    #[account(
+        mut,
+        seeds = [CREATOR_TAG, params.address.as_ref()],
+        bump,
+    )]
+    pub creator_info: Box<Account<'info, CreatorInfo>>,
+
+    /// The payer who is executing the transaction (must hold the `MANAGE_CREATORS` permission)
+    pub payer: Signer<'info>,
+}
@@ -2,23 +2,39 @@ use super::*;
 
 /// Function to update the fee collection account
 ///
-/// This function updates the `fees_collection_account` field of the `FeeAccount`.
-/// It ensures that only sub-admins are authorized to perform this action.
+/// This function updates the `fees_collection_account` field of the `FeeAccount`
+/// once an `UpdateFeeAccount` proposal has been approved.
+/// Only the owner is authorized to execute the proposal, unless the governance PDA
+/// applies the account of an approved custom instruction proposal.
 ///
 /// # Arguments
 /// * `ctx` - The context containing all account information
-/// * `fee_collection_account` - The new fee collection account public key
+/// * `proposal_id` - Id of the approved proposal, unused when executed by the governance PDA
+/// * `fees_collection_account` - Account applied when executed by the governance PDA
 ///
 /// # Errors
-/// * `Unauthorized` - If the caller is not a sub-admin
-pub fn handler(ctx: Context<UpdateFeeAccount>, fee_collection_account: Pubkey) -> Result<()> {
+/// * `Unauthorized` - If the caller is neither the owner nor the governance PDA
+pub fn handler(
+    ctx: Context<UpdateFeeAccount>,
+    proposal_id: u32,
+    fees_collection_account: Option<Pubkey>,
+) -> Result<()> {
     let global_config = &ctx.accounts.global_config;
+    let authority = ctx.accounts.authority.key;
 
-    // Ensure the caller has sub-admin rights
-    require!(
-        global_config.is_sub_admin(&ctx.accounts.authority.key),
-        CustomError::Unauthorized
-    );
+    let fee_collection_account = if is_governance(authority) {
+        // The approved custom instruction proposal carries the new account itself
+        fees_collection_account.ok_or(CustomError::MissingGovernanceValue)?
+    } else {
+        // Ensure valid executor
+        require!(global_config.is_owner(authority), CustomError::Unauthorized);
+
+        let proposal = ctx
+            .accounts
+            .proposals_list
+            .perform_execution(proposal_id, &ProposalType::UpdateFeeAccount)?;
+        proposal.address.ok_or(CustomError::AddressNotFound)?
+    };
 
     // Retrieve and update the fee collection account
     let fee_account = &mut ctx.accounts.fee_account;
@@ -45,6 +61,13 @@ pub struct UpdateFeeAccount<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
     /// The fee account storing fee collection details
     #[account(
         mut,
@@ -53,7 +76,7 @@ pub struct UpdateFeeAccount<'info> {
     )]
     pub fee_account: Box<Account<'info, FeeAccount>>,
 
-    /// The authority (must be a signer) requesting the fee account update
+    /// The authority (the owner or the governance PDA) executing the fee account update
     #[account(mut)]
     pub authority: Signer<'info>,
 
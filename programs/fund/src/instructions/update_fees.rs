@@ -2,38 +2,81 @@ use super::*;
 
 /// Function to update fees in the system.
 ///
-/// This function updates the fee percentage for a specific fund and its creator.
-/// Only sub-admins are authorized to perform this operation.
+/// This function updates the fee percentage for a specific fund and its creator's
+/// record of that fund once an `UpdateFees` proposal has been approved. The
+/// creator's default fee, applied to its future DAOs, is left unchanged.
+/// Only the owner is authorized to execute the proposal, unless the governance PDA
+/// applies the fee of an approved custom instruction proposal.
 ///
 /// # Arguments
 /// * `ctx` - The execution context containing all relevant accounts.
-/// * `params` - The struct containing the new fee percentage.
+/// * `params` - The struct containing the creator, token and proposal id.
 ///
 /// # Errors
-/// * `CustomError::Unauthorized` - If the caller is not a sub-admin.
+/// * `CustomError::Unauthorized` - If the caller is neither the owner nor the governance PDA.
+/// * `CustomError::AccountMisMatch` - If the address is not the creator of the DAO.
 pub fn handler(ctx: Context<UpdateFees>, params: structs::update_fee::Params) -> Result<()> {
     let global_config = &ctx.accounts.global_config;
+    let authority = ctx.accounts.authority.key;
 
-    // Ensure that the caller has sub-admin rights before proceeding
-    require!(
-        global_config.is_sub_admin(&ctx.accounts.authority.key),
-        CustomError::Unauthorized
-    );
+    let fee_percent = if is_governance(authority) {
+        // The approved custom instruction proposal carries the new fee itself
+        params
+            .fee_percent
+            .ok_or(CustomError::MissingGovernanceValue)?
+    } else {
+        // Ensure valid executor
+        require!(global_config.is_owner(authority), CustomError::Unauthorized);
+
+        let proposal = ctx
+            .accounts
+            .proposals_list
+            .perform_execution(params.proposal_id, &ProposalType::UpdateFees)?;
+
+        // Ensure the proposal targets the given creator and token
+        require!(
+            proposal
+                .address
+                .ok_or(CustomError::AddressNotFound)?
+                .eq(&params.address),
+            CustomError::AccountMisMatch
+        );
+        require!(
+            proposal
+                .dao_name
+                .ok_or(CustomError::NotFound)?
+                .eq(&params.token),
+            CustomError::AccountMisMatch
+        );
+
+        proposal.transfer_amount.unwrap_or_default() as u32
+    };
 
     // Retrieve the fund data store and update the fee percentage
     let fund_store = &mut ctx.accounts.fund_data_store;
+
+    // Ensure the record updated is the one of the DAO's creator
+    require!(
+        params.address.eq(&fund_store.created_by),
+        CustomError::AccountMisMatch
+    );
+
     let from = fund_store.fee_percent; // Store the previous fee percentage
-    fund_store.fee_percent = params.fee_percent; // Update to the new fee percentage
+    fund_store.fee_percent = fee_percent; // Update to the new fee percentage
 
-    // Update the fee percentage for the creator as well
-    let creator_info = &mut ctx.accounts.creator_info;
-    creator_info.fee_percent = params.fee_percent;
+    // Update the fee percentage of the creator's record of the DAO as well, created
+    // for DAOs launched before records were kept per DAO
+    let creator_dao_info = &mut ctx.accounts.creator_dao_info;
+    creator_dao_info.creator = params.address;
+    creator_dao_info.mint = ctx.accounts.mint_account.key();
+    creator_dao_info.token = params.token;
+    creator_dao_info.fee_percent = fee_percent;
 
     // Emit an event to notify that the fee percentage has been updated
     emit!(events::FeeUpdated {
         token: ctx.accounts.mint_account.key(),
         from: from,
-        to: params.fee_percent,
+        to: fee_percent,
     });
 
     Ok(())
@@ -44,21 +87,30 @@ pub fn handler(ctx: Context<UpdateFees>, params: structs::update_fee::Params) ->
 #[instruction(params: structs::update_fee::Params)]
 pub struct UpdateFees<'info> {
     /// Global configuration account.
-    /// This holds the list of sub-admins and other global settings.
+    /// This holds the owner and other global settings.
     #[account(
         seeds = [GLOBAL_CONFIG_TAG],
         bump
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
-    /// Creator information account.
-    /// This stores information about the creator, including their fee percentage.
     #[account(
         mut,
-        seeds = [CREATOR_TAG, params.address.as_ref()],
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// Creator information account of the DAO.
+    /// This stores information about the creator of the DAO, including their fee percentage.
+    #[account(
+        init_if_needed,
+        seeds = [CREATOR_TAG, params.address.as_ref(), mint_account.key().as_ref()],
         bump,
+        space = calc_creator_dao_info_size(),
+        payer = authority,
     )]
-    pub creator_info: Box<Account<'info, CreatorInfo>>,
+    pub creator_dao_info: Box<Account<'info, CreatorDaoInfo>>,
 
     /// Fund data store account.
     /// This account stores fundraising details, including fee percentages.
@@ -78,7 +130,7 @@ pub struct UpdateFees<'info> {
     )]
     pub mint_account: Box<InterfaceAccount<'info, Mint>>,
 
-    /// The signer who must be a sub-admin to authorize this action.
+    /// The signer who must be the owner or the governance PDA to execute the update.
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -24,7 +24,7 @@ pub fn handler(ctx: Context<UpdateStatus>, params: structs::update_status::Param
     // Ensure that the caller has creator rights before proceeding
     creators.is_creator(
         &ctx.accounts.payer.key(),
-        ctx.accounts.metadata.creators.clone(),
+        load_token_creators(&ctx.accounts.metadata, &ctx.accounts.mint_account.key())?,
     )?;
 
     // Update the status of the fund
@@ -69,9 +69,9 @@ pub struct UpdateStatus<'info> {
     )]
     pub mint_account: Box<InterfaceAccount<'info, Mint>>,
 
-    /// CHECK: Metadata account for the token
+    /// CHECK: Metadata account for the token, or the mint itself for Token-2022 tokens
     #[account()]
-    pub metadata: Box<Account<'info, MetadataAccount>>,
+    pub metadata: AccountInfo<'info>,
 
     /// The signer who must be an authorized creator to update the status.
     #[account(mut)]
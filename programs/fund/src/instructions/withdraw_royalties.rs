@@ -0,0 +1,176 @@
+use super::*;
+
+/// Function to withdraw royalties from the DAO treasury once a `WithdrawRoyalties` proposal is approved
+/// Only the owner is authorized to execute the proposal
+///
+/// The royalties collected from transfer fees are held in the treasury token account
+/// of the escrow SOL account, and transferred to the recipient of the proposal.
+///
+/// # Arguments
+/// * `ctx` - The execution context, the accounts of the mint's transfer hook being passed as remaining accounts.
+/// * `token` - Token name of the DAO.
+/// * `proposal_id` - Proposal Id.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawRoyalties<'info>>,
+    token: String,
+    proposal_id: u32,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+    let mint_key = ctx.accounts.mint_account.key();
+
+    // Ensure valid executor
+    require!(
+        global_config.is_owner(ctx.accounts.authority.key),
+        CustomError::Unauthorized
+    );
+
+    // Ensure the DAO is not paused
+    require!(
+        !ctx.accounts.pause_config.is_paused(&mint_key),
+        CustomError::Paused
+    );
+
+    let proposal = ctx
+        .accounts
+        .proposals_list
+        .perform_execution(proposal_id, &ProposalType::WithdrawRoyalties)?;
+
+    // Ensure the proposal targets the given token and recipient
+    require!(
+        proposal.dao_name.ok_or(CustomError::NotFound)?.eq(&token),
+        CustomError::AccountMisMatch
+    );
+    let recipient = ctx.accounts.recipient.key();
+    require!(
+        proposal
+            .address
+            .ok_or(CustomError::AddressNotFound)?
+            .eq(&recipient),
+        CustomError::AccountMisMatch
+    );
+
+    let amount = proposal.transfer_amount.unwrap_or_default();
+    require!(
+        amount <= ctx.accounts.treasury_token_account.amount,
+        CustomError::ExceedsWithdrawLimit
+    );
+
+    // Define signer seeds for the escrow SOL account, holding the treasury token account
+    let seeds = &[
+        ESCROW_TAG,
+        SOL_TAG,
+        mint_key.as_ref(),
+        &[ctx.bumps.escrow_sol_account],
+    ];
+    let signer = [&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury_token_account.to_account_info(),
+        mint: ctx.accounts.mint_account.to_account_info(),
+        to: ctx.accounts.recipient_ata.to_account_info(),
+        authority: ctx.accounts.escrow_sol_account.to_account_info(),
+    };
+
+    transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint_account.decimals,
+        &signer,
+    )?;
+    record_treasury_flow(
+        &ctx.accounts.treasury_ledger,
+        LedgerEntryKind::RoyaltyWithdrawal,
+        amount,
+    )?;
+
+    emit!(events::RoyaltiesWithdrawn {
+        token,
+        recipient,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Struct defining the accounts required for withdrawing royalties from the treasury.
+#[derive(Accounts)]
+#[instruction(token: String)]
+pub struct WithdrawRoyalties<'info> {
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Account storing the pause flags
+    #[account(
+        seeds = [PAUSE_TAG],
+        bump,
+    )]
+    pub pause_config: Box<Account<'info, PauseConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_TAG],
+        bump,
+    )]
+    pub proposals_list: Account<'info, ProposalsList>,
+
+    /// CHECK: Escrow SOL account of the DAO, holding its treasury
+    #[account(
+        seeds = [ESCROW_TAG, SOL_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_sol_account: AccountInfo<'info>,
+
+    /// Treasury token account holding the collected royalties
+    #[account(
+        mut,
+        associated_token::mint = mint_account,
+        associated_token::authority = escrow_sol_account,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Treasury ledger of the DAO, absent for DAOs initialised before it
+    #[account(
+        mut,
+        seeds = [TREASURY_TAG, mint_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury_ledger: AccountInfo<'info>,
+
+    /// Mint account of the DAO
+    #[account(
+        seeds = [MINT_TAG, token.as_bytes()],
+        bump,
+    )]
+    pub mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Recipient's associated token account
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint_account,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Recipient of the royalties, checked against the proposal
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Solana Token-2022 Program
+    pub token_program: Program<'info, Token2022>,
+
+    /// Program for handling associated token accounts
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
@@ -5,24 +5,66 @@
 use crate::{constants::*, enums::*, errors::*, helper::*, instructions::*, states::*, utils::*};
 use anchor_lang::{
     prelude::*,
-    solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, rent::Rent},
-    system_program,
+    solana_program::{
+        account_info::AccountInfo,
+        entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
+        keccak,
+        program::{invoke, invoke_signed},
+        rent::Rent,
+        sysvar::instructions as tx_instructions,
+    },
+    system_program, Discriminator,
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
     metadata::{
         create_metadata_accounts_v3,
-        mpl_token_metadata::{self, types::DataV2},
-        CreateMetadataAccountsV3, Metadata, MetadataAccount,
+        mpl_token_metadata::{
+            self,
+            types::{Collection, DataV2},
+        },
+        update_metadata_accounts_v2, CreateMetadataAccountsV3, Metadata, MetadataAccount,
+        UpdateMetadataAccountsV2,
+    },
+    token_interface::{
+        self, harvest_withheld_tokens_to_mint, metadata_pointer_initialize, set_authority,
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_2022::{
+            self,
+            extension::{
+                transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType,
+                StateWithExtensions,
+            },
+        },
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_authority, token_metadata_update_field,
+        transfer_fee_initialize, transfer_hook_initialize, withdraw_withheld_tokens_from_mint,
+        Burn, HarvestWithheldTokensToMint, InitializeMint2, MetadataPointerInitialize, Mint,
+        MintTo, SetAuthority, Token2022, TokenAccount, TokenInterface, TokenMetadataInitialize,
+        TokenMetadataUpdateAuthority, TokenMetadataUpdateField, TransferChecked,
+        TransferFeeInitialize, TransferHookInitialize, WithdrawWithheldTokensFromMint,
     },
-    token::{self, set_authority, Burn, MintTo, SetAuthority, Token, Transfer},
-    token_interface::{Mint, TokenAccount},
 };
-pub use enums::{AmountType, ProposalType};
+pub use constants::{
+    BLACKLIST_ENTRY_TAG, BLACKLIST_POLICY_TAG, BLACKLIST_TAG, ESCROW_TAG, GOVERNANCE_TAG, MINT_TAG,
+    TRANSFER_HOOK_PROGRAM_ID,
+};
+pub use enums::{
+    AmountType, BlacklistAction, BlacklistPolicy, PayoutKind, ProposalState, ProposalType,
+};
+pub use helper::{is_governance, require_allowed_by_blacklist};
 use spl_token::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
-pub use states::{ProposalData, VestingPercent};
+pub use states::{
+    CoCreator, ProposalAccountMeta, ProposalData, ProposalInstruction, Tokenomics,
+    TokenomicsBounds, TreasuryBreakdown, VestingPercent,
+};
 use std::{collections::HashSet, mem::size_of};
-pub use structs::{create::Params as CreateParams, mint::Params as MintParams};
+pub use structs::{
+    create::Params as CreateParams, metadata_update::Params as MetadataUpdateParams,
+    milestone::Params as MilestoneParams, mint::Params as MintParams,
+    token_extensions::Params as TokenExtensionsParams,
+};
 
 mod constants; // Program constants and configuration values
 mod enums; // Enum definitions for program states
@@ -30,6 +72,7 @@ pub mod errors; // Custom error definitions
 mod events; // Event definitions for logging
 mod helper; // Helper functions
 mod instructions; // Instruction handlers
+pub mod permissions; // Role permission flags
 mod states; // Program state definitions
 mod structs; // Data structure definitions
 mod utils; // Util Libraries
@@ -58,6 +101,11 @@ pub mod fund {
         init_commitment::handler(ctx, token)
     }
 
+    /// Initialize the treasury ledger of a DAO created before ledgers were kept
+    pub fn init_treasury_ledger(ctx: Context<InitTreasuryLedger>, token: String) -> Result<()> {
+        init_treasury_ledger::handler(ctx, token)
+    }
+
     /// Initialize user accounts with maximum allowable commit amount
     pub fn init_users(ctx: Context<InitUsers>, token: String) -> Result<()> {
         init_users::handler(ctx, token)
@@ -68,28 +116,37 @@ pub mod fund {
         init_multisig::handler(ctx)
     }
 
-    /// Update the owner address for the program
+    /// Nominate a new owner for the program through an approved proposal
     pub fn update_owner(ctx: Context<UpdateGlobalConfig>, proposal_id: u32) -> Result<()> {
         maintainers::update_owner(ctx, proposal_id)
     }
 
-    /// Add multiple sub-admin accounts to the program
-    pub fn add_sub_admin_accounts(
-        ctx: Context<UpdateSubAdmins>,
-        addresses: Vec<Pubkey>,
-    ) -> Result<()> {
-        maintainers::add_sub_admins(ctx, addresses)
+    /// Accept the ownership as the owner nominated through an approved proposal
+    pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+        maintainers::accept_ownership(ctx)
     }
 
-    /// Remove multiple sub-admin accounts from the program
-    pub fn remove_sub_admin_accounts(
-        ctx: Context<UpdateSubAdmins>,
-        addresses: Vec<Pubkey>,
-    ) -> Result<()> {
-        maintainers::remove_sub_admins(ctx, addresses)
+    /// Cancel a pending ownership transfer
+    pub fn cancel_ownership_transfer(ctx: Context<UpdateOwnership>) -> Result<()> {
+        maintainers::cancel_ownership_transfer(ctx)
+    }
+
+    /// Add a sub-admin account to the program through an approved proposal
+    pub fn add_sub_admin_accounts(ctx: Context<UpdateSubAdmins>, proposal_id: u32) -> Result<()> {
+        maintainers::add_sub_admin(ctx, proposal_id)
+    }
+
+    /// Grant a time-bounded or DAO-scoped sub-admin role through an approved proposal
+    pub fn grant_sub_admin(ctx: Context<GrantSubAdmin>, proposal_id: u32) -> Result<()> {
+        maintainers::grant_sub_admin(ctx, proposal_id)
+    }
+
+    /// Remove a sub-admin account from the program through an approved proposal
+    pub fn remove_sub_admin_accounts(ctx: Context<RemoveSubAdmin>, proposal_id: u32) -> Result<()> {
+        maintainers::remove_sub_admin(ctx, proposal_id)
     }
 
-    /// Update fee parameters for the program
+    /// Update fee parameters for the program through an approved proposal
     pub fn update_fees(
         ctx: Context<UpdateFees>,
         params: structs::update_fee::Params,
@@ -97,9 +154,13 @@ pub mod fund {
         update_fees::handler(ctx, params)
     }
 
-    /// Update the fee collection account address
-    pub fn update_fee_account(ctx: Context<UpdateFeeAccount>, fee_account: Pubkey) -> Result<()> {
-        update_fee_account::handler(ctx, fee_account)
+    /// Update the fee collection account address through an approved proposal
+    pub fn update_fee_account(
+        ctx: Context<UpdateFeeAccount>,
+        proposal_id: u32,
+        fees_collection_account: Option<Pubkey>,
+    ) -> Result<()> {
+        update_fee_account::handler(ctx, proposal_id, fees_collection_account)
     }
 
     /// Manage creator accounts and their permissions
@@ -110,6 +171,19 @@ pub mod fund {
         add_creator::handler(ctx, params)
     }
 
+    /// Rewrite the details of a creator stored with the legacy layout
+    pub fn migrate_creator_info(ctx: Context<MigrateCreatorInfo>, creator: Pubkey) -> Result<()> {
+        migrate_creator_info::handler(ctx, creator)
+    }
+
+    /// Update the default fee percentage applied to a creator's future DAOs
+    pub fn update_creator_fee(
+        ctx: Context<UpdateCreatorFee>,
+        params: structs::add_creator::Params,
+    ) -> Result<()> {
+        update_creator_fee::handler(ctx, params)
+    }
+
     /// Manage user accounts and their permissions
     pub fn manage_users(
         ctx: Context<ManageUsers>,
@@ -118,11 +192,27 @@ pub mod fund {
         manage_users::handler(ctx, params)
     }
 
+    /// Update the caps of listed users, remove them by address, or move them to the other type
+    pub fn update_users(
+        ctx: Context<UpdateUsers>,
+        params: structs::manage_users::UpdateParams,
+    ) -> Result<()> {
+        manage_users::update(ctx, params)
+    }
+
     /// Create a new token with specified parameters
     pub fn create(ctx: Context<CreateToken>, params: structs::create::Params) -> Result<()> {
         create::handler(ctx, params)
     }
 
+    /// Create a new Token-2022 token, its metadata being stored in the mint
+    pub fn create_token_2022(
+        ctx: Context<CreateToken2022>,
+        params: structs::create::Params,
+    ) -> Result<()> {
+        create::handler_token_2022(ctx, params)
+    }
+
     /// Mint additional tokens to a specified account
     pub fn mint(ctx: Context<MintToken>, params: structs::mint::Params) -> Result<()> {
         mint::handler(ctx, params)
@@ -136,6 +226,10 @@ pub mod fund {
         start_party_round::handler(ctx, token)
     }
 
+    pub fn start_public_round(ctx: Context<StartPublicRound>, token: String) -> Result<()> {
+        start_public_round::handler(ctx, token)
+    }
+
     pub fn end_dao(ctx: Context<EndDao>, token: String) -> Result<()> {
         end_dao::handler(ctx, token)
     }
@@ -149,6 +243,11 @@ pub mod fund {
         burn::handler(ctx, params)
     }
 
+    /// Burn claimed tokens of a stopped DAO, restoring their share of the refund
+    pub fn burn_to_refund(ctx: Context<BurnToRefund>, token: String, amount: u64) -> Result<()> {
+        burn_to_refund::handler(ctx, token, amount)
+    }
+
     /// Transfer tokens between accounts
     pub fn transfer_to_deployer(
         ctx: Context<TransferSolToDeployer>,
@@ -157,33 +256,147 @@ pub mod fund {
         transfer_sol_to_deployer::handler(ctx, params)
     }
 
-    pub fn transfer_sol_to_creator(
-        ctx: Context<TransferSolToCreator>,
+    pub fn transfer_sol_to_creator<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferSolToCreator<'info>>,
         params: structs::transfer::Params,
     ) -> Result<()> {
         transfer_sol_to_creator::handler(ctx, params)
     }
 
-    pub fn move_to_lp(ctx: Context<MoveToLP>, token: String) -> Result<()> {
+    /// Release the creator funds of the next milestone once approved
+    pub fn release_milestone<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferSolToCreator<'info>>,
+        params: structs::transfer::Params,
+    ) -> Result<()> {
+        milestones::release_milestone(ctx, params)
+    }
+
+    /// Fail a milestone once approved, refunding the escrow to committers
+    pub fn fail_milestone(
+        ctx: Context<FailMilestone>,
+        token: String,
+        proposal_id: u32,
+    ) -> Result<()> {
+        milestones::fail_milestone(ctx, token, proposal_id)
+    }
+
+    /// Set the period over which approved creator and deployer payouts are streamed
+    pub fn set_stream_period(
+        ctx: Context<SetStreamPeriod>,
+        token: String,
+        period: i64,
+    ) -> Result<()> {
+        streams::set_stream_period(ctx, token, period)
+    }
+
+    /// Withdraw the vested amount of a payout stream to its recipient
+    pub fn withdraw_stream<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawStream<'info>>,
+        token: String,
+        kind: PayoutKind,
+    ) -> Result<()> {
+        streams::withdraw_stream(ctx, token, kind)
+    }
+
+    /// Cancel the rest of a payout stream once approved
+    pub fn cancel_stream(
+        ctx: Context<CancelStream>,
+        token: String,
+        proposal_id: u32,
+        kind: PayoutKind,
+    ) -> Result<()> {
+        streams::cancel_stream(ctx, token, proposal_id, kind)
+    }
+
+    pub fn move_to_lp<'info>(
+        ctx: Context<'_, '_, '_, 'info, MoveToLP<'info>>,
+        token: String,
+    ) -> Result<()> {
         move_to_lp::handler(ctx, token)
     }
 
-    /// Record a commitment for a specific token
-    pub fn commitment(ctx: Context<Commitment>, token: String, sol_amount: u64) -> Result<()> {
-        commitment::handler(ctx, token, sol_amount)
+    /// Record a commitment for a specific token, proving an allowlisted allocation if passed
+    pub fn commitment(
+        ctx: Context<Commitment>,
+        token: String,
+        sol_amount: u64,
+        allocation: Option<structs::merkle_allocation::Params>,
+    ) -> Result<()> {
+        commitment::handler(ctx, token, sol_amount, allocation)
+    }
+
+    /// Publish or clear the Merkle root of a round's allowlist
+    pub fn set_merkle_root(
+        ctx: Context<SetMerkleRoot>,
+        token: String,
+        user_type: UserType,
+        root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        set_merkle_root::handler(ctx, token, user_type, root)
+    }
+
+    /// Set the sale tiers of a DAO before it starts
+    pub fn set_sale_tiers(
+        ctx: Context<SetSaleTiers>,
+        token: String,
+        tiers: Vec<structs::sale_tier::Params>,
+    ) -> Result<()> {
+        set_sale_tiers::handler(ctx, token, tiers)
+    }
+
+    /// Set the bounds on the tokenomics of new DAOs
+    pub fn set_tokenomics_bounds(
+        ctx: Context<SetTokenomicsBounds>,
+        bounds: Option<TokenomicsBounds>,
+    ) -> Result<()> {
+        set_tokenomics_bounds::handler(ctx, bounds)
+    }
+
+    /// Configure the public round of a DAO
+    pub fn set_public_round(
+        ctx: Context<SetPublicRound>,
+        token: String,
+        per_wallet_cap: u64,
+        single_commitment: bool,
+    ) -> Result<()> {
+        set_public_round::handler(ctx, token, per_wallet_cap, single_commitment)
     }
 
     /// Claim tokens based on recorded commitments
-    pub fn claim(ctx: Context<Claim>, token: String) -> Result<()> {
+    pub fn claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, Claim<'info>>,
+        token: String,
+    ) -> Result<()> {
         claim::handler(ctx, token)
     }
 
     /// Claim tokens based on recorded commitments
     /// TODO: day field is added for testing purpose, will be removed in future
-    pub fn claim_test(ctx: Context<Claim>, token: String, day: i64) -> Result<()> {
+    pub fn claim_test<'info>(
+        ctx: Context<'_, '_, '_, 'info, Claim<'info>>,
+        token: String,
+        day: i64,
+    ) -> Result<()> {
         claim::test(ctx, token, day)
     }
 
+    /// Collect the royalty withheld on transfers of a DAO token into the DAO treasury
+    pub fn collect_transfer_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectTransferFees<'info>>,
+        token: String,
+    ) -> Result<()> {
+        collect_transfer_fees::handler(ctx, token)
+    }
+
+    /// Withdraw royalties from the DAO treasury once approved
+    pub fn withdraw_royalties<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawRoyalties<'info>>,
+        token: String,
+        proposal_id: u32,
+    ) -> Result<()> {
+        withdraw_royalties::handler(ctx, token, proposal_id)
+    }
+
     /// Update the status of a token or account
     pub fn update_status(
         ctx: Context<UpdateStatus>,
@@ -203,13 +416,51 @@ pub mod fund {
     }
 
     /// Check wheather the given account has sub-admin rights or not
-    pub fn is_sub_admin(ctx: Context<HasRole>, address: Pubkey) -> Result<bool> {
-        has_role::is_sub_admin(ctx, &address)
+    pub fn is_sub_admin(
+        ctx: Context<HasRole>,
+        address: Pubkey,
+        mint: Option<Pubkey>,
+    ) -> Result<bool> {
+        has_role::is_sub_admin(ctx, &address, mint.as_ref())
     }
 
     /// Check wheather the given account has either admin or sub-admin rights or not
-    pub fn is_either_admin_or_sub_admin(ctx: Context<HasRole>, address: Pubkey) -> Result<bool> {
-        has_role::is_either_admin_or_sub_admin(ctx, &address)
+    pub fn is_either_admin_or_sub_admin(
+        ctx: Context<HasRole>,
+        address: Pubkey,
+        mint: Option<Pubkey>,
+    ) -> Result<bool> {
+        has_role::is_either_admin_or_sub_admin(ctx, &address, mint.as_ref())
+    }
+
+    /// Checks if the address holds every given permission flag
+    pub fn has_permission(
+        ctx: Context<HasPermission>,
+        address: Pubkey,
+        permission: u64,
+    ) -> Result<bool> {
+        has_role::has_permission(ctx, &address, permission)
+    }
+
+    /// Create the role membership of an existing admin or sub-admin
+    pub fn migrate_role(ctx: Context<MigrateRole>, address: Pubkey) -> Result<()> {
+        roles::migrate_role(ctx, address)
+    }
+
+    pub fn create_set_permissions_proposal(
+        ctx: Context<CreateProposal>,
+        address: Pubkey,
+        permissions: u64,
+    ) -> Result<u32> {
+        proposals::create::set_permissions::handler(ctx, address, permissions)
+    }
+
+    pub fn set_permissions(
+        ctx: Context<SetPermissions>,
+        proposal_id: u32,
+        address: Pubkey,
+    ) -> Result<()> {
+        roles::set_permissions(ctx, proposal_id, address)
     }
 
     /// Upgrade account
@@ -232,12 +483,12 @@ pub mod fund {
         proposals::create::remove_admin::handler(ctx, address)
     }
 
-    pub fn remove_admin(ctx: Context<UpdateGlobalConfig>, proposal_id: u32) -> Result<()> {
+    pub fn remove_admin(ctx: Context<RemoveAdmin>, proposal_id: u32) -> Result<()> {
         maintainers::remove_admin(ctx, proposal_id)
     }
 
-    pub fn reset(ctx: Context<ResetAccount>) -> Result<()> {
-        reset::handler(ctx)
+    pub fn reset(ctx: Context<ResetAccount>, mint: Option<Pubkey>) -> Result<()> {
+        reset::handler(ctx, mint)
     }
 
     pub fn create_transfer_sol_to_creator_proposal(
@@ -248,6 +499,40 @@ pub mod fund {
         proposals::create::transfer_sol_to_creator::handler(ctx, token, amount)
     }
 
+    pub fn create_release_milestone_proposal(
+        ctx: Context<CreateCreatorProposal>,
+        token: String,
+        milestone: u8,
+    ) -> Result<u32> {
+        proposals::create::release_milestone::handler(ctx, token, milestone)
+    }
+
+    pub fn create_fail_milestone_proposal(
+        ctx: Context<CreateDaoProposal>,
+        token: String,
+        milestone: u8,
+    ) -> Result<u32> {
+        proposals::create::fail_milestone::handler(ctx, token, milestone)
+    }
+
+    pub fn create_cancel_stream_proposal(
+        ctx: Context<CreateDaoProposal>,
+        token: String,
+        recipient: Pubkey,
+        kind: PayoutKind,
+    ) -> Result<u32> {
+        proposals::create::cancel_stream::handler(ctx, token, recipient, kind)
+    }
+
+    pub fn create_withdraw_royalties_proposal(
+        ctx: Context<CreateDaoProposal>,
+        token: String,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<u32> {
+        proposals::create::withdraw_royalties::handler(ctx, token, recipient, amount)
+    }
+
     pub fn create_transfer_sol_to_deployer_proposal(
         ctx: Context<CreateCreatorProposal>,
         token: String,
@@ -325,25 +610,85 @@ pub mod fund {
     }
 
     pub fn create_block_user_proposal(
-        ctx: Context<CreateProposal>,
-        address: Pubkey,
+        ctx: Context<CreateBlockUserProposal>,
+        params: structs::block_user::Params,
     ) -> Result<u32> {
-        proposals::create::block_user::handler(ctx, address)
+        proposals::create::block_user::handler(ctx, params)
     }
 
-    pub fn blacklist_user(ctx: Context<BlacklistUser>, proposal_id: u32) -> Result<()> {
-        block_user::handler(ctx, proposal_id)
+    pub fn blacklist_user(
+        ctx: Context<BlacklistUser>,
+        proposal_id: u32,
+        user: Pubkey,
+    ) -> Result<()> {
+        block_user::handler(ctx, proposal_id, user)
+    }
+
+    pub fn blacklist_dao_user(
+        ctx: Context<BlacklistDaoUser>,
+        token: String,
+        proposal_id: u32,
+        user: Pubkey,
+    ) -> Result<()> {
+        block_user::dao_handler(ctx, token, proposal_id, user)
     }
 
     pub fn create_unblock_user_proposal(
         ctx: Context<CreateProposal>,
         address: Pubkey,
+        token: Option<String>,
     ) -> Result<u32> {
-        proposals::create::unblock_user::handler(ctx, address)
+        proposals::create::unblock_user::handler(ctx, address, token)
     }
 
-    pub fn unblock_user(ctx: Context<BlacklistUser>, proposal_id: u32) -> Result<()> {
-        unblock_user::handler(ctx, proposal_id)
+    pub fn unblock_user(ctx: Context<UnblockUser>, proposal_id: u32, user: Pubkey) -> Result<()> {
+        unblock_user::handler(ctx, proposal_id, user)
+    }
+
+    pub fn unblock_dao_user(
+        ctx: Context<UnblockDaoUser>,
+        token: String,
+        proposal_id: u32,
+        user: Pubkey,
+    ) -> Result<()> {
+        unblock_user::dao_handler(ctx, token, proposal_id, user)
+    }
+
+    pub fn create_set_blacklist_policy_proposal(
+        ctx: Context<CreateDaoProposal>,
+        token: String,
+        policy: BlacklistPolicy,
+    ) -> Result<u32> {
+        proposals::create::set_blacklist_policy::handler(ctx, token, policy)
+    }
+
+    /// Set the blacklist policy of a DAO through an approved proposal
+    pub fn set_blacklist_policy(
+        ctx: Context<SetBlacklistPolicy>,
+        token: String,
+        proposal_id: u32,
+    ) -> Result<()> {
+        set_blacklist_policy::handler(ctx, token, proposal_id)
+    }
+
+    /// Move the entries of the global blacklist into marker accounts
+    pub fn migrate_blacklist<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateBlacklist<'info>>,
+    ) -> Result<()> {
+        migrate_blacklist::migrate_blacklist(ctx)
+    }
+
+    /// Move the entries of a DAO blacklist into marker accounts
+    pub fn migrate_dao_blacklist<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateDaoBlacklist<'info>>,
+        token: String,
+    ) -> Result<()> {
+        migrate_blacklist::migrate_dao_blacklist(ctx, token)
+    }
+
+    /// Rewrite the global configuration stored with the legacy layout
+    pub fn migrate_global_config(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+        migrate_global_config::handler(ctx)
     }
 
     pub fn create_publish_to_amm_proposal(
@@ -362,6 +707,94 @@ pub mod fund {
         proposals::create::remove_liquidity::handler(ctx, token, percent)
     }
 
+    pub fn create_custom_instruction_proposal(
+        ctx: Context<CreateCustomInstructionProposal>,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<u32> {
+        proposals::create::custom_instruction::handler(ctx, instructions)
+    }
+
+    pub fn create_update_token_metadata_proposal(
+        ctx: Context<CreateUpdateTokenMetadataProposal>,
+        token: String,
+        params: structs::metadata_update::Params,
+    ) -> Result<u32> {
+        proposals::create::update_token_metadata::handler(ctx, token, params)
+    }
+
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadata>,
+        token: String,
+        proposal_id: u32,
+    ) -> Result<()> {
+        token_metadata::update_token_metadata(ctx, token, proposal_id)
+    }
+
+    pub fn lock_metadata(ctx: Context<LockMetadata>, token: String) -> Result<()> {
+        token_metadata::lock_metadata(ctx, token)
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+        proposal_id: u32,
+    ) -> Result<()> {
+        proposals::execute::handler(ctx, proposal_id)
+    }
+
+    pub fn create_update_fees_proposal(
+        ctx: Context<CreateDaoProposal>,
+        token: String,
+        creator_address: Pubkey,
+        fee_percent: u32,
+    ) -> Result<u32> {
+        proposals::create::update_fees::handler(ctx, token, creator_address, fee_percent)
+    }
+
+    pub fn create_update_fee_account_proposal(
+        ctx: Context<CreateProposal>,
+        fee_account: Pubkey,
+    ) -> Result<u32> {
+        proposals::create::update_fee_account::handler(ctx, fee_account)
+    }
+
+    pub fn create_add_sub_admin_proposal(
+        ctx: Context<CreateProposal>,
+        sub_admin_address: Pubkey,
+    ) -> Result<u32> {
+        proposals::create::add_sub_admin::handler(ctx, sub_admin_address)
+    }
+
+    pub fn create_grant_sub_admin_proposal(
+        ctx: Context<CreateProposal>,
+        address: Pubkey,
+        token: Option<String>,
+        duration_days: Option<u64>,
+    ) -> Result<u32> {
+        proposals::create::grant_sub_admin::handler(ctx, address, token, duration_days)
+    }
+
+    pub fn create_remove_sub_admin_proposal(
+        ctx: Context<CreateProposal>,
+        sub_admin_address: Pubkey,
+    ) -> Result<u32> {
+        proposals::create::remove_sub_admin::handler(ctx, sub_admin_address)
+    }
+
+    pub fn create_update_trade_fees_proposal(
+        ctx: Context<CreateProposal>,
+        mint: Pubkey,
+        fee_percent: u32,
+    ) -> Result<u32> {
+        proposals::create::update_trade_fees::handler(ctx, mint, fee_percent)
+    }
+
+    pub fn create_update_trade_fee_account_proposal(
+        ctx: Context<CreateProposal>,
+        fee_account: Pubkey,
+    ) -> Result<u32> {
+        proposals::create::update_trade_fee_account::handler(ctx, fee_account)
+    }
+
     pub fn get_proposal_data(
         ctx: Context<GetProposalData>,
         proposal_id: u32,
@@ -370,8 +803,110 @@ pub mod fund {
         proposals::get_proposal_data::handler(ctx, proposal_id, proposal_type)
     }
 
-    pub fn is_user_blocked(ctx: Context<IsUserBlocked>, address: Pubkey) -> Result<()> {
-        is_user_blocked::handler(ctx, address)
+    /// View the SOL flows recorded by the treasury ledger of a DAO
+    pub fn get_treasury_breakdown(
+        ctx: Context<GetTreasuryBreakdown>,
+        token: String,
+    ) -> Result<TreasuryBreakdown> {
+        get_treasury_breakdown::handler(ctx, token)
+    }
+
+    pub fn list_proposals(
+        ctx: Context<ViewProposals>,
+        params: structs::list_proposals::Params,
+    ) -> Result<Vec<u32>> {
+        proposals::view::list_proposals(ctx, params)
+    }
+
+    pub fn peek_proposal(ctx: Context<ViewProposals>, proposal_id: u32) -> Result<ProposalData> {
+        proposals::view::peek_proposal(ctx, proposal_id)
+    }
+
+    pub fn is_user_blocked(
+        ctx: Context<IsUserBlocked>,
+        address: Pubkey,
+        mint: Pubkey,
+        action: BlacklistAction,
+    ) -> Result<()> {
+        is_user_blocked::handler(ctx, address, mint, action)
+    }
+
+    pub fn init_governance(
+        ctx: Context<InitGovernance>,
+        params: structs::init_governance::Params,
+    ) -> Result<()> {
+        governance::init::handler(ctx, params)
+    }
+
+    pub fn deposit_governance_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositGovernanceTokens<'info>>,
+        token: String,
+        amount: u64,
+    ) -> Result<()> {
+        governance::deposit::handler(ctx, token, amount)
+    }
+
+    pub fn withdraw_governance_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawGovernanceTokens<'info>>,
+        token: String,
+        amount: u64,
+    ) -> Result<()> {
+        governance::withdraw::handler(ctx, token, amount)
+    }
+
+    pub fn create_community_proposal(
+        ctx: Context<CreateCommunityProposal>,
+        params: structs::community_proposal::Params,
+    ) -> Result<u32> {
+        governance::create_proposal::handler(ctx, params)
+    }
+
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        token: String,
+        proposal_id: u32,
+        support: bool,
+    ) -> Result<()> {
+        governance::vote::handler(ctx, token, proposal_id, support)
+    }
+
+    pub fn finalize_community_proposal(
+        ctx: Context<FinalizeCommunityProposal>,
+        token: String,
+        proposal_id: u32,
+    ) -> Result<Option<u32>> {
+        governance::finalize::handler(ctx, token, proposal_id)
+    }
+
+    pub fn init_pause_config(ctx: Context<InitPauseConfig>) -> Result<()> {
+        pause::init_pause_config(ctx)
+    }
+
+    pub fn add_guardians(ctx: Context<UpdateGuardians>, addresses: Vec<Pubkey>) -> Result<()> {
+        pause::add_guardians(ctx, addresses)
+    }
+
+    pub fn remove_guardians(ctx: Context<UpdateGuardians>, addresses: Vec<Pubkey>) -> Result<()> {
+        pause::remove_guardians(ctx, addresses)
+    }
+
+    pub fn pause(ctx: Context<Pause>, mint: Option<Pubkey>) -> Result<()> {
+        pause::pause(ctx, mint)
+    }
+
+    pub fn create_unpause_proposal(
+        ctx: Context<CreateProposal>,
+        mint: Option<Pubkey>,
+    ) -> Result<u32> {
+        proposals::create::unpause::handler(ctx, mint)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, proposal_id: u32) -> Result<()> {
+        pause::unpause(ctx, proposal_id)
+    }
+
+    pub fn is_paused(ctx: Context<IsPaused>, mint: Pubkey) -> Result<()> {
+        is_paused::handler(ctx, mint)
     }
 
     pub fn approve_proposal(ctx: Context<ApproveProposal>, proposal_id: u32) -> Result<(bool, u8)> {
@@ -0,0 +1,42 @@
+/// Permission flags stored in the bitmask of a `RoleMembership` account
+/// Each flag grants access to one group of operational instructions
+use super::*;
+
+/// Permission to start DAOs
+#[constant]
+pub const START_DAO: u64 = 1;
+
+/// Permission to end DAOs
+#[constant]
+pub const END_DAO: u64 = 2;
+
+/// Permission to add creators
+#[constant]
+pub const MANAGE_CREATORS: u64 = 4;
+
+/// Permission to execute treasury transfers
+#[constant]
+pub const TREASURY: u64 = 8;
+
+/// Permission to initialize the multisig
+#[constant]
+pub const MULTISIG: u64 = 16;
+
+/// Permission to reset accounts
+#[constant]
+pub const RESET: u64 = 32;
+
+/// Permission to initialize trading on the bonding curve
+#[constant]
+pub const TRADE: u64 = 64;
+
+/// Permission to initialize token holder governance
+#[constant]
+pub const GOVERNANCE: u64 = 128;
+
+/// Every permission, granted to the owner on initialization
+#[constant]
+pub const ALL: u64 = u64::MAX;
+
+/// Permissions mirrored from the legacy sub-admin role on migration
+pub const SUB_ADMIN: u64 = START_DAO | END_DAO | MANAGE_CREATORS | TREASURY | MULTISIG | GOVERNANCE;
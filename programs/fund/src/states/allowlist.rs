@@ -0,0 +1,128 @@
+use super::*;
+
+/// Account storing the Merkle roots of the allowlisted users of a DAO, one per round.
+///
+/// While a round has a root, its users may commit by proving their
+/// `(address, max_allowable_amount, tier)` leaf instead of being stored in `Users`.
+/// Each sale tier of a DAO selling in tiers is a round of its own.
+#[account]
+pub struct MerkleAllowlist {
+    /// Root of the VIP round allowlist.
+    pub vip_root: Option<[u8; 32]>,
+
+    /// Root of the party round allowlist.
+    pub party_root: Option<[u8; 32]>,
+
+    /// Roots of the sale tier allowlists, indexed by tier.
+    pub tier_roots: [Option<[u8; 32]>; MAX_SALE_TIERS],
+}
+
+impl MerkleAllowlist {
+    /// Retrieves the root of a round.
+    pub fn get_root(&self, tier: UserType) -> Option<&[u8; 32]> {
+        match tier {
+            UserType::Vip => self.vip_root.as_ref(),
+            UserType::Party => self.party_root.as_ref(),
+            UserType::Tier(index) => self.tier_roots.get(index as usize).and_then(Option::as_ref),
+        }
+    }
+
+    /// Retrieves the round whose leaves are accepted in the given status, if any.
+    ///
+    /// VIP leaves are only accepted during the VIP round and party leaves afterwards,
+    /// so a user allowlisted in both rounds cannot pick the larger cap. A DAO selling
+    /// in tiers only accepts the leaves of the tier open at the time.
+    ///
+    /// # Arguments
+    /// * `status` - Current fundraising status.
+    /// * `active_tier` - Index of the sale tier open at the time, if any.
+    pub fn current_round(status: Status, active_tier: Option<usize>) -> Option<UserType> {
+        match status {
+            Status::FundraisingVip => Some(UserType::Vip),
+            Status::FundraisingParty | Status::FundraisingPublic => Some(UserType::Party),
+            Status::Fundraising => active_tier.map(|index| UserType::Tier(index as u8)),
+            _ => None,
+        }
+    }
+
+    /// Checks whether a round has started, after which its root is locked.
+    ///
+    /// The round of a sale tier starts when the tier opens, or when the sale ends
+    /// before it.
+    ///
+    /// # Arguments
+    /// * `tier` - The round.
+    /// * `status` - Current fundraising status.
+    /// * `sale_tiers` - Sale tiers of the DAO, if it sells in tiers.
+    /// * `now` - Current timestamp.
+    pub fn has_started(
+        tier: UserType,
+        status: Status,
+        sale_tiers: Option<&SaleTiers>,
+        now: i64,
+    ) -> bool {
+        match tier {
+            UserType::Vip => status != Status::Created,
+            UserType::Party => !matches!(status, Status::Created | Status::FundraisingVip),
+            UserType::Tier(index) => match status {
+                Status::Created => false,
+                Status::Fundraising => sale_tiers
+                    .and_then(|sale_tiers| sale_tiers.tiers.get(index as usize))
+                    .map_or(true, |sale_tier| sale_tier.start_at <= now),
+                _ => true,
+            },
+        }
+    }
+
+    /// Sets or clears the root of a round.
+    pub fn set_root(&mut self, tier: UserType, root: Option<[u8; 32]>) -> Result<()> {
+        match tier {
+            UserType::Vip => self.vip_root = root,
+            UserType::Party => self.party_root = root,
+            UserType::Tier(index) => {
+                *self
+                    .tier_roots
+                    .get_mut(index as usize)
+                    .ok_or(CustomError::InvalidSaleTiers)? = root
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the allocation of a user against the root of its round.
+    ///
+    /// # Arguments
+    /// * `address` - The user's address.
+    /// * `allocation` - The allocation and proof passed by the user.
+    /// * `status` - Current fundraising status.
+    /// * `active_tier` - Index of the sale tier open at the time, if any.
+    ///
+    /// # Returns
+    /// * `Result<u64>` - The user's maximum commitment, or an error if the proof is invalid
+    ///   or its round is not the one open.
+    pub fn verify(
+        &self,
+        address: &Pubkey,
+        allocation: &structs::merkle_allocation::Params,
+        status: Status,
+        active_tier: Option<usize>,
+    ) -> Result<u64> {
+        // Only leaves of the round currently open are accepted
+        require!(
+            Self::current_round(status, active_tier) == Some(allocation.tier),
+            CustomError::InEligible
+        );
+
+        let root = self
+            .get_root(allocation.tier)
+            .ok_or(CustomError::InEligible)?;
+        let leaf = calc_merkle_leaf(address, allocation.max_allowable_amount, allocation.tier);
+        require!(
+            verify_merkle_proof(&allocation.proof, root, leaf),
+            CustomError::InvalidMerkleProof
+        );
+
+        Ok(allocation.max_allowable_amount)
+    }
+}
@@ -1,35 +1,81 @@
 use super::*;
 
+/// Legacy account storing blocked addresses, either globally or for a single DAO.
+///
+/// Blocked addresses are now stored as `BlacklistEntry` markers; the lists
+/// are drained into markers by `migrate_blacklist`, and still checked until then.
+/// Unblocking never shrank the lists, so nothing is read past `users`.
 #[account]
 pub struct Blacklist {
-    /// List of blacklist users.
+    /// List of blacklist users, blocked permanently.
     pub users: Vec<Pubkey>,
 }
 
-impl Blacklist {
-    /// Adds to blacklist
-    pub fn add_user(&mut self, user: Pubkey) -> Result<()> {
-        let existing_addresses: HashSet<_> = self.users.iter().collect();
+/// Marker account of a blocked address, one per address and scope.
+///
+/// The account existing means the address is blocked, until `blocked_until`.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct BlacklistEntry {
+    /// Blocked address.
+    pub address: Pubkey,
 
-        if existing_addresses.contains(&user) {
-            return Err(CustomError::DuplicateUser.into());
-        }
-        self.users.push(user);
+    /// Timestamp at which the block is lifted, never if none.
+    pub blocked_until: Option<i64>,
 
-        Ok(())
-    }
+    /// Reason code of the block.
+    pub reason: u16,
+}
+
+/// Account storing the block details of a `BlocklistUser` proposal.
+#[account]
+pub struct BlockDetails {
+    /// Id of the proposal these details belong to.
+    pub proposal_id: u32,
+
+    /// Timestamp at which the block is lifted, never if none.
+    pub blocked_until: Option<i64>,
 
-    /// Remove from blacklist
-    pub fn remove_user(&mut self, user: Pubkey) -> Result<()> {
-        // Ensure user is present in the blacklist
-        require!(self.users.contains(&user), CustomError::NotFound);
+    /// Reason code of the block.
+    pub reason: u16,
+}
 
-        self.users.retain(|u| user.ne(u));
+/// Account storing the blacklist policy of a DAO, `RefundsOnly` while absent.
+#[account]
+pub struct BlacklistPolicyConfig {
+    /// Policy applied to blacklisted users.
+    pub policy: BlacklistPolicy,
+}
 
-        Ok(())
+impl Blacklist {
+    /// Removes up to `count` users for migration, as permanent entries.
+    ///
+    /// # Arguments
+    /// * `count` - Maximum number of entries to take.
+    ///
+    /// # Returns
+    /// * `Vec<BlacklistEntry>` - The entries taken, in order.
+    pub fn take_entries(&mut self, count: usize) -> Vec<BlacklistEntry> {
+        let users = self.users.len().min(count);
+        self.users
+            .drain(..users)
+            .map(|address| BlacklistEntry {
+                address,
+                blocked_until: None,
+                reason: 0,
+            })
+            .collect()
     }
 
+    /// Checks whether the user is blocked by an entry not migrated yet.
     pub fn is_blocked(&self, user: &Pubkey) -> bool {
         self.users.contains(user)
     }
 }
+
+impl BlacklistEntry {
+    /// Checks whether the block still applies at the given time.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.blocked_until.map_or(true, |until| now < until)
+    }
+}
@@ -71,6 +71,10 @@ impl Commitments {
     /// * `address` - Reference to the committer's public key.
     /// * `amount_type` - The type of amount being claimed (SOL or Token).
     /// * `fund_store` - Reference to the fund data store containing vesting rules.
+    /// * `transfer_fee` - Fee withheld when transferring a given amount of tokens.
+    ///
+    /// Claimed tokens are recorded net of the fee withheld, the committer being entitled to
+    /// its tokens less the fee of their transfer.
     ///
     /// # Returns
     /// * `Result<u64>` - The amount that was successfully claimed, or an error if the committer is not found.
@@ -80,6 +84,7 @@ impl Commitments {
         amount_type: AmountType,
         vesting_percent: &VestingPercent,
         day: i64,
+        transfer_fee: impl Fn(u64) -> Result<u64>,
     ) -> Result<u64> {
         if let Some(commiter) = self
             .commiters
@@ -121,11 +126,14 @@ impl Commitments {
                         );
                     }
                     // Update committer's claimed amount and last claimed timestamp.
-                    if commiter.token_amount < commiter.amount_claimed + amount {
-                        amount = commiter.token_amount - commiter.amount_claimed;
-                        commiter.amount_claimed = commiter.token_amount;
+                    let entitled = commiter.token_amount - transfer_fee(commiter.token_amount)?;
+                    let received = amount - transfer_fee(amount)?;
+                    if entitled < commiter.amount_claimed + received {
+                        let remaining = entitled.saturating_sub(commiter.amount_claimed);
+                        amount = remaining + transfer_fee(remaining)?;
+                        commiter.amount_claimed = entitled;
                     } else {
-                        commiter.amount_claimed += amount;
+                        commiter.amount_claimed += received;
                     }
 
                     commiter.last_claimed_at = Some(now);
@@ -138,6 +146,65 @@ impl Commitments {
         }
     }
 
+    /// Claims the SOL refund of a given address, once the fundraise failed or was stopped.
+    ///
+    /// The committed SOL is reduced by the share of tokens already claimed. If a refund
+    /// pool was recorded, the committer is refunded its share of the pool left, by its
+    /// reduced commitment over those of the committers not refunded yet, so the SOL
+    /// deducted from earlier refunds goes to the later ones.
+    ///
+    /// # Arguments
+    /// * `address` - Reference to the committer's public key.
+    /// * `refund_pool` - The escrow balance refunded pro rata, if recorded.
+    ///
+    /// # Returns
+    /// * `Result<u64>` - The SOL refunded to the given address.
+    pub fn claim_refund(
+        &mut self,
+        address: &Pubkey,
+        refund_pool: Option<&mut RefundPool>,
+    ) -> Result<u64> {
+        let outstanding: u64 = self
+            .commiters
+            .iter()
+            .filter(|commiter| commiter.sol_amount > 0)
+            .map(CommitmentDetails::refundable_amount)
+            .sum();
+
+        let commiter = self
+            .commiters
+            .iter_mut()
+            .find(|commiter| commiter.address == *address)
+            .ok_or(CustomError::NotFound)?;
+
+        let amount = commiter.refundable_amount();
+        commiter.sol_amount = 0;
+
+        Ok(refund_pool.map_or(amount, |refund_pool| refund_pool.take(amount, outstanding)))
+    }
+
+    /// Records claimed tokens returned by a given address, restoring their share of its refund.
+    ///
+    /// # Arguments
+    /// * `address` - Reference to the committer's public key.
+    /// * `amount` - The amount of claimed tokens returned.
+    pub fn return_claimed_tokens(&mut self, address: &Pubkey, amount: u64) -> Result<()> {
+        let commiter = self
+            .commiters
+            .iter_mut()
+            .find(|commiter| commiter.address == *address)
+            .ok_or(CustomError::NotFound)?;
+
+        require!(commiter.sol_amount > 0, CustomError::AlreadyClaimed);
+        require!(
+            amount > 0 && amount <= commiter.amount_claimed,
+            CustomError::ExceedsClaimedTokens
+        );
+        commiter.amount_claimed -= amount;
+
+        Ok(())
+    }
+
     /// Retrieves the total SOL amount committed by a given address.
     ///
     /// # Arguments
@@ -151,6 +218,44 @@ impl Commitments {
             .find_map(|commiter| (commiter.address == *address).then_some(commiter.sol_amount))
             .unwrap_or_default()
     }
+
+    /// Retrieves the amount of tokens still vesting in escrow for a given address.
+    ///
+    /// # Arguments
+    /// * `address` - Reference to the committer's public key.
+    ///
+    /// # Returns
+    /// * `u64` - The committed tokens not yet claimed by the given address (default is 0 if not found).
+    pub fn get_unclaimed_token_amount(&self, address: &Pubkey) -> u64 {
+        self.commiters
+            .iter()
+            .find(|commiter| commiter.address == *address)
+            .map(|commiter| {
+                commiter
+                    .token_amount
+                    .saturating_sub(commiter.amount_claimed)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Converts tokens of a given address to SOL, at the average price the address paid.
+    ///
+    /// # Arguments
+    /// * `address` - Reference to the committer's public key.
+    /// * `token_amount` - The amount of tokens to convert.
+    ///
+    /// # Returns
+    /// * `u64` - The SOL equivalent of the tokens (default is 0 if not found).
+    pub fn get_sol_equivalent(&self, address: &Pubkey, token_amount: u64) -> u64 {
+        self.commiters
+            .iter()
+            .find(|commiter| commiter.address == *address && commiter.token_amount > 0)
+            .map(|commiter| {
+                (token_amount as u128 * commiter.sol_amount as u128 / commiter.token_amount as u128)
+                    as u64
+            })
+            .unwrap_or_default()
+    }
 }
 
 impl CommitmentDetails {
@@ -172,6 +277,16 @@ impl CommitmentDetails {
         }
     }
 
+    /// Returns the committed SOL refundable, less the share of tokens already claimed.
+    fn refundable_amount(&self) -> u64 {
+        if self.token_amount == 0 {
+            return self.sol_amount;
+        }
+
+        let unclaimed = self.token_amount.saturating_sub(self.amount_claimed);
+        (self.sol_amount as u128 * unclaimed as u128 / self.token_amount as u128) as u64
+    }
+
     /// Converts commitment details into an event structure.
     ///
     /// # Arguments
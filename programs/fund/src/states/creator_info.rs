@@ -1,10 +1,79 @@
 use super::*;
 
+/// Account storing the details of a creator, shared by all of its DAOs.
 #[account]
 pub struct CreatorInfo {
+    /// Token name of the latest DAO launched by the creator
+    pub token: String,
+
+    /// Fee Percent applied by default to the creator's DAOs
+    pub fee_percent: u32,
+
+    /// Mints of the DAOs launched by the creator, in launch order
+    pub daos: Vec<Pubkey>,
+}
+
+/// Layout of the creator details before the DAOs of the creator were indexed.
+#[derive(AnchorDeserialize)]
+pub struct LegacyCreatorInfo {
+    /// Token name of the latest DAO launched by the creator
+    pub token: String,
+
+    /// Fee Percent applied by default to the creator's DAOs
+    pub fee_percent: u32,
+}
+
+/// Account storing the details of a creator for one of its DAOs.
+#[account]
+pub struct CreatorDaoInfo {
+    /// Creator address
+    pub creator: Pubkey,
+
+    /// Mint of the DAO
+    pub mint: Pubkey,
+
     /// Token name
     pub token: String,
 
-    /// Fee Percent
+    /// Fee Percent of the DAO
     pub fee_percent: u32,
 }
+
+impl CreatorInfo {
+    /// Size of the accounts created with the legacy layout, never resized since.
+    pub const LEGACY_SIZE: usize = size_of::<LegacyCreatorInfo>() + 32;
+
+    /// Decodes creator details stored with the legacy layout.
+    ///
+    /// Only the legacy fields are read, the bytes after the token name possibly being
+    /// left over from a longer name; the DAOs launched before start unindexed.
+    ///
+    /// # Arguments
+    /// * `data` - Data of the account, discriminator included.
+    ///
+    /// # Returns
+    /// * `Result<CreatorInfo>` - The creator details with the new layout.
+    pub fn from_legacy(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 8 && data[..8] == CreatorInfo::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let legacy = LegacyCreatorInfo::deserialize(&mut &data[8..])?;
+
+        Ok(Self {
+            token: legacy.token,
+            fee_percent: legacy.fee_percent,
+            daos: vec![],
+        })
+    }
+
+    /// Records a DAO launched by the creator.
+    ///
+    /// # Arguments
+    /// * `token` - Token name of the DAO.
+    /// * `mint` - Mint of the DAO.
+    pub fn add_dao(&mut self, token: String, mint: Pubkey) {
+        self.token = token;
+        self.daos.push(mint);
+    }
+}
@@ -40,11 +40,7 @@ impl Creators {
     }
 
     /// Checks if the given address belongs to a registered creator.
-    pub fn is_creator(
-        &self,
-        address: &Pubkey,
-        token_creators: Option<Vec<mpl_token_metadata::types::Creator>>,
-    ) -> Result<()> {
+    pub fn is_creator(&self, address: &Pubkey, token_creators: Option<Vec<Pubkey>>) -> Result<()> {
         require!(self.in_creator_list(address), CustomError::Unauthorized);
         require!(
             self.creators
@@ -54,10 +50,7 @@ impl Creators {
         );
 
         if let Some(creators) = token_creators {
-            require!(
-                creators.iter().any(|cr| cr.address == *address),
-                CustomError::Unauthorized
-            );
+            require!(creators.contains(address), CustomError::Unauthorized);
         }
         Ok(())
     }
@@ -0,0 +1,183 @@
+use super::*;
+
+/// Account storing token holder governance settings and community proposals of a DAO.
+#[account]
+pub struct DaoGovernance {
+    /// Share of the token supply that must vote for a proposal to be valid, upto 6 decimal places.
+    pub quorum_percent: u32,
+
+    /// Share of the cast votes that must be in favour for a proposal to pass, upto 6 decimal places.
+    pub approval_percent: u32,
+
+    /// Share of the token supply a holder needs to create a proposal, upto 6 decimal places.
+    pub proposal_threshold_percent: u32,
+
+    /// Duration of the voting period in seconds.
+    pub voting_period: i64,
+
+    /// List of community proposals.
+    pub proposals: Vec<CommunityProposal>,
+}
+
+/// Struct representing a proposal voted on by token holders.
+#[account]
+pub struct CommunityProposal {
+    /// Community proposal Id.
+    pub id: u32,
+
+    /// Timestamp of creation, used as the voting weight snapshot.
+    pub created_at: i64,
+
+    /// Address of the proposer.
+    pub created_by: Pubkey,
+
+    /// Type of the proposal.
+    pub proposal_type: ProposalType,
+
+    /// Address the proposal applies to, if any.
+    pub address: Option<Pubkey>,
+
+    /// Amount or percent carried by the proposal.
+    pub transfer_amount: Option<u64>,
+
+    /// Timestamp at which voting closes.
+    pub voting_ends_at: i64,
+
+    /// Total weight voted in favour.
+    pub yes_votes: u64,
+
+    /// Total weight voted against.
+    pub no_votes: u64,
+
+    /// Current status of the proposal.
+    pub status: ProposalStatus,
+
+    /// Id of the executable proposal created once passed.
+    pub executable_proposal_id: Option<u32>,
+
+    /// Token supply at creation, the quorum being taken against it.
+    pub supply: u64,
+}
+
+/// Account tracking the governance tokens deposited by a voter.
+#[account]
+pub struct VoterRecord {
+    /// Amount of tokens deposited in the governance vault.
+    pub deposited_amount: u64,
+
+    /// Timestamp of the latest deposit.
+    pub deposited_at: i64,
+
+    /// Deposited tokens cannot be withdrawn before this timestamp.
+    pub locked_until: i64,
+
+    /// Amount of tokens deposited before the latest deposit.
+    pub previous_amount: u64,
+
+    /// Timestamp of the deposit before the latest one.
+    pub previous_at: i64,
+}
+
+/// Account recording a vote on a community proposal, one per voter and proposal.
+#[account]
+pub struct VoteRecord {
+    /// Community proposal Id.
+    pub proposal_id: u32,
+
+    /// Address of the voter.
+    pub voter: Pubkey,
+
+    /// Whether the vote is in favour.
+    pub support: bool,
+
+    /// Voting weight applied.
+    pub weight: u64,
+}
+
+impl DaoGovernance {
+    /// Retrieves a community proposal by its Id.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - Community proposal Id.
+    ///
+    /// # Returns
+    /// * `Result<&mut CommunityProposal>` - The proposal, or an error if not found.
+    pub fn get_proposal_mut(&mut self, proposal_id: u32) -> Result<&mut CommunityProposal> {
+        self.proposals
+            .iter_mut()
+            .find(|p| p.id == proposal_id)
+            .ok_or(CustomError::NotFound.into())
+    }
+}
+
+impl CommunityProposal {
+    /// Checks whether votes are still accepted.
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        self.status == ProposalStatus::Pending && now < self.voting_ends_at
+    }
+
+    /// Checks whether the proposal met quorum and the approval threshold.
+    ///
+    /// # Arguments
+    /// * `governance` - Governance settings of the DAO.
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the proposal passed.
+    pub fn has_passed(&self, governance: &DaoGovernance) -> bool {
+        let total_votes = self.yes_votes as u128 + self.no_votes as u128;
+        let quorum = calc_amount(self.supply as u128, governance.quorum_percent as u128) as u128;
+
+        total_votes > 0
+            && total_votes >= quorum
+            && self.yes_votes as u128 * 100000000
+                >= total_votes * governance.approval_percent as u128
+    }
+}
+
+impl VoterRecord {
+    /// Records a deposit, keeping the amount deposited before it for the snapshots
+    /// taken in between.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of tokens deposited.
+    /// * `now` - Timestamp of the deposit.
+    pub fn deposit(&mut self, amount: u64, now: i64) {
+        if now > self.deposited_at {
+            self.previous_amount = self.deposited_amount;
+            self.previous_at = self.deposited_at;
+        }
+        self.deposited_amount += amount;
+        self.deposited_at = now;
+    }
+
+    /// Records a withdrawal, which lowers the weight of every snapshot.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of tokens withdrawn.
+    pub fn withdraw(&mut self, amount: u64) {
+        self.deposited_amount -= amount;
+        self.previous_amount = self.previous_amount.min(self.deposited_amount);
+    }
+
+    /// Calculates the deposited voting weight at a given snapshot.
+    ///
+    /// Deposits made at or after the snapshot do not count, so tokens cannot be
+    /// moved between wallets to vote more than once on the same proposal. A top
+    /// up only excludes the amount it adds, the amount deposited before it still
+    /// counting, unless it was itself topped up after the snapshot.
+    ///
+    /// # Arguments
+    /// * `snapshot` - Timestamp of the snapshot.
+    ///
+    /// # Returns
+    /// * `u64` - The weight of the deposit at the snapshot.
+    pub fn weight_at(&self, snapshot: i64) -> u64 {
+        if self.deposited_at < snapshot {
+            self.deposited_amount
+        } else if self.previous_at < snapshot {
+            self.previous_amount
+        } else {
+            0
+        }
+    }
+}
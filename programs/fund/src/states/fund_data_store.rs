@@ -33,6 +33,18 @@ pub struct FundDataStore {
     pub creators: CreatorShare,
 
     pub deployers: DeployerShare,
+
+    /// Co-creators sharing the creator withdrawals, the creator alone if empty.
+    pub co_creators: Vec<CoCreator>,
+
+    /// Tokenomics of the DAO, the default ones if none.
+    pub tokenomics: Option<Tokenomics>,
+
+    /// Milestones releasing the creator funds, released freely if empty.
+    pub milestones: Vec<Milestone>,
+
+    /// Escrow balance refunded pro rata to committers, once a milestone failed or the DAO was closed.
+    pub refund_pool: Option<RefundPool>,
 }
 
 impl FundDataStore {
@@ -41,6 +53,7 @@ impl FundDataStore {
     /// # Arguments
     /// * `create_params` - Struct containing fundraising parameters.
     /// * `fee_percent` - Percentage of fees to be applied.
+    /// * `tokenomics_bounds` - Bounds on the tokenomics set by admins, if any.
     ///
     /// # Returns
     /// * `Result<()>` - Returns `Ok(())` on success or an error if timestamp retrieval fails.
@@ -49,28 +62,241 @@ impl FundDataStore {
         create_params: &structs::create::Params,
         fee_percent: u32,
         caller: Pubkey,
+        tokenomics_bounds: Option<&TokenomicsBounds>,
     ) -> Result<()> {
+        let tokenomics = create_params.tokenomics.unwrap_or_default();
+        tokenomics.validate(tokenomics_bounds)?;
+        self.tokenomics = Some(tokenomics);
+
         // Initialize fundraising parameters.
         self.fundraising_goal = create_params.fundraising_goal;
         self.tokens_per_sol = calc_token_per_sol(
             create_params.fundraising_goal as u128,
             create_params.amount as u128,
+            tokenomics.sale_bps,
         );
         self.update_status(Status::Created)?; // Default status set to "Created".
         self.fee_percent = fee_percent;
         self.vesting_percent = create_params.vesting_percent;
         self.created_by = caller;
+        self.set_co_creators(&create_params.co_creators, caller)?;
+        self.set_milestones(&create_params.milestones)?;
 
         self.calc_shares();
 
         Ok(())
     }
 
+    /// Sets the co-creators, which must include the caller and hold all shares.
+    ///
+    /// # Arguments
+    /// * `co_creators` - Co-creators with their shares in basis points.
+    /// * `caller` - The creator of the DAO.
+    fn set_co_creators(&mut self, co_creators: &[CoCreator], caller: Pubkey) -> Result<()> {
+        if co_creators.is_empty() {
+            return Ok(());
+        }
+
+        CoCreator::validate(co_creators, caller)?;
+        self.co_creators = co_creators.to_vec();
+
+        Ok(())
+    }
+
+    /// Sets the milestones, whose shares must add up to the creators' whole withdrawable raise.
+    ///
+    /// # Arguments
+    /// * `milestones` - The milestones, in delivery order.
+    fn set_milestones(&mut self, milestones: &[structs::milestone::Params]) -> Result<()> {
+        if milestones.is_empty() {
+            return Ok(());
+        }
+
+        require!(
+            milestones.len() <= MAX_MILESTONES
+                && milestones.iter().all(|milestone| milestone.release_bps > 0)
+                && milestones
+                    .iter()
+                    .map(|milestone| milestone.release_bps as u32)
+                    .sum::<u32>()
+                    == MAX_SHARE_BPS as u32,
+            CustomError::InvalidMilestones
+        );
+
+        self.milestones = milestones
+            .iter()
+            .map(|milestone| Milestone {
+                description_hash: milestone.description_hash,
+                release_bps: milestone.release_bps,
+                status: MilestoneStatus::Pending,
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    /// Ensures the creator funds are not gated by milestones.
+    pub fn require_no_milestones(&self) -> Result<()> {
+        require!(self.milestones.is_empty(), CustomError::MilestonesDeclared);
+
+        Ok(())
+    }
+
+    /// Returns the index of the next milestone to deliver, if any.
+    pub fn next_milestone(&self) -> Option<usize> {
+        self.milestones
+            .iter()
+            .position(|milestone| milestone.status == MilestoneStatus::Pending)
+    }
+
+    /// Ensures the given milestone is the next one to deliver, in a successful DAO.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the milestone.
+    pub fn check_milestone(&self, index: u64) -> Result<()> {
+        require!(
+            self.status.eq(&Status::FundraisingSuccess) || self.status.eq(&Status::Trade),
+            CustomError::InValidDaoStatus
+        );
+        require!(
+            self.next_milestone() == Some(index as usize),
+            CustomError::InvalidMilestone
+        );
+
+        Ok(())
+    }
+
+    /// Returns the SOL released by the given milestone, the last one releasing the remainder.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the milestone.
+    pub fn milestone_release_amount(&self, index: usize) -> u64 {
+        if index + 1 == self.milestones.len() {
+            self.unreleased_creator_amount()
+        } else {
+            calc_bps_amount(
+                self.creators.total_withdrawable as u128,
+                self.milestones[index].release_bps,
+            )
+        }
+    }
+
+    /// Returns the creators' withdrawable raise not withdrawn yet.
+    pub fn unreleased_creator_amount(&self) -> u64 {
+        self.creators
+            .total_withdrawable
+            .saturating_sub(self.creator_withdrawn_amount())
+    }
+
+    /// Releases the given milestone, recording the amount as withdrawn by the creators.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the milestone.
+    ///
+    /// # Returns
+    /// * `Result<u64>` - The amount of SOL released, the last milestone releasing the remainder.
+    pub fn release_milestone(&mut self, index: u64) -> Result<u64> {
+        self.check_milestone(index)?;
+
+        let amount = self.milestone_release_amount(index as usize);
+        self.milestones[index as usize].status = MilestoneStatus::Released;
+        self.update_creator_amount(amount)?;
+
+        Ok(amount)
+    }
+
+    /// Fails the given milestone and the ones after it, refunding the creator funds they
+    /// gated pro rata.
+    ///
+    /// The deployers' share and the SOL paired in the pool stay in escrow, only the
+    /// creators' withdrawable raise not released yet being refunded.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the milestone.
+    /// * `escrow_balance` - The escrow balance above its rent-exempt minimum.
+    /// * `total_commited_sols` - Total SOL committed to the DAO.
+    ///
+    /// # Returns
+    /// * `Result<u64>` - The amount of SOL refunded.
+    pub fn fail_milestone(
+        &mut self,
+        index: u64,
+        escrow_balance: u64,
+        total_commited_sols: u64,
+    ) -> Result<u64> {
+        self.check_milestone(index)?;
+
+        let refundable = self.unreleased_creator_amount().min(escrow_balance);
+        for milestone in self.milestones.iter_mut().skip(index as usize) {
+            milestone.status = MilestoneStatus::Failed;
+        }
+        self.refund_pool = Some(RefundPool {
+            sol_amount: refundable,
+            total_commited_sols,
+        });
+        self.update_status(Status::Refunding)?;
+
+        Ok(refundable)
+    }
+
+    /// Closes the DAO, refunding the escrow left pro rata to committers.
+    ///
+    /// # Arguments
+    /// * `refund_pool` - The escrow balance to refund.
+    pub fn close_with_refunds(&mut self, refund_pool: RefundPool) -> Result<()> {
+        self.refund_pool = Some(refund_pool);
+        self.update_status(Status::Closed)
+    }
+
+    /// Returns the creators to write into the token metadata.
+    pub fn metadata_creators(&self) -> Vec<mpl_token_metadata::types::Creator> {
+        if self.co_creators.is_empty() {
+            return vec![mpl_token_metadata::types::Creator {
+                address: self.created_by,
+                verified: false,
+                share: 100,
+            }];
+        }
+
+        let shares: Vec<u16> = self.co_creators.iter().map(|cr| cr.share_bps).collect();
+        self.co_creators
+            .iter()
+            .zip(bps_to_percent_shares(&shares))
+            .map(|(cr, share)| mpl_token_metadata::types::Creator {
+                address: cr.address,
+                verified: false,
+                share,
+            })
+            .collect()
+    }
+
+    /// Splits a creator withdrawal between the co-creators.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount withdrawn.
+    /// * `creator` - The creator the withdrawal was approved for.
+    ///
+    /// # Returns
+    /// * `Vec<(Pubkey, u64)>` - The amount paid to each recipient.
+    pub fn split_creator_amount(&self, amount: u64, creator: Pubkey) -> Result<Vec<(Pubkey, u64)>> {
+        CoCreator::split(&self.co_creators, amount, creator)
+    }
+
+    /// Returns the tokenomics of the DAO.
+    pub fn tokenomics(&self) -> Tokenomics {
+        self.tokenomics.unwrap_or_default()
+    }
+
     fn calc_shares(&mut self) {
-        let total_withdrawable = calc_amount(self.fundraising_goal as u128, 90000000); // 90% of the sol raised
-        self.creators.total_withdrawable = calc_amount(total_withdrawable as u128, 49000000); // 49% of 90% of the sol raised
-        self.deployers.total_withdrawable = calc_amount(total_withdrawable as u128, 51000000);
-        // 51% of 90% of the sol raised
+        let tokenomics = self.tokenomics();
+        let total_withdrawable =
+            calc_bps_amount(self.fundraising_goal as u128, tokenomics.withdrawable_bps); // withdrawable share of the sol raised
+        self.creators.total_withdrawable =
+            calc_bps_amount(total_withdrawable as u128, tokenomics.creators_bps); // creators' share of it
+        self.deployers.total_withdrawable = calc_bps_amount(
+            total_withdrawable as u128,
+            MAX_SHARE_BPS - tokenomics.creators_bps,
+        ); // deployers' share of it
     }
 
     pub fn check_creator_withdrawl(&self, amount: u64) -> Result<()> {
@@ -121,6 +347,34 @@ impl FundDataStore {
         Ok(())
     }
 
+    /// Returns a cancelled creator allocation to the creators' withdrawable share.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount cancelled.
+    pub fn return_creator_amount(&mut self, mut amount: u64) {
+        for creator in self.creators.creators.iter_mut().rev() {
+            let returned = amount.min(creator.amount);
+            creator.amount -= returned;
+            amount -= returned;
+        }
+    }
+
+    /// Returns a cancelled deployer allocation to the deployers' withdrawable share.
+    ///
+    /// # Arguments
+    /// * `address` - The deployer address.
+    /// * `amount` - The amount cancelled.
+    pub fn return_deployer_amount(&mut self, address: &Pubkey, amount: u64) {
+        if let Some(deployer) = self
+            .deployers
+            .deployers
+            .iter_mut()
+            .find(|dep| dep.address == *address)
+        {
+            deployer.amount = deployer.amount.saturating_sub(amount);
+        }
+    }
+
     /// Updates the status of the fundraising campaign.
     ///
     /// # Arguments
@@ -136,7 +390,7 @@ impl FundDataStore {
         use Status::*;
         match self.status {
             Created => self.created_at = now,
-            FundraisingVip => self.start_date = now,
+            FundraisingVip | Fundraising => self.start_date = now,
             FundraisingSuccess | FundraisingFail | Trade | Expired | Closed => self.end_date = now,
             _ => (),
         }
@@ -148,9 +402,10 @@ impl FundDataStore {
     ///
     /// # Arguments
     /// * `supply` - Token supply.
-    pub fn calc_lp_pairs(&self, supply: u128) -> (u64, u64) {
-        let tokens = 10 * supply / 100;
-        let sols = tokens / self.tokens_per_sol as u128;
+    /// * `tokens_per_sol` - Price the tokens were sold at, averaged across the sale tiers if any.
+    pub fn calc_lp_pairs(&self, supply: u128, tokens_per_sol: u64) -> (u64, u64) {
+        let tokens = calc_bps_amount(supply, self.tokenomics().liquidity_bps) as u128;
+        let sols = tokens / tokens_per_sol as u128;
         (tokens as u64, sols as u64)
     }
 
@@ -162,22 +417,102 @@ impl FundDataStore {
         self.deployers.deployers.iter().map(|dep| dep.amount).sum()
     }
 
+    /// Returns the Borsh size of the account with the given number of extra entries,
+    /// reserving room for the refund pool set when refunds start.
+    ///
+    /// # Arguments
+    /// * `extra` - Size of the entries about to be added.
+    fn size_with(&self, extra: usize) -> usize {
+        let refund_pool = if self.refund_pool.is_none() {
+            RefundPool::SIZE
+        } else {
+            0
+        };
+
+        8 + self.try_to_vec().map_or(0, |data| data.len()) + extra + refund_pool
+    }
+
+    /// Returns the size of the account once a new deployer is recorded.
     pub fn realloc_for_deployer(&self) -> usize {
-        32 + size_of::<FundDataStore>()
-            + (self.creators.creators.len() * size_of::<CreatorData>())
-            + size_of::<Pubkey>()
-            + size_of::<u64>()
-            + ((self.deployers.deployers.len() + 1) * size_of::<DeployerData>())
-            + size_of::<u64>()
+        self.size_with(DeployerData::INIT_SPACE)
     }
 
+    /// Returns the size of the account once a new creator withdrawal is recorded.
     pub fn realloc_for_creator(&self) -> usize {
-        32 + size_of::<FundDataStore>()
-            + ((self.creators.creators.len() + 1) * size_of::<CreatorData>())
-            + size_of::<Pubkey>()
-            + size_of::<u64>()
-            + (self.deployers.deployers.len() * size_of::<DeployerData>())
-            + size_of::<u64>()
+        self.size_with(CreatorData::INIT_SPACE)
+    }
+}
+
+/// Struct representing a co-creator and its share of the creator withdrawals.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CoCreator {
+    /// Co-creator address.
+    pub address: Pubkey,
+
+    /// Share in basis points.
+    pub share_bps: u16,
+}
+
+impl CoCreator {
+    /// Validates the co-creators of a DAO.
+    ///
+    /// They must be distinct, include the caller and hold all shares, each share being
+    /// at least `MIN_CO_CREATOR_SHARE_BPS` so that it is not rounded away in the
+    /// percent shares of the token metadata.
+    ///
+    /// # Arguments
+    /// * `co_creators` - Co-creators with their shares in basis points.
+    /// * `caller` - The creator of the DAO.
+    pub fn validate(co_creators: &[CoCreator], caller: Pubkey) -> Result<()> {
+        let addresses: HashSet<Pubkey> = co_creators.iter().map(|cr| cr.address).collect();
+        require!(
+            co_creators.len() <= MAX_CO_CREATORS
+                && addresses.len() == co_creators.len()
+                && addresses.contains(&caller)
+                && co_creators
+                    .iter()
+                    .all(|cr| cr.share_bps >= MIN_CO_CREATOR_SHARE_BPS)
+                && co_creators
+                    .iter()
+                    .map(|cr| cr.share_bps as u32)
+                    .sum::<u32>()
+                    == MAX_SHARE_BPS as u32,
+            CustomError::InvalidCoCreators
+        );
+
+        Ok(())
+    }
+
+    /// Splits a creator withdrawal between the co-creators.
+    ///
+    /// # Arguments
+    /// * `co_creators` - Co-creators of the DAO, empty if it has none.
+    /// * `amount` - The amount withdrawn.
+    /// * `creator` - The creator the withdrawal was approved for, which must be one
+    ///   of the co-creators if there are any.
+    ///
+    /// # Returns
+    /// * `Vec<(Pubkey, u64)>` - The amount paid to each recipient.
+    pub fn split(
+        co_creators: &[CoCreator],
+        amount: u64,
+        creator: Pubkey,
+    ) -> Result<Vec<(Pubkey, u64)>> {
+        if co_creators.is_empty() {
+            return Ok(vec![(creator, amount)]);
+        }
+
+        require!(
+            co_creators.iter().any(|cr| cr.address == creator),
+            CustomError::AccountMisMatch
+        );
+
+        let shares: Vec<u16> = co_creators.iter().map(|cr| cr.share_bps).collect();
+        Ok(co_creators
+            .iter()
+            .map(|cr| cr.address)
+            .zip(split_by_bps(amount, &shares))
+            .collect())
     }
 }
 
@@ -14,13 +14,145 @@ pub struct GlobalConfig {
 
     /// The owner.
     pub owner: Pubkey,
+
+    /// List of sub-admin grants that expire or are scoped to a single DAO.
+    pub sub_admin_grants: Vec<RoleGrant>,
+
+    /// Owner nominated through an `UpdateOwner` proposal, awaiting acceptance.
+    pub pending_owner: Option<PendingOwner>,
+
+    /// Bounds on the tokenomics of new DAOs, unbounded if none.
+    pub tokenomics_bounds: Option<TokenomicsBounds>,
+}
+
+/// Layout of the global configuration before grants, ownership nominations and
+/// tokenomics bounds were appended to it.
+#[derive(AnchorDeserialize)]
+pub struct LegacyGlobalConfig {
+    /// List of deployers.
+    pub deployers: Vec<Pubkey>,
+
+    /// List of sub-admins.
+    pub sub_admins: Vec<Pubkey>,
+
+    /// List of admins.
+    pub admins: Vec<Pubkey>,
+
+    /// The owner.
+    pub owner: Pubkey,
+}
+
+/// Struct representing an ownership transfer awaiting acceptance.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct PendingOwner {
+    /// Address nominated as the new owner.
+    pub address: Pubkey,
+
+    /// Timestamp after which the nomination can no longer be accepted.
+    pub deadline: i64,
+}
+
+/// Struct representing a time-bounded or DAO-scoped sub-admin grant.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct RoleGrant {
+    /// Address the role is granted to.
+    pub address: Pubkey,
+
+    /// Mint of the only DAO the grant applies to, all DAOs if none.
+    pub mint: Option<Pubkey>,
+
+    /// Timestamp after which the grant no longer applies, never if none.
+    pub expires_at: Option<i64>,
+}
+
+impl RoleGrant {
+    /// Checks whether the grant applies to the caller for the given DAO at the given time.
+    ///
+    /// # Arguments
+    /// * `caller` - The public key to check.
+    /// * `mint` - Mint of the DAO being acted on, if any.
+    /// * `now` - Current timestamp.
+    ///
+    /// # Returns
+    /// * `true` if the grant is active, `false` otherwise.
+    pub fn applies(&self, caller: &Pubkey, mint: Option<&Pubkey>, now: i64) -> bool {
+        self.address.eq(caller)
+            && self.mint.as_ref().map_or(true, |scope| Some(scope) == mint)
+            && self.expires_at.map_or(true, |expires_at| now < expires_at)
+    }
 }
 
 impl GlobalConfig {
+    /// Decodes a global configuration stored with the legacy layout.
+    ///
+    /// Only the legacy fields are read, the bytes after them possibly being left over
+    /// from lists that shrank; the appended fields start empty.
+    ///
+    /// # Arguments
+    /// * `data` - Data of the account, discriminator included.
+    ///
+    /// # Returns
+    /// * `Result<GlobalConfig>` - The configuration with the new layout.
+    pub fn from_legacy(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 8 && data[..8] == GlobalConfig::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let legacy = LegacyGlobalConfig::deserialize(&mut &data[8..])?;
+
+        Ok(Self {
+            deployers: legacy.deployers,
+            sub_admins: legacy.sub_admins,
+            admins: legacy.admins,
+            owner: legacy.owner,
+            sub_admin_grants: vec![],
+            pending_owner: None,
+            tokenomics_bounds: None,
+        })
+    }
+
     pub fn set_owner(&mut self, owner: Pubkey) {
         self.owner = owner;
     }
 
+    /// Nominates a new owner, replacing any earlier nomination.
+    ///
+    /// # Arguments
+    /// * `address` - The public key of the nominated owner.
+    /// * `deadline` - Timestamp until which the nomination can be accepted.
+    pub fn set_pending_owner(&mut self, address: Pubkey, deadline: i64) {
+        self.pending_owner = Some(PendingOwner { address, deadline });
+    }
+
+    /// Completes the ownership transfer to the nominated owner.
+    ///
+    /// # Arguments
+    /// * `caller` - The public key accepting the ownership.
+    /// * `now` - Current timestamp.
+    ///
+    /// # Returns
+    /// * `Result<Pubkey>` - The previous owner, or an error if the caller cannot accept.
+    pub fn accept_owner(&mut self, caller: &Pubkey, now: i64) -> Result<Pubkey> {
+        let pending_owner = self
+            .pending_owner
+            .as_ref()
+            .ok_or(CustomError::NoPendingOwner)?;
+
+        require!(pending_owner.address.eq(caller), CustomError::Unauthorized);
+        require!(
+            now <= pending_owner.deadline,
+            CustomError::OwnershipTransferExpired
+        );
+
+        let previous_owner = self.owner;
+        self.set_owner(*caller);
+        self.pending_owner = None;
+
+        Ok(previous_owner)
+    }
+
     /// Sets the primary admin of the system.
     ///
     /// # Arguments
@@ -108,13 +240,69 @@ impl GlobalConfig {
 
     /// Checks if a given public key belongs to a sub-admin.
     ///
+    /// Grants only count while they have not expired, and scoped grants only
+    /// count for the DAO they are scoped to.
+    ///
     /// # Arguments
     /// * `caller` - The public key to check.
+    /// * `mint` - Mint of the DAO being acted on, if any.
+    /// * `now` - Current timestamp.
     ///
     /// # Returns
     /// * `true` if the caller is a sub-admin, `false` otherwise.
-    pub fn is_sub_admin(&self, caller: &Pubkey) -> bool {
-        self.admins.contains(&caller) || self.sub_admins.contains(&caller)
+    pub fn is_sub_admin(&self, caller: &Pubkey, mint: Option<&Pubkey>, now: i64) -> bool {
+        if self.admins.contains(&caller) || self.sub_admins.contains(&caller) {
+            return true;
+        }
+
+        self.sub_admin_grants
+            .iter()
+            .any(|grant| grant.applies(caller, mint, now))
+    }
+
+    /// Returns the permissions granted to the caller by its active sub-admin grants.
+    ///
+    /// Grants carry the operational permissions of the legacy sub-admin role.
+    ///
+    /// # Arguments
+    /// * `caller` - The public key to check.
+    /// * `mint` - Mint of the DAO being acted on, if any.
+    /// * `now` - Current timestamp.
+    ///
+    /// # Returns
+    /// * `permissions::SUB_ADMIN` if a grant applies, `0` otherwise.
+    pub fn grant_permissions(&self, caller: &Pubkey, mint: Option<&Pubkey>, now: i64) -> u64 {
+        if self
+            .sub_admin_grants
+            .iter()
+            .any(|grant| grant.applies(caller, mint, now))
+        {
+            permissions::SUB_ADMIN
+        } else {
+            0
+        }
+    }
+
+    /// Adds a sub-admin grant, dropping grants that have expired.
+    ///
+    /// # Arguments
+    /// * `grant` - The grant to add.
+    /// * `now` - Current timestamp.
+    pub fn add_sub_admin_grant(&mut self, grant: RoleGrant, now: i64) {
+        self.sub_admin_grants.retain(|g| {
+            g.expires_at.map_or(true, |expires_at| now < expires_at)
+                && !(g.address == grant.address && g.mint == grant.mint)
+        });
+        self.sub_admin_grants.push(grant);
+    }
+
+    /// Removes every sub-admin grant of the given addresses.
+    ///
+    /// # Arguments
+    /// * `addresses` - A vector of public keys to be removed.
+    pub fn remove_sub_admin_grants(&mut self, addresses: &[Pubkey]) {
+        self.sub_admin_grants
+            .retain(|grant| !addresses.contains(&grant.address));
     }
 
     /// Checks if a given public key belongs to a deployer.
@@ -136,11 +324,15 @@ impl GlobalConfig {
     // /// # Returns
     // /// * `true` if the caller is admin, `false` otherwise.
     // pub fn has_access(&self, caller: &Pubkey) -> bool {
-    //    self.is_owner(caller) || self.is_admin(caller) || self.is_sub_admin(caller)
+    //    self.is_owner(caller) || self.is_admin(caller) || self.is_sub_admin(caller, Clock::get()?.unix_timestamp)
     // }
 
-    /// Get current size
+    /// Get current size in 32 byte slots, each grant taking three slots
     pub fn get_len(&self) -> usize {
-        self.admins.len() + self.sub_admins.len() + self.deployers.len() + 1
+        self.admins.len()
+            + self.sub_admins.len()
+            + self.deployers.len()
+            + self.sub_admin_grants.len() * 3
+            + 1
     }
 }
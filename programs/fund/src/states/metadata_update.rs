@@ -0,0 +1,27 @@
+use super::*;
+
+/// Account storing the metadata changes of an update token metadata proposal.
+#[account]
+#[derive(InitSpace)]
+pub struct MetadataUpdate {
+    /// Id of the proposal these changes belong to.
+    pub proposal_id: u32,
+
+    /// Mint of the DAO token whose metadata is updated.
+    pub mint: Pubkey,
+
+    /// New name of the token, unchanged if none.
+    #[max_len(MAX_METADATA_NAME_LEN)]
+    pub name: Option<String>,
+
+    /// New symbol of the token, unchanged if none.
+    #[max_len(MAX_METADATA_SYMBOL_LEN)]
+    pub symbol: Option<String>,
+
+    /// New URI of the token, unchanged if none.
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub uri: Option<String>,
+
+    /// Collection the token belongs to, unchanged if none.
+    pub collection: Option<Pubkey>,
+}
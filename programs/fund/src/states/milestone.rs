@@ -0,0 +1,78 @@
+use super::*;
+
+/// Enum representing the status of a milestone.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MilestoneStatus {
+    /// The milestone's funds are still held in escrow.
+    Pending,
+
+    /// The milestone's funds were released to the creators.
+    Released,
+
+    /// The milestone was voted failed, its funds being refunded.
+    Failed,
+}
+
+/// Struct representing a delivery milestone gating a share of the creator funds.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Milestone {
+    /// Hash of the milestone description.
+    pub description_hash: [u8; 32],
+
+    /// Share of the creators' withdrawable raise released with the milestone, in basis points.
+    pub release_bps: u16,
+
+    /// Current status of the milestone.
+    pub status: MilestoneStatus,
+}
+
+/// Struct recording the escrow balance refunded pro rata to committers.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RefundPool {
+    /// SOL left to refund, drawn down as committers are refunded.
+    pub sol_amount: u64,
+
+    /// Total SOL committed, each committer being refunded its share of it.
+    pub total_commited_sols: u64,
+}
+
+impl RefundPool {
+    /// Borsh size of the pool.
+    pub const SIZE: usize = 8 + 8;
+
+    /// Calculates the refund of a committer, its share of the pool by the SOL it committed.
+    ///
+    /// # Arguments
+    /// * `sol_amount` - The SOL committed by the committer.
+    ///
+    /// # Returns
+    /// * `u64` - The SOL refunded to the committer.
+    pub fn refund_amount(&self, sol_amount: u64) -> u64 {
+        if self.total_commited_sols == 0 {
+            return 0;
+        }
+
+        (sol_amount as u128 * self.sol_amount as u128 / self.total_commited_sols as u128) as u64
+    }
+
+    /// Takes the refund of a committer out of the pool left, its share of it by the SOL it
+    /// is refunded for over that of all committers not refunded yet.
+    ///
+    /// # Arguments
+    /// * `sol_amount` - The SOL the committer is refunded for.
+    /// * `outstanding` - The SOL all committers not refunded yet are refunded for, the
+    ///   committer included.
+    ///
+    /// # Returns
+    /// * `u64` - The SOL refunded to the committer.
+    pub fn take(&mut self, sol_amount: u64, outstanding: u64) -> u64 {
+        if outstanding == 0 {
+            return 0;
+        }
+
+        let refund = (sol_amount as u128 * self.sol_amount as u128 / outstanding as u128) as u64;
+        self.sol_amount -= refund;
+
+        refund
+    }
+}
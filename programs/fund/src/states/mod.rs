@@ -1,18 +1,32 @@
 use super::*;
 
+mod allowlist;
 mod blacklist;
 mod commitments;
 mod creator_info;
 mod creators;
+mod dao_governance;
 mod dao_list;
 mod fee;
 mod fund_data_store;
 mod global_config;
+mod metadata_update;
+mod milestone;
+mod pause_config;
+mod payout_stream;
 mod proposal_data;
+mod proposal_instructions;
+mod public_round;
+mod role_membership;
+mod sale_tiers;
 mod status;
+mod tokenomics;
+mod treasury_ledger;
 mod users;
 
 pub use {
-    blacklist::*, commitments::*, creator_info::*, creators::*, dao_list::*, fee::*,
-    fund_data_store::*, global_config::*, proposal_data::*, status::*, users::*,
+    allowlist::*, blacklist::*, commitments::*, creator_info::*, creators::*, dao_governance::*,
+    dao_list::*, fee::*, fund_data_store::*, global_config::*, metadata_update::*, milestone::*,
+    pause_config::*, payout_stream::*, proposal_data::*, proposal_instructions::*, public_round::*,
+    role_membership::*, sale_tiers::*, status::*, tokenomics::*, treasury_ledger::*, users::*,
 };
@@ -0,0 +1,72 @@
+use super::*;
+
+/// Account storing the emergency pause flags and the guardians allowed to set them.
+#[account]
+pub struct PauseConfig {
+    /// Whether all DAOs are paused.
+    pub paused: bool,
+
+    /// List of mints paused individually.
+    pub paused_mints: Vec<Pubkey>,
+
+    /// List of guardians allowed to pause instantly.
+    pub guardians: Vec<Pubkey>,
+}
+
+impl PauseConfig {
+    /// Checks whether the given address is a guardian.
+    pub fn is_guardian(&self, address: &Pubkey) -> bool {
+        self.guardians.contains(address)
+    }
+
+    /// Checks whether actions on the given mint are halted, globally or individually.
+    pub fn is_paused(&self, mint: &Pubkey) -> bool {
+        self.paused || self.paused_mints.contains(mint)
+    }
+
+    /// Counts the entries pausing the given mint adds, none for the global pause
+    /// or a mint already paused.
+    pub fn new_entries(&self, mint: Option<&Pubkey>) -> usize {
+        usize::from(mint.map_or(false, |mint| !self.paused_mints.contains(mint)))
+    }
+
+    /// Pauses all DAOs, or a single mint when given.
+    pub fn pause(&mut self, mint: Option<Pubkey>) {
+        match mint {
+            Some(mint) => {
+                if !self.paused_mints.contains(&mint) {
+                    self.paused_mints.push(mint);
+                }
+            }
+            None => self.paused = true,
+        }
+    }
+
+    /// Lifts the global pause, or the pause of a single mint when given.
+    pub fn unpause(&mut self, mint: Option<Pubkey>) -> Result<()> {
+        match mint {
+            Some(mint) => {
+                require!(self.paused_mints.contains(&mint), CustomError::NotPaused);
+                self.paused_mints.retain(|m| mint.ne(m));
+            }
+            None => {
+                require!(self.paused, CustomError::NotPaused);
+                self.paused = false;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds guardians, ignoring duplicates.
+    pub fn add_guardians(&mut self, guardians: Vec<Pubkey>) {
+        self.guardians.extend(guardians);
+        self.guardians.sort();
+        self.guardians.dedup();
+    }
+
+    /// Removes guardians.
+    pub fn remove_guardians(&mut self, guardians: Vec<Pubkey>) {
+        self.guardians.retain(|addr| !guardians.contains(addr));
+    }
+}
@@ -0,0 +1,127 @@
+use super::*;
+
+/// Account storing the period over which approved payouts of a DAO are streamed.
+#[account]
+pub struct StreamConfig {
+    /// Streaming period in seconds, approved payouts being paid at once if zero.
+    pub period: i64,
+}
+
+/// Account streaming approved payouts linearly to a creator or deployer.
+#[account]
+pub struct PayoutStream {
+    /// Address the payouts are streamed to.
+    pub recipient: Pubkey,
+
+    /// Whether the recipient is paid as the creator or as a deployer.
+    pub kind: PayoutKind,
+
+    /// Amount already vested when the current stream started.
+    pub released: u64,
+
+    /// Amount vesting linearly between `start_at` and `end_at`.
+    pub amount: u64,
+
+    /// Timestamp the current stream started at.
+    pub start_at: i64,
+
+    /// Timestamp the current stream ends at.
+    pub end_at: i64,
+
+    /// Total amount withdrawn by the recipient.
+    pub withdrawn: u64,
+}
+
+impl PayoutStream {
+    /// Returns the total amount vested at the given time.
+    ///
+    /// # Arguments
+    /// * `now` - The current timestamp.
+    pub fn vested(&self, now: i64) -> u64 {
+        let duration = self.end_at - self.start_at;
+        let streamed = if now >= self.end_at || duration <= 0 {
+            self.amount
+        } else if now <= self.start_at {
+            0
+        } else {
+            (self.amount as u128 * (now - self.start_at) as u128 / duration as u128) as u64
+        };
+
+        self.released + streamed
+    }
+
+    /// Returns the amount the recipient can withdraw at the given time.
+    ///
+    /// # Arguments
+    /// * `now` - The current timestamp.
+    pub fn withdrawable(&self, now: i64) -> u64 {
+        self.vested(now) - self.withdrawn
+    }
+
+    /// Adds an approved payout, streaming it along with the unvested remainder.
+    ///
+    /// The remainder is not restarted over a whole period: the merged stream ends after
+    /// the remaining durations of the remainder and of the payout, weighted by their amounts.
+    ///
+    /// # Arguments
+    /// * `recipient` - Address the payout is streamed to.
+    /// * `kind` - Whether the recipient is paid as the creator or as a deployer.
+    /// * `amount` - The approved payout.
+    /// * `now` - The current timestamp.
+    /// * `period` - Streaming period in seconds.
+    pub fn add(&mut self, recipient: Pubkey, kind: PayoutKind, amount: u64, now: i64, period: i64) {
+        let vested = self.vested(now);
+        let unvested = self.released + self.amount - vested;
+        let total = unvested + amount;
+
+        let remaining = (self.end_at - now).max(0);
+        let duration = if total == 0 {
+            period
+        } else {
+            ((unvested as u128 * remaining as u128 + amount as u128 * period as u128)
+                / total as u128) as i64
+        };
+
+        self.recipient = recipient;
+        self.kind = kind;
+        self.released = vested;
+        self.amount = total;
+        self.start_at = now;
+        self.end_at = now + duration;
+    }
+
+    /// Withdraws the vested amount not withdrawn yet.
+    ///
+    /// # Arguments
+    /// * `now` - The current timestamp.
+    ///
+    /// # Returns
+    /// * `Result<u64>` - The amount withdrawn.
+    pub fn withdraw(&mut self, now: i64) -> Result<u64> {
+        let amount = self.withdrawable(now);
+        require!(amount > 0, CustomError::AmountCannotBeZero);
+
+        self.withdrawn += amount;
+
+        Ok(amount)
+    }
+
+    /// Cancels the rest of the stream, the amount vested so far remaining withdrawable.
+    ///
+    /// # Arguments
+    /// * `now` - The current timestamp.
+    ///
+    /// # Returns
+    /// * `u64` - The unvested amount cancelled.
+    pub fn cancel(&mut self, now: i64) -> u64 {
+        let vested = self.vested(now);
+        let unvested = self.released + self.amount - vested;
+
+        self.released = vested;
+        self.amount = 0;
+        self.start_at = now;
+        self.end_at = now;
+
+        unvested
+    }
+}
@@ -115,6 +115,23 @@ impl ProposalsList {
         Ok(proposal.clone())
     }
 
+    pub fn get_proposal(&self, proposal_id: u32) -> Result<&ProposalData> {
+        self.proposals
+            .iter()
+            .find(|p| p.id == proposal_id)
+            .ok_or(CustomError::NotFound.into())
+    }
+
+    pub fn list_proposals(&self, params: &structs::list_proposals::Params) -> Vec<u32> {
+        self.proposals
+            .iter()
+            .filter(|p| params.matches(p))
+            .skip(params.offset as usize)
+            .take(params.limit.min(MAX_PROPOSALS_PAGE_SIZE) as usize)
+            .map(|p| p.id)
+            .collect()
+    }
+
     pub fn get_dao_name(&self, proposal_id: u32) -> Result<String> {
         let proposal = self
             .proposals
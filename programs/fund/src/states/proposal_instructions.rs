@@ -0,0 +1,58 @@
+use super::*;
+
+/// Account storing the instructions of a custom instruction proposal.
+#[account]
+pub struct ProposalInstructions {
+    /// Id of the proposal these instructions belong to.
+    pub proposal_id: u32,
+
+    /// Instructions invoked in order once the proposal is executed.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+/// Struct representing a single instruction to be invoked by the governance PDA.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProposalInstruction {
+    /// Program to invoke.
+    pub program_id: Pubkey,
+
+    /// Accounts passed to the program.
+    pub accounts: Vec<ProposalAccountMeta>,
+
+    /// Instruction data.
+    pub data: Vec<u8>,
+}
+
+/// Struct describing an account passed to a proposal instruction.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProposalAccountMeta {
+    /// Address of the account.
+    pub pubkey: Pubkey,
+
+    /// Whether the account must sign the instruction.
+    pub is_signer: bool,
+
+    /// Whether the account is writable.
+    pub is_writable: bool,
+}
+
+impl ProposalInstruction {
+    /// Converts the stored instruction into a Solana instruction.
+    pub fn to_instruction(&self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: self
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: self.data.clone(),
+        }
+    }
+}
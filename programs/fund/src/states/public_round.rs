@@ -0,0 +1,34 @@
+use super::*;
+
+/// Account storing the public round configuration of a DAO.
+#[account]
+pub struct PublicRound {
+    /// Maximum commitment of a wallet not listed in `Users`.
+    pub per_wallet_cap: u64,
+
+    /// Whether a commitment must be the only top-level `commitment` of its transaction.
+    pub single_commitment: bool,
+}
+
+impl PublicRound {
+    /// Checks whether a wallet stays within the public round cap.
+    ///
+    /// # Arguments
+    /// * `committed_amount` - Total amount committed by the wallet.
+    pub fn is_within_cap(&self, committed_amount: u64) -> bool {
+        committed_amount <= self.per_wallet_cap
+    }
+
+    /// Checks whether the public round has started, after which its configuration is locked.
+    ///
+    /// A DAO selling in tiers may open a public tier as soon as its sale starts.
+    ///
+    /// # Arguments
+    /// * `status` - Current fundraising status.
+    pub fn has_started(status: Status) -> bool {
+        !matches!(
+            status,
+            Status::Created | Status::FundraisingVip | Status::FundraisingParty
+        )
+    }
+}
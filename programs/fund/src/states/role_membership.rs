@@ -0,0 +1,18 @@
+use super::*;
+
+/// Account storing the permissions granted to an address.
+#[account]
+pub struct RoleMembership {
+    /// Address the permissions are granted to.
+    pub address: Pubkey,
+
+    /// Bitmask of flags from the `permissions` module.
+    pub permissions: u64,
+}
+
+impl RoleMembership {
+    /// Checks whether every flag of the given permission is granted.
+    pub fn has_permission(&self, permission: u64) -> bool {
+        self.permissions & permission == permission
+    }
+}
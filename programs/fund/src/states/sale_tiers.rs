@@ -0,0 +1,163 @@
+use super::*;
+
+/// Account storing the sale tiers of a DAO, ordered by time.
+#[account]
+pub struct SaleTiers {
+    /// Tiers of the sale, not overlapping.
+    pub tiers: Vec<SaleTier>,
+}
+
+/// Struct representing a single sale tier.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SaleTier {
+    /// Timestamp at which the tier opens.
+    pub start_at: i64,
+
+    /// Timestamp at which the tier closes.
+    pub end_at: i64,
+
+    /// Number of tokens allocated per SOL committed in the tier.
+    pub tokens_per_sol: u64,
+
+    /// Maximum commitment of a single user in the tier.
+    pub per_user_cap: u64,
+
+    /// Maximum commitment of all users in the tier.
+    pub tier_cap: u64,
+
+    /// Amount of SOL committed in the tier so far.
+    pub committed: u64,
+}
+
+/// Account storing the commitments of a user in each sale tier of a DAO.
+#[account]
+pub struct SaleTierCommitments {
+    /// Amount of SOL committed, indexed by tier.
+    pub amounts: [u64; MAX_SALE_TIERS],
+}
+
+impl SaleTiers {
+    /// Replaces the tiers, validating them against the tokens for sale.
+    ///
+    /// # Arguments
+    /// * `params` - The new tiers, ordered by time.
+    /// * `tokens_for_sale` - Number of tokens the escrow holds for the sale.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Returns an error if the tiers are invalid.
+    pub fn set(
+        &mut self,
+        params: &[structs::sale_tier::Params],
+        tokens_for_sale: u128,
+    ) -> Result<()> {
+        require!(
+            !params.is_empty() && params.len() <= MAX_SALE_TIERS,
+            CustomError::InvalidSaleTiers
+        );
+
+        let mut tokens_sold: u128 = 0;
+        for (index, tier) in params.iter().enumerate() {
+            require!(
+                tier.start_at < tier.end_at
+                    && tier.tokens_per_sol > 0
+                    && tier.per_user_cap > 0
+                    && tier.per_user_cap <= tier.tier_cap,
+                CustomError::InvalidSaleTiers
+            );
+
+            // Tiers must follow each other without overlapping
+            if index > 0 {
+                require!(
+                    params[index - 1].end_at <= tier.start_at,
+                    CustomError::InvalidSaleTiers
+                );
+            }
+
+            tokens_sold += tier.tier_cap as u128 * tier.tokens_per_sol as u128;
+        }
+
+        // The tiers can't sell more tokens than the escrow holds
+        require!(
+            tokens_sold <= tokens_for_sale,
+            CustomError::InvalidSaleTiers
+        );
+
+        self.tiers = params
+            .iter()
+            .map(|tier| SaleTier {
+                start_at: tier.start_at,
+                end_at: tier.end_at,
+                tokens_per_sol: tier.tokens_per_sol,
+                per_user_cap: tier.per_user_cap,
+                tier_cap: tier.tier_cap,
+                committed: 0,
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    /// Finds the tier open at the given time.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp.
+    ///
+    /// # Returns
+    /// * `Option<usize>` - Index of the active tier, if any.
+    pub fn active_tier(&self, now: i64) -> Option<usize> {
+        self.tiers
+            .iter()
+            .position(|tier| tier.start_at <= now && now < tier.end_at)
+    }
+
+    /// Computes the average number of tokens sold per SOL committed across the tiers.
+    ///
+    /// # Returns
+    /// * `Option<u64>` - The average price, or none if nothing was committed.
+    pub fn average_tokens_per_sol(&self) -> Option<u64> {
+        let (committed, tokens) =
+            self.tiers
+                .iter()
+                .fold((0u128, 0u128), |(committed, tokens), tier| {
+                    (
+                        committed + tier.committed as u128,
+                        tokens + tier.committed as u128 * tier.tokens_per_sol as u128,
+                    )
+                });
+
+        (committed > 0).then(|| (tokens / committed) as u64)
+    }
+
+    /// Records a commitment in a tier, checking the tier and per-user caps.
+    ///
+    /// # Arguments
+    /// * `index` - Index of the tier.
+    /// * `user_commitments` - Commitments of the user in each tier.
+    /// * `amount` - The amount of SOL committed.
+    ///
+    /// # Returns
+    /// * `Result<u64>` - The tokens allocated per SOL in the tier.
+    pub fn commit(
+        &mut self,
+        index: usize,
+        user_commitments: &mut SaleTierCommitments,
+        amount: u64,
+    ) -> Result<u64> {
+        let tier = &mut self.tiers[index];
+
+        let tier_committed = tier
+            .committed
+            .checked_add(amount)
+            .filter(|committed| *committed <= tier.tier_cap)
+            .ok_or(CustomError::SaleTierCapExceeded)?;
+        let user_committed = user_commitments.amounts[index]
+            .checked_add(amount)
+            .filter(|committed| *committed <= tier.per_user_cap)
+            .ok_or(CustomError::CommitAmountExceeded)?;
+
+        tier.committed = tier_committed;
+        user_commitments.amounts[index] = user_committed;
+
+        Ok(tier.tokens_per_sol)
+    }
+}
@@ -26,4 +26,31 @@ pub enum Status {
 
     /// The project has been closed and is no longer active.
     Closed,
+
+    /// The fundraising phase open to every wallet, following the party round.
+    FundraisingPublic,
+
+    /// A milestone has failed, the SOL left in escrow being refunded pro rata.
+    Refunding,
+
+    /// The fundraising phase of a DAO selling in tiers, each tier being a round of its own.
+    Fundraising,
+}
+
+impl Status {
+    /// Checks whether the DAO has not left fundraising yet, started or not.
+    pub fn is_open(&self) -> bool {
+        *self == Status::Created || self.is_fundraising()
+    }
+
+    /// Checks whether commitments are being collected, in any round.
+    pub fn is_fundraising(&self) -> bool {
+        matches!(
+            self,
+            Status::FundraisingVip
+                | Status::FundraisingParty
+                | Status::FundraisingPublic
+                | Status::Fundraising
+        )
+    }
 }
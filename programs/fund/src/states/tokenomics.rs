@@ -0,0 +1,92 @@
+use super::*;
+
+/// Struct defining how the supply and the raise of a DAO are shared, in basis points.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Tokenomics {
+    /// Share of the supply sold to committers.
+    pub sale_bps: u16,
+
+    /// Share of the supply paired with SOL in the liquidity pool.
+    pub liquidity_bps: u16,
+
+    /// Share of the raise withdrawable by creators and deployers.
+    pub withdrawable_bps: u16,
+
+    /// Share of the withdrawable raise going to creators, the rest going to deployers.
+    pub creators_bps: u16,
+}
+
+/// Struct defining the bounds admins set on the tokenomics of new DAOs, in basis points.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TokenomicsBounds {
+    /// Minimum share of the supply paired in the liquidity pool.
+    pub min_liquidity_bps: u16,
+
+    /// Maximum share of the raise withdrawable by creators and deployers.
+    pub max_withdrawable_bps: u16,
+
+    /// Maximum share of the withdrawable raise going to creators.
+    pub max_creators_bps: u16,
+}
+
+impl Default for Tokenomics {
+    /// The tokenomics of DAOs created without explicit ones.
+    fn default() -> Self {
+        Self {
+            sale_bps: 9_000,
+            liquidity_bps: 1_000,
+            withdrawable_bps: 8_800,
+            creators_bps: 4_900,
+        }
+    }
+}
+
+impl Tokenomics {
+    /// Validates the shares, and checks them against the admin bounds if any.
+    ///
+    /// # Arguments
+    /// * `bounds` - The bounds set in `GlobalConfig`, if any.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Returns an error if the shares are invalid or out of bounds.
+    pub fn validate(&self, bounds: Option<&TokenomicsBounds>) -> Result<()> {
+        // The whole supply is either sold or paired in the pool
+        require!(
+            self.sale_bps > 0
+                && self.liquidity_bps > 0
+                && self.sale_bps as u32 + self.liquidity_bps as u32 == MAX_SHARE_BPS as u32
+                && self.withdrawable_bps <= MAX_SHARE_BPS
+                && self.creators_bps <= MAX_SHARE_BPS,
+            CustomError::InvalidTokenomics
+        );
+
+        if let Some(bounds) = bounds {
+            require!(
+                self.liquidity_bps >= bounds.min_liquidity_bps
+                    && self.withdrawable_bps <= bounds.max_withdrawable_bps
+                    && self.creators_bps <= bounds.max_creators_bps,
+                CustomError::InvalidTokenomics
+            );
+        }
+
+        // The raise must fund both the withdrawals and the SOL paired in the pool, the
+        // liquidity tokens being priced at the sale price
+        require!(
+            self.withdrawable_bps as u32
+                + self.liquidity_bps as u32 * MAX_SHARE_BPS as u32 / self.sale_bps as u32
+                <= MAX_SHARE_BPS as u32,
+            CustomError::InvalidTokenomics
+        );
+
+        Ok(())
+    }
+}
+
+impl TokenomicsBounds {
+    /// Checks the bounds are valid shares.
+    pub fn is_valid(&self) -> bool {
+        self.min_liquidity_bps <= MAX_SHARE_BPS
+            && self.max_withdrawable_bps <= MAX_SHARE_BPS
+            && self.max_creators_bps <= MAX_SHARE_BPS
+    }
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+/// Enum representing the kind of a SOL flow of a DAO's treasury.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerEntryKind {
+    /// SOL committed into the escrow.
+    Commitment,
+
+    /// Fees paid to the fees collection account, outside the escrow.
+    Fee,
+
+    /// SOL moved from the escrow to the liquidity pool.
+    MoveToLp,
+
+    /// SOL paid from the escrow to the creators.
+    CreatorPayout,
+
+    /// SOL paid from the escrow to a deployer.
+    DeployerPayout,
+
+    /// SOL refunded from the escrow to a committer.
+    Refund,
+
+    /// SOL drained from an account of the DAO by a reset, outside the escrow.
+    Reset,
+
+    /// Royalty tokens collected into the treasury token account, outside the escrow.
+    Royalty,
+
+    /// Royalty tokens withdrawn from the treasury token account, outside the escrow.
+    RoyaltyWithdrawal,
+}
+
+/// Struct representing a single SOL flow of a DAO's treasury.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct LedgerEntry {
+    /// Kind of the flow.
+    pub kind: LedgerEntryKind,
+
+    /// Amount of SOL, or of tokens for royalties.
+    pub amount: u64,
+
+    /// Escrow balance after the flow.
+    pub balance: u64,
+
+    /// Timestamp of the flow.
+    pub timestamp: i64,
+}
+
+/// Struct representing the SOL totals of a DAO's treasury.
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TreasuryBreakdown {
+    /// SOL held in escrow, as recorded by the ledger.
+    pub balance: u64,
+
+    /// Total SOL committed.
+    pub committed: u64,
+
+    /// Total fees paid.
+    pub fees: u64,
+
+    /// Total SOL moved to the liquidity pool.
+    pub moved_to_lp: u64,
+
+    /// Total SOL paid to the creators.
+    pub creator_payouts: u64,
+
+    /// Total SOL paid to deployers.
+    pub deployer_payouts: u64,
+
+    /// Total SOL refunded.
+    pub refunds: u64,
+}
+
+/// Account recording the SOL flows of a DAO's treasury.
+#[account]
+pub struct TreasuryLedger {
+    /// Running totals of the treasury.
+    pub breakdown: TreasuryBreakdown,
+
+    /// Most recent flows, the oldest being dropped past `MAX_LEDGER_ENTRIES`.
+    pub entries: Vec<LedgerEntry>,
+}
+
+impl TreasuryLedger {
+    /// Records a flow, updating the totals and the running escrow balance.
+    ///
+    /// # Arguments
+    /// * `kind` - Kind of the flow.
+    /// * `amount` - Amount of SOL.
+    /// * `timestamp` - Timestamp of the flow.
+    pub fn record(&mut self, kind: LedgerEntryKind, amount: u64, timestamp: i64) {
+        use LedgerEntryKind::*;
+        let breakdown = &mut self.breakdown;
+        match kind {
+            Commitment => {
+                breakdown.committed += amount;
+                breakdown.balance += amount;
+            }
+            Fee => breakdown.fees += amount,
+            MoveToLp => {
+                breakdown.moved_to_lp += amount;
+                breakdown.balance = breakdown.balance.saturating_sub(amount);
+            }
+            CreatorPayout => {
+                breakdown.creator_payouts += amount;
+                breakdown.balance = breakdown.balance.saturating_sub(amount);
+            }
+            DeployerPayout => {
+                breakdown.deployer_payouts += amount;
+                breakdown.balance = breakdown.balance.saturating_sub(amount);
+            }
+            Refund => {
+                breakdown.refunds += amount;
+                breakdown.balance = breakdown.balance.saturating_sub(amount);
+            }
+            Reset | Royalty | RoyaltyWithdrawal => (),
+        }
+
+        if self.entries.len() >= MAX_LEDGER_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(LedgerEntry {
+            kind,
+            amount,
+            balance: breakdown.balance,
+            timestamp,
+        });
+    }
+}
@@ -8,6 +8,22 @@ pub enum UserType {
 
     /// Regular party users participating in fundraising.
     Party,
+
+    /// Users of a sale tier, eligible in that tier only. They are allowlisted through
+    /// the tier's Merkle root rather than stored in `Users`.
+    Tier(u8),
+}
+
+impl UserType {
+    /// Returns the bytes identifying the user type in allowlist leaves, matching its
+    /// Borsh encoding.
+    pub fn leaf_bytes(&self) -> Vec<u8> {
+        match self {
+            UserType::Vip => vec![0],
+            UserType::Party => vec![1],
+            UserType::Tier(index) => vec![2, *index],
+        }
+    }
 }
 
 #[account]
@@ -21,7 +37,7 @@ pub struct Users {
 
 impl Users {
     /// Adds a list of new VIP users, ensuring no duplicates.
-    pub fn add_vip_users(&mut self, users: Vec<UserDetails>, blacklist: &Blacklist) -> Result<()> {
+    pub fn add_vip_users(&mut self, users: Vec<UserDetails>) -> Result<()> {
         let existing_addresses: HashSet<_> = self
             .vip
             .iter()
@@ -40,12 +56,6 @@ impl Users {
                 CustomError::AmountCannotBeZero
             );
 
-            // Ensure the user is not blacklisted
-            require!(
-                !blacklist.is_blocked(&user.address),
-                CustomError::BlockedAccount
-            );
-
             self.vip.push(user);
         }
 
@@ -59,11 +69,7 @@ impl Users {
     }
 
     /// Adds a list of new party users, ensuring no duplicates.
-    pub fn add_party_users(
-        &mut self,
-        users: Vec<UserDetails>,
-        blacklist: &Blacklist,
-    ) -> Result<()> {
+    pub fn add_party_users(&mut self, users: Vec<UserDetails>) -> Result<()> {
         let existing_addresses: HashSet<_> = self
             .vip
             .iter()
@@ -82,12 +88,6 @@ impl Users {
                 CustomError::AmountCannotBeZero
             );
 
-            // Ensure the user is not blacklisted
-            require!(
-                !blacklist.is_blocked(&user.address),
-                CustomError::BlockedAccount
-            );
-
             self.party.push(user);
         }
 
@@ -96,16 +96,99 @@ impl Users {
 
     /// Removes a list of party users from the list.
     pub fn remove_party_users(&mut self, users: Vec<UserDetails>) {
-        self.vip
+        self.party
             .retain(|user| !users.iter().any(|u| u.address == user.address));
     }
 
+    /// Returns the list of users of the given type, none for users of a sale tier.
+    fn list(&self, user_type: UserType) -> Option<&Vec<UserDetails>> {
+        match user_type {
+            UserType::Vip => Some(&self.vip),
+            UserType::Party => Some(&self.party),
+            UserType::Tier(_) => None,
+        }
+    }
+
+    /// Returns the mutable list of users of the given type, none for users of a sale tier.
+    fn list_mut(&mut self, user_type: UserType) -> Option<&mut Vec<UserDetails>> {
+        match user_type {
+            UserType::Vip => Some(&mut self.vip),
+            UserType::Party => Some(&mut self.party),
+            UserType::Tier(_) => None,
+        }
+    }
+
+    /// Counts the given addresses listed as users of the given type.
+    pub fn count_listed(&self, user_type: UserType, addresses: &[Pubkey]) -> usize {
+        self.list(user_type).map_or(0, |list| {
+            list.iter()
+                .filter(|user| addresses.contains(&user.address))
+                .count()
+        })
+    }
+
+    /// Removes users of the given type by address, ignoring addresses not listed.
+    pub fn remove_by_address(&mut self, user_type: UserType, addresses: &[Pubkey]) {
+        if let Some(list) = self.list_mut(user_type) {
+            list.retain(|user| !addresses.contains(&user.address));
+        }
+    }
+
+    /// Updates the maximum allowable amount of listed users of the given type.
+    pub fn update_caps(&mut self, user_type: UserType, users: Vec<UserDetails>) -> Result<()> {
+        let list = self
+            .list_mut(user_type)
+            .ok_or(CustomError::InvalidUserType)?;
+
+        for user in users {
+            // Ensure max_allowable_amount can't be 0
+            require!(
+                user.max_allowable_amount > 0,
+                CustomError::AmountCannotBeZero
+            );
+
+            let listed = list
+                .iter_mut()
+                .find(|listed| listed.address == user.address)
+                .ok_or(CustomError::AddressNotFound)?;
+            listed.max_allowable_amount = user.max_allowable_amount;
+        }
+
+        Ok(())
+    }
+
+    /// Moves listed users of the given type to the other type, keeping their caps.
+    pub fn move_tier(&mut self, from: UserType, addresses: &[Pubkey]) -> Result<()> {
+        let (source, target) = match from {
+            UserType::Vip => (&mut self.vip, &mut self.party),
+            UserType::Party => (&mut self.party, &mut self.vip),
+            UserType::Tier(_) => return Err(CustomError::InvalidUserType.into()),
+        };
+        require!(
+            source
+                .iter()
+                .filter(|user| addresses.contains(&user.address))
+                .count()
+                == addresses.len(),
+            CustomError::AddressNotFound
+        );
+
+        let (moved, kept) = source
+            .drain(..)
+            .partition(|user| addresses.contains(&user.address));
+        *source = kept;
+        target.extend::<Vec<UserDetails>>(moved);
+
+        Ok(())
+    }
+
     /// Checks if a given user (identified by `address`) is eligible to participate in fundraising.
-    /// VIP users are always eligible, while party users can only participate in the `FundraisingParty` phase.
+    /// VIP users are always eligible, in every round or sale tier, while party users can only
+    /// participate in the `FundraisingParty` phase and later.
     pub fn is_eligible(&self, address: &Pubkey, status: Status) -> bool {
         self.vip.iter().any(|u| &u.address == address)
             || (self.party.iter().any(|u| &u.address == address)
-                && status == Status::FundraisingParty)
+                && matches!(status, Status::FundraisingParty | Status::FundraisingPublic))
     }
 
     /// Checks if the provided amount is within the maximum allowable limit for a given address.
@@ -0,0 +1,17 @@
+use super::*;
+
+/// The struct containing instructions for block user proposal params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct Params {
+    /// User Address
+    pub address: Pubkey,
+
+    /// Token Name of the DAO the block applies to, all DAOs if none
+    pub token: Option<String>,
+
+    /// Timestamp at which the block is lifted, never if none
+    pub blocked_until: Option<i64>,
+
+    /// Reason code of the block
+    pub reason: u16,
+}
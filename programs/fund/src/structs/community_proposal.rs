@@ -0,0 +1,14 @@
+use super::*;
+
+/// The struct containing instructions for community proposal params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Params {
+    /// Token Name
+    pub token: String,
+
+    /// Proposal Type, either `RemoveLiquidity`, `TransferSolToCreator` or `FailMilestone`
+    pub proposal_type: ProposalType,
+
+    /// Percent of liquidity to remove, amount of SOL to transfer or index of the milestone
+    pub amount: u64,
+}
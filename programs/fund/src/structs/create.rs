@@ -23,4 +23,16 @@ pub struct Params {
 
     /// Token Amount
     pub amount: u64,
+
+    /// Co-creators with their shares, the caller holding all shares if empty
+    pub co_creators: Vec<CoCreator>,
+
+    /// Tokenomics, the default ones if none
+    pub tokenomics: Option<Tokenomics>,
+
+    /// Milestones releasing the creator funds, released freely if empty
+    pub milestones: Vec<structs::milestone::Params>,
+
+    /// Token-2022 extensions of the mint, none for legacy mints
+    pub token_extensions: Option<structs::token_extensions::Params>,
 }
@@ -0,0 +1,20 @@
+use super::*;
+
+/// The struct containing instructions for init governance params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Params {
+    /// Token Name
+    pub token: String,
+
+    /// Share of the token supply that must vote, upto 6 decimal places
+    pub quorum_percent: u32,
+
+    /// Share of the cast votes that must be in favour, upto 6 decimal places
+    pub approval_percent: u32,
+
+    /// Share of the token supply needed to create a proposal, upto 6 decimal places
+    pub proposal_threshold_percent: u32,
+
+    /// Voting period in seconds
+    pub voting_period: i64,
+}
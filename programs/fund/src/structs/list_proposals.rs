@@ -0,0 +1,39 @@
+use super::*;
+
+/// The struct containing instructions for list proposals params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Params {
+    /// Only proposals in this state
+    pub status: Option<ProposalState>,
+
+    /// Only proposals of this type
+    pub proposal_type: Option<ProposalType>,
+
+    /// Only proposals of this DAO
+    pub dao_name: Option<String>,
+
+    /// Only proposals created by this address
+    pub created_by: Option<Pubkey>,
+
+    /// Number of matching proposals to skip
+    pub offset: u32,
+
+    /// Maximum number of proposal ids to return, capped at `MAX_PROPOSALS_PAGE_SIZE`
+    pub limit: u32,
+}
+
+impl Params {
+    /// Checks whether a proposal matches every filter that is set.
+    pub fn matches(&self, proposal: &ProposalData) -> bool {
+        self.status.map_or(true, |status| status.matches(proposal))
+            && self.proposal_type.map_or(true, |proposal_type| {
+                proposal.proposal_type == proposal_type
+            })
+            && self.dao_name.as_ref().map_or(true, |dao_name| {
+                proposal.dao_name.as_ref() == Some(dao_name)
+            })
+            && self
+                .created_by
+                .map_or(true, |created_by| proposal.created_by == created_by)
+    }
+}
@@ -25,3 +25,49 @@ impl Params {
         }
     }
 }
+
+/// Update applied to users already listed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum UserUpdate {
+    /// Sets the maximum allowable amount of the users
+    UpdateCap(Vec<UserDetails>),
+
+    /// Removes the users with the given addresses
+    RemoveByAddress(Vec<Pubkey>),
+
+    /// Moves the users with the given addresses to the other user type
+    MoveTier(Vec<Pubkey>),
+}
+
+/// The struct containing instructions for update users params
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct UpdateParams {
+    /// Token Name
+    pub token: String,
+
+    /// User Type the listed users belong to
+    pub user_type: UserType,
+
+    /// Update to apply
+    pub update: UserUpdate,
+}
+
+impl UpdateParams {
+    /// Returns the addresses of the users being updated.
+    pub fn addresses(&self) -> Vec<Pubkey> {
+        match &self.update {
+            UserUpdate::UpdateCap(users) => users.iter().map(|user| user.address).collect(),
+            UserUpdate::RemoveByAddress(addresses) | UserUpdate::MoveTier(addresses) => {
+                addresses.clone()
+            }
+        }
+    }
+
+    pub fn to_events(&self) -> events::UsersUpdated {
+        events::UsersUpdated {
+            token: self.token.to_string(),
+            user_type: self.user_type,
+            addresses: self.addresses(),
+        }
+    }
+}
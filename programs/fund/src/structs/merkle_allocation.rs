@@ -0,0 +1,14 @@
+use super::*;
+
+/// The struct containing the allowlisted allocation proven by a committer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct Params {
+    /// Maximum commitment of the user, in lamports
+    pub max_allowable_amount: u64,
+
+    /// Round the user is allowlisted for
+    pub tier: UserType,
+
+    /// Sibling hashes from the leaf up to the root
+    pub proof: Vec<[u8; 32]>,
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+/// The struct containing the metadata changes proposed for a DAO token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Default)]
+pub struct Params {
+    /// New name of the token, unchanged if none
+    pub name: Option<String>,
+
+    /// New symbol of the token, unchanged if none
+    pub symbol: Option<String>,
+
+    /// New URI of the token, unchanged if none
+    pub uri: Option<String>,
+
+    /// Collection the token belongs to, unchanged if none
+    pub collection: Option<Pubkey>,
+}
+
+impl Params {
+    /// Ensures at least one field changes and each fits in Metaplex metadata
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.name.is_some()
+                || self.symbol.is_some()
+                || self.uri.is_some()
+                || self.collection.is_some(),
+            CustomError::InvalidMetadataUpdate
+        );
+        for (value, max_len) in [
+            (&self.name, MAX_METADATA_NAME_LEN),
+            (&self.symbol, MAX_METADATA_SYMBOL_LEN),
+            (&self.uri, MAX_METADATA_URI_LEN),
+        ] {
+            require!(
+                value
+                    .as_ref()
+                    .map_or(true, |value| !value.is_empty() && value.len() <= max_len),
+                CustomError::InvalidMetadataUpdate
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+/// The struct containing the parameters of a milestone
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct Params {
+    /// Hash of the milestone description
+    pub description_hash: [u8; 32],
+
+    /// Share of the creators' withdrawable raise released with the milestone, in basis points
+    pub release_bps: u16,
+}
@@ -1,11 +1,20 @@
 use super::*;
 
 pub mod add_creator;
+pub mod block_user;
 pub mod burn;
+pub mod community_proposal;
 pub mod create;
+pub mod init_governance;
+pub mod list_proposals;
 pub mod manage_users;
+pub mod merkle_allocation;
+pub mod metadata_update;
+pub mod milestone;
 pub mod mint;
+pub mod sale_tier;
 pub mod start_dao;
+pub mod token_extensions;
 pub mod transfer;
 pub mod update_fee;
 pub mod update_status;
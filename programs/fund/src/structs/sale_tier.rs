@@ -0,0 +1,20 @@
+use super::*;
+
+/// The struct containing the parameters of a sale tier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct Params {
+    /// Timestamp at which the tier opens
+    pub start_at: i64,
+
+    /// Timestamp at which the tier closes
+    pub end_at: i64,
+
+    /// Number of tokens allocated per SOL committed in the tier
+    pub tokens_per_sol: u64,
+
+    /// Maximum commitment of a single user in the tier, in lamports
+    pub per_user_cap: u64,
+
+    /// Maximum commitment of all users in the tier, in lamports
+    pub tier_cap: u64,
+}
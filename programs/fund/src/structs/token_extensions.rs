@@ -0,0 +1,25 @@
+use super::*;
+
+/// The struct containing the Token-2022 extensions of a DAO mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default)]
+pub struct Params {
+    /// Royalty withheld on every transfer and routed to the DAO treasury, in basis points, none if 0
+    pub transfer_fee_bps: u16,
+
+    /// Maximum royalty withheld on a transfer, in tokens
+    pub maximum_transfer_fee: u64,
+
+    /// Whether transfers are checked against the blacklist by the transfer hook program
+    pub transfer_hook: bool,
+}
+
+impl Params {
+    /// Ensures the transfer fee is within the maximum royalty
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.transfer_fee_bps <= MAX_TRANSFER_FEE_BPS,
+            CustomError::InvalidTransferFee
+        );
+        Ok(())
+    }
+}
@@ -10,6 +10,9 @@ pub struct Params {
     /// Creator Address
     pub token: String,
 
-    /// Fee Percent
-    pub fee_percent: u32,
+    /// Proposal Id, unused when executed by the governance PDA
+    pub proposal_id: u32,
+
+    /// Fee percent applied when executed by the governance PDA
+    pub fee_percent: Option<u32>,
 }
@@ -4,7 +4,7 @@ use super::*;
 fn test_calc_token_per_sol() {
     let fundraise_goal = 5 * 10u128.pow(9);
     let supply = 10u128.pow(9) * 10u128.pow(6);
-    let result = calc_token_per_sol(fundraise_goal, supply);
+    let result = calc_token_per_sol(fundraise_goal, supply, Tokenomics::default().sale_bps);
     assert_eq!(result, 180000);
 }
 
@@ -31,3 +31,1975 @@ fn test_calc_days_large_gap() {
     // 1000-day difference
     assert_eq!(calc_days(1_786_400_000, 1_700_000_000), 1000);
 }
+
+const BLACKLIST_ACTIONS: [BlacklistAction; 5] = [
+    BlacklistAction::Commit,
+    BlacklistAction::Buy,
+    BlacklistAction::Sell,
+    BlacklistAction::ClaimTokens,
+    BlacklistAction::ClaimRefund,
+];
+
+/// Asserts the actions a policy allows a blacklisted user, in `BLACKLIST_ACTIONS` order
+fn assert_blacklist_policy(policy: BlacklistPolicy, allowed: [bool; 5]) {
+    for (action, allowed) in BLACKLIST_ACTIONS.iter().zip(allowed) {
+        assert_eq!(policy.allows(*action), allowed, "{:?} {:?}", policy, action);
+    }
+}
+
+#[test]
+fn test_blacklist_policy_block_all() {
+    assert_blacklist_policy(
+        BlacklistPolicy::BlockAll,
+        [false, false, false, false, false],
+    );
+}
+
+#[test]
+fn test_blacklist_policy_block_inflows() {
+    assert_blacklist_policy(
+        BlacklistPolicy::BlockInflows,
+        [false, false, true, true, true],
+    );
+}
+
+#[test]
+fn test_blacklist_policy_freeze_claims() {
+    assert_blacklist_policy(
+        BlacklistPolicy::FreezeClaims,
+        [false, false, true, false, false],
+    );
+}
+
+#[test]
+fn test_blacklist_policy_refunds_only() {
+    assert_blacklist_policy(
+        BlacklistPolicy::RefundsOnly,
+        [false, false, false, false, true],
+    );
+}
+
+#[test]
+fn test_blacklist_policy_codes() {
+    // Codes carried by proposals map back to the policy they were created from
+    for policy in [
+        BlacklistPolicy::BlockAll,
+        BlacklistPolicy::BlockInflows,
+        BlacklistPolicy::FreezeClaims,
+        BlacklistPolicy::RefundsOnly,
+    ] {
+        assert_eq!(BlacklistPolicy::from_code(policy as u64), Some(policy));
+    }
+    assert_eq!(BlacklistPolicy::from_code(4), None);
+}
+
+/// Hashes a pair of Merkle nodes the way `verify_merkle_proof` does
+fn hash_merkle_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    keccak::hashv(&[&[1u8], &first, &second]).to_bytes()
+}
+
+#[test]
+fn test_verify_merkle_proof() {
+    let users = [
+        (Pubkey::new_unique(), 1_000_000_000, UserType::Vip),
+        (Pubkey::new_unique(), 2_000_000_000, UserType::Party),
+        (Pubkey::new_unique(), 3_000_000_000, UserType::Party),
+    ];
+    let leaves: Vec<[u8; 32]> = users
+        .iter()
+        .map(|(address, amount, tier)| calc_merkle_leaf(address, *amount, *tier))
+        .collect();
+
+    // Odd leaf is promoted to the next level
+    let left = hash_merkle_pair(leaves[0], leaves[1]);
+    let root = hash_merkle_pair(left, leaves[2]);
+
+    assert!(verify_merkle_proof(
+        &[leaves[1], leaves[2]],
+        &root,
+        leaves[0]
+    ));
+    assert!(verify_merkle_proof(
+        &[leaves[0], leaves[2]],
+        &root,
+        leaves[1]
+    ));
+    assert!(verify_merkle_proof(&[left], &root, leaves[2]));
+
+    // A different amount or tier does not match the published leaf
+    let (address, amount, _) = users[0];
+    let inflated = calc_merkle_leaf(&address, amount + 1, UserType::Vip);
+    assert!(!verify_merkle_proof(
+        &[leaves[1], leaves[2]],
+        &root,
+        inflated
+    ));
+    let other_tier = calc_merkle_leaf(&address, amount, UserType::Party);
+    assert!(!verify_merkle_proof(
+        &[leaves[1], leaves[2]],
+        &root,
+        other_tier
+    ));
+}
+
+#[test]
+fn test_sale_tiers() {
+    let tier =
+        |start_at, end_at, tokens_per_sol, per_user_cap, tier_cap| structs::sale_tier::Params {
+            start_at,
+            end_at,
+            tokens_per_sol,
+            per_user_cap,
+            tier_cap,
+        };
+    let params = [tier(100, 200, 20, 5, 10), tier(200, 300, 10, 10, 30)];
+
+    // Tiers must fit the tokens for sale, and not overlap
+    let mut sale_tiers = SaleTiers { tiers: vec![] };
+    assert!(sale_tiers.set(&params, 499).is_err());
+    assert!(sale_tiers
+        .set(&[params[0], tier(150, 300, 10, 10, 30)], 500)
+        .is_err());
+    assert!(sale_tiers.set(&[tier(100, 200, 20, 11, 10)], 500).is_err());
+    assert!(sale_tiers.set(&params, 500).is_ok());
+
+    assert_eq!(sale_tiers.active_tier(99), None);
+    assert_eq!(sale_tiers.active_tier(100), Some(0));
+    assert_eq!(sale_tiers.active_tier(200), Some(1));
+    assert_eq!(sale_tiers.active_tier(300), None);
+
+    // Each tier applies its own price and caps
+    let mut user = SaleTierCommitments {
+        amounts: [0; MAX_SALE_TIERS],
+    };
+    let mut other = SaleTierCommitments {
+        amounts: [0; MAX_SALE_TIERS],
+    };
+    assert_eq!(sale_tiers.commit(0, &mut user, 5).unwrap(), 20);
+    assert_eq!(sale_tiers.commit(0, &mut other, 5).unwrap(), 20);
+    assert_eq!(sale_tiers.commit(1, &mut user, 10).unwrap(), 10);
+    assert_eq!(
+        sale_tiers.commit(1, &mut user, 1).unwrap_err(),
+        CustomError::CommitAmountExceeded.into()
+    );
+    assert_eq!(
+        sale_tiers
+            .commit(
+                0,
+                &mut SaleTierCommitments {
+                    amounts: [0; MAX_SALE_TIERS],
+                },
+                1
+            )
+            .unwrap_err(),
+        CustomError::SaleTierCapExceeded.into()
+    );
+
+    // Overflowing amounts are rejected instead of wrapping
+    assert_eq!(
+        sale_tiers
+            .commit(
+                1,
+                &mut SaleTierCommitments {
+                    amounts: [0; MAX_SALE_TIERS],
+                },
+                u64::MAX
+            )
+            .unwrap_err(),
+        CustomError::SaleTierCapExceeded.into()
+    );
+
+    // Liquidity is paired at the average price of the tokens sold: 10 SOL at 20 and 10 at 10
+    assert_eq!(sale_tiers.average_tokens_per_sol(), Some(15));
+    assert_eq!(SaleTiers { tiers: vec![] }.average_tokens_per_sol(), None);
+}
+
+#[test]
+fn test_users_updates() {
+    let user = |max_allowable_amount| UserDetails {
+        address: Pubkey::new_unique(),
+        max_allowable_amount,
+    };
+    let (vip, party, other) = (user(10), user(20), user(30));
+    let mut users = Users {
+        vip: vec![],
+        party: vec![],
+    };
+    users.add_vip_users(vec![vip.clone()]).unwrap();
+    users
+        .add_party_users(vec![party.clone(), other.clone()])
+        .unwrap();
+
+    // Caps can only be updated for listed users of the given type
+    users
+        .update_caps(
+            UserType::Vip,
+            vec![UserDetails {
+                address: vip.address,
+                max_allowable_amount: 15,
+            }],
+        )
+        .unwrap();
+    assert_eq!(users.vip[0].max_allowable_amount, 15);
+    assert!(users
+        .update_caps(UserType::Vip, vec![party.clone()])
+        .is_err());
+
+    // Moving keeps the cap
+    assert!(users.move_tier(UserType::Vip, &[party.address]).is_err());
+    users.move_tier(UserType::Party, &[party.address]).unwrap();
+    assert!(users.vip == vec![users.vip[0].clone(), party.clone()]);
+    assert!(users.party == vec![other.clone()]);
+
+    // Party users can be removed, and the account shrinks accordingly
+    assert_eq!(
+        users.count_listed(UserType::Party, &[other.address, vip.address]),
+        1
+    );
+    users.remove_party_users(vec![other]);
+    assert!(users.party.is_empty());
+    users.remove_by_address(UserType::Vip, &[vip.address]);
+    assert!(users.vip == vec![party.clone()]);
+    assert_eq!(calc_users_size(1), calc_init_user_size() + 40);
+
+    // Users of a sale tier are allowlisted through its root, never stored
+    let tier = UserType::Tier(0);
+    assert_eq!(users.count_listed(tier, &[party.address]), 0);
+    assert!(users.update_caps(tier, vec![party.clone()]).is_err());
+    assert!(users.move_tier(tier, &[party.address]).is_err());
+    users.remove_by_address(tier, &[party.address]);
+    assert!(users.vip == vec![party]);
+}
+
+#[test]
+fn test_co_creator_shares() {
+    // Rounding remainders go to the first share
+    assert_eq!(
+        split_by_bps(1_000, &[3_333, 3_333, 3_334]),
+        vec![334, 333, 333]
+    );
+    assert_eq!(split_by_bps(1_000_000, &[10_000]), vec![1_000_000]);
+    assert_eq!(
+        bps_to_percent_shares(&[3_333, 3_333, 3_334]),
+        vec![34, 33, 33]
+    );
+    assert_eq!(bps_to_percent_shares(&[9_900, 100]), vec![99, 1]);
+    assert_eq!(bps_to_percent_shares(&[9_950, 50]), vec![100, 0]);
+
+    // Shares rounded away in the metadata are rejected, every co-creator holding 1% at least
+    let creator = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let co_creators = |shares: [u16; 2]| {
+        vec![
+            CoCreator {
+                address: creator,
+                share_bps: shares[0],
+            },
+            CoCreator {
+                address: other,
+                share_bps: shares[1],
+            },
+        ]
+    };
+    assert!(CoCreator::validate(&co_creators([9_950, 50]), creator).is_err());
+    assert!(CoCreator::validate(&co_creators([9_900, 100]), creator).is_ok());
+    assert!(CoCreator::validate(&co_creators([9_900, 100]), Pubkey::new_unique()).is_err());
+    assert!(CoCreator::validate(&co_creators([9_900, 99]), creator).is_err());
+
+    // Withdrawals are split between the co-creators, only when approved for one of them
+    assert_eq!(
+        CoCreator::split(&co_creators([7_500, 2_500]), 1_000, other).unwrap(),
+        vec![(creator, 750), (other, 250)]
+    );
+    assert!(CoCreator::split(&co_creators([7_500, 2_500]), 1_000, Pubkey::new_unique()).is_err());
+    assert_eq!(
+        CoCreator::split(&[], 1_000, other).unwrap(),
+        vec![(other, 1_000)]
+    );
+}
+
+#[test]
+fn test_tokenomics() {
+    let tokenomics = Tokenomics::default();
+    assert!(tokenomics.validate(None).is_ok());
+
+    // The supply must be fully allocated between sale and liquidity
+    let unallocated = Tokenomics {
+        sale_bps: 8_000,
+        ..tokenomics
+    };
+    assert!(unallocated.validate(None).is_err());
+
+    // Admin bounds apply on top
+    let bounds = TokenomicsBounds {
+        min_liquidity_bps: 1_500,
+        max_withdrawable_bps: 10_000,
+        max_creators_bps: 5_000,
+    };
+    assert!(tokenomics.validate(Some(&bounds)).is_err());
+    let bounded = Tokenomics {
+        sale_bps: 8_500,
+        liquidity_bps: 1_500,
+        withdrawable_bps: 8_000,
+        ..tokenomics
+    };
+    assert!(bounded.validate(Some(&bounds)).is_ok());
+
+    // The raise must cover both the withdrawals and the SOL paired in the pool,
+    // 1_000 liquidity bps at a 9_000 bps sale needing 1_111 bps of the raise
+    let funded = Tokenomics {
+        sale_bps: 9_000,
+        liquidity_bps: 1_000,
+        withdrawable_bps: 8_889,
+        ..tokenomics
+    };
+    assert!(funded.validate(None).is_ok());
+    let overdrawn = Tokenomics {
+        withdrawable_bps: 8_890,
+        ..funded
+    };
+    assert!(overdrawn.validate(None).is_err());
+    let all_liquidity = Tokenomics {
+        sale_bps: 5_000,
+        liquidity_bps: 5_000,
+        withdrawable_bps: 0,
+        ..tokenomics
+    };
+    assert!(all_liquidity.validate(None).is_ok());
+
+    // The default creators' share matches the former hardcoded percentage
+    assert_eq!(
+        calc_bps_amount(900_000_000, tokenomics.creators_bps),
+        calc_amount(900_000_000, 49000000)
+    );
+}
+
+#[test]
+fn test_milestone_refunds() {
+    // Committers share the escrow left pro rata to their commitments
+    let pool = RefundPool {
+        sol_amount: 600_000_000,
+        total_commited_sols: 1_000_000_000,
+    };
+    assert_eq!(pool.refund_amount(250_000_000), 150_000_000);
+    assert_eq!(pool.refund_amount(1_000_000_000), 600_000_000);
+
+    let empty = RefundPool {
+        sol_amount: 600_000_000,
+        total_commited_sols: 0,
+    };
+    assert_eq!(empty.refund_amount(250_000_000), 0);
+}
+
+#[test]
+fn test_payout_stream() {
+    let mut stream = PayoutStream {
+        recipient: Pubkey::default(),
+        kind: PayoutKind::Creator,
+        released: 0,
+        amount: 0,
+        start_at: 0,
+        end_at: 0,
+        withdrawn: 0,
+    };
+
+    // Payouts vest linearly over the period
+    stream.add(Pubkey::default(), PayoutKind::Creator, 1_000, 100, 100);
+    assert_eq!(stream.vested(100), 0);
+    assert_eq!(stream.vested(150), 500);
+    assert_eq!(stream.withdraw(150).unwrap(), 500);
+    assert!(stream.withdraw(150).is_err());
+
+    // A new payout merges with the unvested remainder, which keeps its remaining
+    // 50s rather than restarting over a whole period: 500 over 50s and 500 over 100s
+    // end after 75s
+    stream.add(Pubkey::default(), PayoutKind::Creator, 500, 150, 100);
+    assert_eq!(stream.end_at, 225);
+    assert_eq!(stream.withdrawable(150), 0);
+    assert_eq!(stream.withdrawable(200), 666);
+    assert_eq!(stream.withdrawable(225), 1_000);
+
+    // Cancelling keeps the vested amount withdrawable
+    assert_eq!(stream.cancel(200), 334);
+    assert_eq!(stream.withdrawable(1_000), 666);
+    assert_eq!(stream.vested(1_000), 1_166);
+
+    // A payout added to a finished stream vests over the whole period
+    stream.add(Pubkey::default(), PayoutKind::Creator, 300, 1_000, 100);
+    assert_eq!(stream.end_at, 1_100);
+    assert_eq!(stream.vested(1_050), 1_316);
+}
+
+#[test]
+fn test_treasury_ledger() {
+    let mut ledger = TreasuryLedger {
+        breakdown: TreasuryBreakdown::default(),
+        entries: vec![],
+    };
+
+    ledger.record(LedgerEntryKind::Commitment, 1_000, 1);
+    ledger.record(LedgerEntryKind::Fee, 10, 1);
+    ledger.record(LedgerEntryKind::MoveToLp, 100, 2);
+    ledger.record(LedgerEntryKind::CreatorPayout, 400, 3);
+    ledger.record(LedgerEntryKind::Refund, 50, 4);
+
+    // Fees are paid outside the escrow
+    assert_eq!(ledger.breakdown.balance, 450);
+    assert_eq!(ledger.breakdown.fees, 10);
+    assert_eq!(ledger.entries[1].balance, 1_000);
+    assert_eq!(ledger.entries.last().unwrap().balance, 450);
+
+    // Resets drain accounts outside the escrow, recorded without moving its balance
+    ledger.record(LedgerEntryKind::Reset, 5, 5);
+    assert_eq!(ledger.breakdown.balance, 450);
+    assert_eq!(ledger.entries.last().unwrap().kind, LedgerEntryKind::Reset);
+
+    // Royalties are tokens held outside the escrow, recorded without moving its balance
+    ledger.record(LedgerEntryKind::Royalty, 70, 6);
+    ledger.record(LedgerEntryKind::RoyaltyWithdrawal, 30, 7);
+    assert_eq!(ledger.breakdown.balance, 450);
+    assert_eq!(ledger.entries[ledger.entries.len() - 2].amount, 70);
+    assert_eq!(
+        ledger.entries.last().unwrap().kind,
+        LedgerEntryKind::RoyaltyWithdrawal
+    );
+
+    // Only the most recent flows are kept
+    for timestamp in 0..MAX_LEDGER_ENTRIES as i64 {
+        ledger.record(LedgerEntryKind::Commitment, 1, timestamp);
+    }
+    assert_eq!(ledger.entries.len(), MAX_LEDGER_ENTRIES);
+    assert_eq!(ledger.entries[0].kind, LedgerEntryKind::Commitment);
+    assert_eq!(
+        ledger.breakdown.committed,
+        1_000 + MAX_LEDGER_ENTRIES as u64
+    );
+}
+
+#[test]
+fn test_closed_dao_refunds() {
+    let alice = Pubkey::new_unique();
+    let bob = Pubkey::new_unique();
+    let mut commitments = Commitments {
+        commiters: vec![
+            CommitmentDetails {
+                address: alice,
+                sol_amount: 600,
+                token_amount: 6_000,
+                last_claimed_at: None,
+                amount_claimed: 0,
+            },
+            CommitmentDetails {
+                address: bob,
+                sol_amount: 400,
+                token_amount: 4_000,
+                last_claimed_at: None,
+                amount_claimed: 1_000,
+            },
+        ],
+        total_commited_sols: 1_000,
+    };
+
+    // Half of the escrow was paid out before the DAO was closed, and bob's refund is
+    // reduced by the tokens he claimed: alice shares the pool with bob's 300 left
+    let mut pool = RefundPool {
+        sol_amount: 500,
+        total_commited_sols: 1_000,
+    };
+    assert_eq!(
+        commitments.claim_refund(&alice, Some(&mut pool)).unwrap(),
+        333
+    );
+    assert!(commitments.return_claimed_tokens(&alice, 1).is_err());
+
+    // Claimed tokens are deducted unless burned back
+    assert!(commitments.return_claimed_tokens(&bob, 2_000).is_err());
+    assert!(commitments.return_claimed_tokens(&bob, 500).is_ok());
+
+    // The last committer refunded gets the pool left, none of it staying stuck
+    assert_eq!(
+        commitments.claim_refund(&bob, Some(&mut pool)).unwrap(),
+        167
+    );
+    assert_eq!(pool.sol_amount, 0);
+    assert_eq!(commitments.claim_refund(&bob, Some(&mut pool)).unwrap(), 0);
+
+    // Without a pool, committers are refunded their reduced commitment
+    let mut commitments = Commitments {
+        commiters: vec![CommitmentDetails {
+            address: bob,
+            sol_amount: 400,
+            token_amount: 4_000,
+            last_claimed_at: None,
+            amount_claimed: 1_000,
+        }],
+        total_commited_sols: 400,
+    };
+    assert_eq!(commitments.claim_refund(&bob, None).unwrap(), 300);
+}
+
+#[test]
+fn test_metadata_creators_field() {
+    let alice = Pubkey::new_unique();
+    let bob = Pubkey::new_unique();
+    let creators = vec![
+        mpl_token_metadata::types::Creator {
+            address: alice,
+            verified: false,
+            share: 60,
+        },
+        mpl_token_metadata::types::Creator {
+            address: bob,
+            verified: false,
+            share: 40,
+        },
+    ];
+
+    let value = format_metadata_creators(&creators);
+    assert_eq!(value, format!("{}:60,{}:40", alice, bob));
+    assert_eq!(parse_metadata_creators(&value), vec![alice, bob]);
+
+    // Malformed entries are skipped
+    assert_eq!(
+        parse_metadata_creators(&format!("invalid:10,{}", bob)),
+        vec![bob]
+    );
+}
+
+#[test]
+fn test_token_extensions_params() {
+    let mut extensions = structs::token_extensions::Params::default();
+    assert!(extensions.validate().is_ok());
+
+    extensions.transfer_fee_bps = MAX_TRANSFER_FEE_BPS;
+    extensions.maximum_transfer_fee = 1_000;
+    assert!(extensions.validate().is_ok());
+
+    // The royalty can't exceed its maximum
+    extensions.transfer_fee_bps = MAX_TRANSFER_FEE_BPS + 1;
+    assert!(extensions.validate().is_err());
+}
+
+#[test]
+fn test_metadata_update_params() {
+    // An update must change at least one field
+    let mut update = structs::metadata_update::Params::default();
+    assert!(update.validate().is_err());
+
+    update.uri = Some("https://example.com/token.json".to_string());
+    assert!(update.validate().is_ok());
+
+    // Fields must fit in Metaplex metadata
+    update.symbol = Some("S".repeat(MAX_METADATA_SYMBOL_LEN + 1));
+    assert!(update.validate().is_err());
+
+    update.symbol = Some(String::new());
+    assert!(update.validate().is_err());
+
+    update.symbol = None;
+    update.collection = Some(Pubkey::new_unique());
+    assert!(update.validate().is_ok());
+}
+
+#[test]
+fn test_is_governance() {
+    let (governance, _) = Pubkey::find_program_address(&[GOVERNANCE_TAG], &crate::ID);
+    assert!(is_governance(&governance));
+
+    // Neither a random address nor a PDA of another program is the governance PDA
+    assert!(!is_governance(&Pubkey::new_unique()));
+    let (other, _) = Pubkey::find_program_address(&[GOVERNANCE_TAG], &Pubkey::new_unique());
+    assert!(!is_governance(&other));
+}
+
+#[test]
+fn test_voter_record_weight_at() {
+    let mut voter_record = VoterRecord {
+        deposited_amount: 0,
+        deposited_at: 0,
+        locked_until: 0,
+        previous_amount: 0,
+        previous_at: 0,
+    };
+    voter_record.deposit(100, 1_000);
+
+    // A deposit made exactly at the snapshot doesn't count
+    assert_eq!(voter_record.weight_at(1_000), 0);
+    assert_eq!(voter_record.weight_at(1_001), 100);
+
+    // A top up only excludes the amount it adds
+    voter_record.deposit(50, 2_000);
+    assert_eq!(voter_record.weight_at(1_500), 100);
+    assert_eq!(voter_record.weight_at(2_001), 150);
+
+    // Topping up again after the snapshot can only lower its weight
+    voter_record.deposit(25, 3_000);
+    assert_eq!(voter_record.weight_at(1_500), 0);
+    assert_eq!(voter_record.weight_at(2_500), 150);
+
+    // A withdrawal lowers every snapshot
+    voter_record.withdraw(160);
+    assert_eq!(voter_record.weight_at(2_500), 15);
+    assert_eq!(voter_record.weight_at(3_001), 15);
+}
+
+#[test]
+fn test_community_proposal_quorum_uses_snapshot_supply() {
+    let governance = DaoGovernance {
+        quorum_percent: 10000000, // 10%
+        approval_percent: 50000000,
+        proposal_threshold_percent: 0,
+        voting_period: 0,
+        proposals: vec![],
+    };
+    let mut proposal = CommunityProposal {
+        id: 1,
+        created_at: 0,
+        created_by: Pubkey::new_unique(),
+        proposal_type: ProposalType::RemoveLiquidity,
+        address: None,
+        transfer_amount: None,
+        voting_ends_at: 0,
+        yes_votes: 100,
+        no_votes: 0,
+        status: ProposalStatus::Pending,
+        executable_proposal_id: None,
+        supply: 1_000,
+    };
+    assert!(proposal.has_passed(&governance));
+
+    // Quorum is taken against the supply at creation
+    proposal.supply = 2_000;
+    assert!(!proposal.has_passed(&governance));
+}
+
+#[test]
+fn test_pause_config_new_entries() {
+    let mint = Pubkey::new_unique();
+    let mut pause_config = PauseConfig {
+        paused: false,
+        paused_mints: vec![],
+        guardians: vec![],
+    };
+
+    // Only pausing a mint not paused yet needs a new entry
+    assert_eq!(pause_config.new_entries(None), 0);
+    assert_eq!(pause_config.new_entries(Some(&mint)), 1);
+
+    pause_config.pause(Some(mint));
+    assert_eq!(pause_config.new_entries(Some(&mint)), 0);
+    assert_eq!(pause_config.new_entries(Some(&Pubkey::new_unique())), 1);
+}
+
+#[test]
+fn test_removed_admin_loses_permissions() {
+    let admin = Pubkey::new_unique();
+    let (key, _) = Pubkey::find_program_address(&[ROLE_TAG, admin.as_ref()], &crate::ID);
+    let owner = crate::ID;
+    let mut lamports = 1_000_000;
+    let mut data = Vec::new();
+    RoleMembership {
+        address: admin,
+        permissions: permissions::ALL,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+    let role_membership = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Only the membership of the removed address is accepted
+    assert!(revoke_role_membership(&role_membership, &Pubkey::new_unique()).is_err());
+
+    revoke_role_membership(&role_membership, &admin).unwrap();
+    let membership = Account::<RoleMembership>::try_from(&role_membership).unwrap();
+    let global_config = global_config_with_grants(vec![]);
+    for permission in [
+        permissions::RESET,
+        permissions::TREASURY,
+        permissions::TRADE,
+    ] {
+        assert!(require_permission(
+            Some(&membership),
+            &global_config,
+            &admin,
+            None,
+            permission,
+            0
+        )
+        .is_err());
+    }
+
+    // Addresses that never migrated their role have nothing to revoke
+    let other = Pubkey::new_unique();
+    let (key, _) = Pubkey::find_program_address(&[ROLE_TAG, other.as_ref()], &crate::ID);
+    let system = system_program::ID;
+    let mut lamports = 0;
+    let mut data = Vec::new();
+    let missing = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &system,
+        false,
+        0,
+    );
+    revoke_role_membership(&missing, &other).unwrap();
+}
+
+fn global_config_with_grants(sub_admin_grants: Vec<RoleGrant>) -> GlobalConfig {
+    GlobalConfig {
+        deployers: vec![],
+        sub_admins: vec![],
+        admins: vec![],
+        owner: Pubkey::new_unique(),
+        sub_admin_grants,
+        pending_owner: None,
+        tokenomics_bounds: None,
+    }
+}
+
+#[test]
+fn test_require_permission_honours_grants() {
+    let scoped = Pubkey::new_unique();
+    let expiring = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let other_mint = Pubkey::new_unique();
+    let global_config = global_config_with_grants(vec![
+        RoleGrant {
+            address: scoped,
+            mint: Some(mint),
+            expires_at: None,
+        },
+        RoleGrant {
+            address: expiring,
+            mint: None,
+            expires_at: Some(100),
+        },
+    ]);
+
+    // A scoped grant carries the sub-admin permissions for its DAO only
+    assert!(require_permission(
+        None,
+        &global_config,
+        &scoped,
+        Some(&mint),
+        permissions::START_DAO,
+        0,
+    )
+    .is_ok());
+    assert!(require_permission(
+        None,
+        &global_config,
+        &scoped,
+        Some(&other_mint),
+        permissions::START_DAO,
+        0,
+    )
+    .is_err());
+    assert!(require_permission(
+        None,
+        &global_config,
+        &scoped,
+        None,
+        permissions::MULTISIG,
+        0
+    )
+    .is_err());
+    assert!(require_permission(
+        None,
+        &global_config,
+        &scoped,
+        Some(&mint),
+        permissions::RESET,
+        0,
+    )
+    .is_err());
+
+    // A time-bounded grant applies to every DAO until it expires
+    assert_eq!(
+        global_config.grant_permissions(&expiring, Some(&other_mint), 99),
+        permissions::SUB_ADMIN
+    );
+    assert_eq!(global_config.grant_permissions(&expiring, None, 100), 0);
+    assert!(!global_config.is_sub_admin(&expiring, None, 100));
+    assert!(global_config.is_sub_admin(&expiring, None, 99));
+    assert!(require_permission(
+        None,
+        &global_config,
+        &expiring,
+        None,
+        permissions::START_DAO,
+        100
+    )
+    .is_err());
+
+    // Grants add to the permissions of a role membership
+    let membership = RoleMembership {
+        address: scoped,
+        permissions: permissions::RESET,
+    };
+    assert!(require_permission(
+        Some(&membership),
+        &global_config,
+        &scoped,
+        Some(&mint),
+        permissions::RESET | permissions::END_DAO,
+        0,
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_legacy_blacklist_fallback() {
+    let permanent = Pubkey::new_unique();
+    let unblocked = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+
+    // Unblocking left the last user of the longer list behind
+    let mut data = Vec::new();
+    Blacklist {
+        users: vec![permanent, unblocked],
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+    let mut shrunk = Vec::new();
+    Blacklist {
+        users: vec![permanent],
+    }
+    .try_serialize(&mut shrunk)
+    .unwrap();
+    data[..shrunk.len()].copy_from_slice(&shrunk);
+
+    let key = Pubkey::new_unique();
+    let owner = crate::ID;
+    let mut lamports = 1_000_000;
+    let blacklist = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let marker_key = Pubkey::new_unique();
+    let system = system_program::ID;
+    let mut marker_lamports = 0;
+    let mut marker_data = Vec::new();
+    let marker = AccountInfo::new(
+        &marker_key,
+        false,
+        false,
+        &mut marker_lamports,
+        &mut marker_data,
+        &system,
+        false,
+        0,
+    );
+
+    // Users not migrated yet still block without a marker, the stale bytes ignored
+    assert!(is_blocked_by(&permanent, &marker, &blacklist, 99).unwrap());
+    assert!(!is_blocked_by(&unblocked, &marker, &blacklist, 99).unwrap());
+    assert!(!is_blocked_by(&other, &marker, &blacklist, 99).unwrap());
+
+    // Migration takes the users as permanent entries
+    let mut legacy = Account::<Blacklist>::try_from(&blacklist).unwrap();
+    assert_eq!(
+        legacy.take_entries(2),
+        vec![BlacklistEntry {
+            address: permanent,
+            blocked_until: None,
+            reason: 0,
+        }]
+    );
+    assert!(legacy.users.is_empty());
+
+    // A DAO without a legacy list only relies on markers
+    assert!(!is_blocked_by(&permanent, &marker, &marker, 99).unwrap());
+}
+
+#[test]
+fn test_require_allowed_by_blacklist_policies() {
+    let user = Pubkey::new_unique();
+    let owner = crate::ID;
+    let system = system_program::ID;
+
+    let marker_key = find_blacklist_entry_address(&user, None).0;
+    let mut marker_lamports = 1_000_000;
+    let mut marker_data = Vec::new();
+    BlacklistEntry {
+        address: user,
+        blocked_until: None,
+        reason: 0,
+    }
+    .try_serialize(&mut marker_data)
+    .unwrap();
+    let marker = AccountInfo::new(
+        &marker_key,
+        false,
+        false,
+        &mut marker_lamports,
+        &mut marker_data,
+        &owner,
+        false,
+        0,
+    );
+
+    let empty_key = Pubkey::new_unique();
+    let mut empty_lamports = 0;
+    let mut empty_data = Vec::new();
+    let empty = AccountInfo::new(
+        &empty_key,
+        false,
+        false,
+        &mut empty_lamports,
+        &mut empty_data,
+        &system,
+        false,
+        0,
+    );
+
+    let policy_key = Pubkey::new_unique();
+    let mut policy_lamports = 1_000_000;
+    let mut policy_data = Vec::new();
+    BlacklistPolicyConfig {
+        policy: BlacklistPolicy::BlockAll,
+    }
+    .try_serialize(&mut policy_data)
+    .unwrap();
+    let policy = AccountInfo::new(
+        &policy_key,
+        false,
+        false,
+        &mut policy_lamports,
+        &mut policy_data,
+        &owner,
+        false,
+        0,
+    );
+
+    let check = |blacklist_entry: &AccountInfo, blacklist_policy: &AccountInfo, action| {
+        require_allowed_by_blacklist(
+            &user,
+            blacklist_entry,
+            &empty,
+            [&empty, &empty],
+            blacklist_policy,
+            action,
+            0,
+        )
+    };
+
+    // Users without a marker are never restricted
+    for action in BLACKLIST_ACTIONS {
+        assert!(check(&empty, &policy, action).is_ok());
+    }
+
+    // Without a policy account, blocked users can still claim their refunds
+    assert!(check(&marker, &empty, BlacklistAction::ClaimRefund).is_ok());
+    for action in [
+        BlacklistAction::Commit,
+        BlacklistAction::Buy,
+        BlacklistAction::Sell,
+        BlacklistAction::ClaimTokens,
+    ] {
+        assert!(check(&marker, &empty, action).is_err());
+    }
+
+    // A DAO can still opt into blocking every action
+    for action in BLACKLIST_ACTIONS {
+        assert!(check(&marker, &policy, action).is_err());
+    }
+}
+
+#[test]
+fn test_merkle_allowlist_rounds() {
+    let address = Pubkey::new_unique();
+    let vip_leaf = calc_merkle_leaf(&address, 5_000_000_000, UserType::Vip);
+    let party_leaf = calc_merkle_leaf(&address, 1_000_000_000, UserType::Party);
+    let mut allowlist = MerkleAllowlist {
+        vip_root: Some(vip_leaf),
+        party_root: Some(party_leaf),
+        tier_roots: [None; MAX_SALE_TIERS],
+    };
+    let allocation = |max_allowable_amount, tier| structs::merkle_allocation::Params {
+        max_allowable_amount,
+        tier,
+        proof: vec![],
+    };
+    let vip = allocation(5_000_000_000, UserType::Vip);
+    let party = allocation(1_000_000_000, UserType::Party);
+
+    // Only the leaf of the round currently open is accepted
+    assert_eq!(
+        allowlist
+            .verify(&address, &vip, Status::FundraisingVip, None)
+            .unwrap(),
+        5_000_000_000
+    );
+    assert!(allowlist
+        .verify(&address, &party, Status::FundraisingVip, None)
+        .is_err());
+    for status in [Status::FundraisingParty, Status::FundraisingPublic] {
+        assert!(allowlist.verify(&address, &vip, status, None).is_err());
+        assert_eq!(
+            allowlist.verify(&address, &party, status, None).unwrap(),
+            1_000_000_000
+        );
+    }
+    assert!(allowlist
+        .verify(&address, &vip, Status::Created, None)
+        .is_err());
+
+    // Roots are locked once their round starts
+    assert!(!MerkleAllowlist::has_started(
+        UserType::Vip,
+        Status::Created,
+        None,
+        0
+    ));
+    assert!(MerkleAllowlist::has_started(
+        UserType::Vip,
+        Status::FundraisingVip,
+        None,
+        0
+    ));
+    assert!(!MerkleAllowlist::has_started(
+        UserType::Party,
+        Status::FundraisingVip,
+        None,
+        0
+    ));
+    assert!(MerkleAllowlist::has_started(
+        UserType::Party,
+        Status::FundraisingParty,
+        None,
+        0
+    ));
+    assert!(MerkleAllowlist::has_started(
+        UserType::Party,
+        Status::FundraisingPublic,
+        None,
+        0
+    ));
+
+    // Each sale tier is a round of its own, accepting only its own leaves while open
+    let tier_leaf = calc_merkle_leaf(&address, 2_000_000_000, UserType::Tier(1));
+    allowlist
+        .set_root(UserType::Tier(1), Some(tier_leaf))
+        .unwrap();
+    assert!(allowlist
+        .set_root(UserType::Tier(MAX_SALE_TIERS as u8), None)
+        .is_err());
+    let tier = allocation(2_000_000_000, UserType::Tier(1));
+    assert_eq!(
+        allowlist
+            .verify(&address, &tier, Status::Fundraising, Some(1))
+            .unwrap(),
+        2_000_000_000
+    );
+    assert!(allowlist
+        .verify(&address, &tier, Status::Fundraising, Some(0))
+        .is_err());
+    assert!(allowlist
+        .verify(&address, &party, Status::Fundraising, Some(1))
+        .is_err());
+    assert!(allowlist.get_root(UserType::Tier(0)).is_none());
+
+    // Tier leaves can't be passed off as the legacy rounds' leaves
+    assert_ne!(
+        tier_leaf,
+        calc_merkle_leaf(&address, 2_000_000_000, UserType::Party)
+    );
+
+    // The root of a tier is locked once the tier opens
+    let sale_tiers = SaleTiers {
+        tiers: vec![
+            SaleTier {
+                start_at: 100,
+                end_at: 200,
+                tokens_per_sol: 10,
+                per_user_cap: 1,
+                tier_cap: 1,
+                committed: 0,
+            },
+            SaleTier {
+                start_at: 200,
+                end_at: 300,
+                tokens_per_sol: 5,
+                per_user_cap: 1,
+                tier_cap: 1,
+                committed: 0,
+            },
+        ],
+    };
+    let has_started = |status, now| {
+        MerkleAllowlist::has_started(UserType::Tier(1), status, Some(&sale_tiers), now)
+    };
+    assert!(!has_started(Status::Created, 250));
+    assert!(!has_started(Status::Fundraising, 150));
+    assert!(has_started(Status::Fundraising, 200));
+    assert!(has_started(Status::FundraisingFail, 150));
+
+    // The tiered sale collects commitments like the fixed rounds
+    assert!(Status::Fundraising.is_fundraising());
+    assert!(Status::Fundraising.is_open());
+    assert_eq!(
+        MerkleAllowlist::current_round(Status::Fundraising, None),
+        None
+    );
+}
+
+#[test]
+fn test_dao_fee_percent_and_creator_sizes() {
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let key = Pubkey::new_unique();
+    let owner = crate::ID;
+    let mut lamports = 1_000_000;
+    let mut data = Vec::new();
+    CreatorDaoInfo {
+        creator,
+        mint,
+        token: "DAO".to_string(),
+        fee_percent: 250,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+    let record = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // The creator's record of the DAO holds the fee applied
+    assert_eq!(load_dao_fee_percent(&record, 100).unwrap(), 250);
+
+    let missing_key = Pubkey::new_unique();
+    let system = system_program::ID;
+    let mut missing_lamports = 0;
+    let mut missing_data = Vec::new();
+    let missing = AccountInfo::new(
+        &missing_key,
+        false,
+        false,
+        &mut missing_lamports,
+        &mut missing_data,
+        &system,
+        false,
+        0,
+    );
+
+    // DAOs launched before records were kept per DAO use the fund store's fee
+    assert_eq!(load_dao_fee_percent(&missing, 100).unwrap(), 100);
+
+    // Both creator records are sized with their discriminator
+    let mut info_data = Vec::new();
+    CreatorInfo {
+        token: "A".repeat(32),
+        fee_percent: 1,
+        daos: vec![mint; 3],
+    }
+    .try_serialize(&mut info_data)
+    .unwrap();
+    assert!(info_data.len() <= calc_creator_info_size(3));
+
+    let mut dao_info_data = Vec::new();
+    CreatorDaoInfo {
+        creator,
+        mint,
+        token: "A".repeat(32),
+        fee_percent: 1,
+    }
+    .try_serialize(&mut dao_info_data)
+    .unwrap();
+    assert!(dao_info_data.len() <= calc_creator_dao_info_size());
+}
+
+fn fund_store_with_milestones(release_bps: &[u16], status: Status) -> FundDataStore {
+    FundDataStore {
+        created_at: 0,
+        created_by: Pubkey::new_unique(),
+        start_date: 0,
+        end_date: 0,
+        fundraising_goal: 1_000_000_000,
+        status,
+        tokens_per_sol: 1_000,
+        fee_percent: 0,
+        vesting_percent: VestingPercent {
+            first_claim: 0,
+            daily_claim: 0,
+        },
+        creators: CreatorShare {
+            address: Pubkey::default(),
+            total_withdrawable: 1_000,
+            creators: Vec::new(),
+        },
+        deployers: DeployerShare {
+            deployers: Vec::new(),
+            total_withdrawable: 0,
+        },
+        co_creators: Vec::new(),
+        tokenomics: None,
+        milestones: release_bps
+            .iter()
+            .map(|release_bps| Milestone {
+                description_hash: [0; 32],
+                release_bps: *release_bps,
+                status: MilestoneStatus::Pending,
+            })
+            .collect(),
+        refund_pool: None,
+    }
+}
+
+#[test]
+fn test_milestone_order_and_release() {
+    let mut fund_store =
+        fund_store_with_milestones(&[3_333, 3_333, 3_334], Status::FundraisingSuccess);
+
+    // Milestones are delivered in order
+    assert!(fund_store.check_milestone(0).is_ok());
+    assert!(fund_store.check_milestone(1).is_err());
+    assert!(fund_store.check_milestone(3).is_err());
+
+    // Each milestone releases its share, the last one whatever remains
+    assert_eq!(fund_store.milestone_release_amount(0), 333);
+    fund_store.milestones[0].status = MilestoneStatus::Released;
+    fund_store.creators.creators.push(CreatorData {
+        amount: 333,
+        claimed_at: 0,
+    });
+    assert!(fund_store.check_milestone(0).is_err());
+    assert!(fund_store.check_milestone(1).is_ok());
+    assert!(fund_store.check_milestone(2).is_err());
+
+    assert_eq!(fund_store.milestone_release_amount(1), 333);
+    fund_store.milestones[1].status = MilestoneStatus::Released;
+    fund_store.creators.creators.push(CreatorData {
+        amount: 333,
+        claimed_at: 0,
+    });
+    assert!(fund_store.check_milestone(2).is_ok());
+    assert_eq!(fund_store.milestone_release_amount(2), 334);
+    assert_eq!(fund_store.unreleased_creator_amount(), 334);
+
+    // Nothing is left for milestone refunds once every milestone was released
+    fund_store.milestones[2].status = MilestoneStatus::Released;
+    fund_store.creators.creators.push(CreatorData {
+        amount: 334,
+        claimed_at: 0,
+    });
+    assert_eq!(fund_store.next_milestone(), None);
+    assert_eq!(fund_store.unreleased_creator_amount(), 0);
+
+    // Milestones are only delivered once the DAO raised its goal
+    let failed = fund_store_with_milestones(&[10_000], Status::FundraisingFail);
+    assert!(failed.check_milestone(0).is_err());
+    let trading = fund_store_with_milestones(&[10_000], Status::Trade);
+    assert!(trading.check_milestone(0).is_ok());
+    assert_eq!(trading.milestone_release_amount(0), 1_000);
+}
+
+#[test]
+fn test_fund_data_store_borsh_sizes() {
+    let mut fund_store = fund_store_with_milestones(&[5_000, 5_000], Status::FundraisingSuccess);
+
+    // Sizes follow the serialized account, with room for the entry added and the refund pool
+    let size = fund_store.realloc_for_creator();
+    fund_store.creators.creators.push(CreatorData {
+        amount: 1,
+        claimed_at: 0,
+    });
+    fund_store.refund_pool = Some(RefundPool {
+        sol_amount: 1,
+        total_commited_sols: 1,
+    });
+    let mut data = Vec::new();
+    fund_store.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), size);
+
+    let size = fund_store.realloc_for_deployer();
+    fund_store.deployers.deployers.push(DeployerData {
+        address: Pubkey::new_unique(),
+        amount: 1,
+        claimed_at: 0,
+    });
+    let mut data = Vec::new();
+    fund_store.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), size);
+}
+
+/// Builds a Token-2022 mint, with a transfer fee of `basis_points` capped at `maximum_fee` if any.
+fn token_2022_mint_data(transfer_fee: Option<(u16, u64)>) -> Vec<u8> {
+    use spl_token_2022::extension::{BaseStateWithExtensionsMut, StateWithExtensionsMut};
+
+    let extensions = match transfer_fee {
+        Some(_) => vec![ExtensionType::TransferFeeConfig],
+        None => vec![],
+    };
+    let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)
+        .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state =
+        StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+            .unwrap();
+    if let Some((basis_points, maximum_fee)) = transfer_fee {
+        let fee = spl_token_2022::extension::transfer_fee::TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        };
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = fee;
+        config.newer_transfer_fee = fee;
+    }
+    state.base.decimals = 6;
+    state.base.is_initialized = true;
+    state.pack_base();
+    state.init_account_type().unwrap();
+    data
+}
+
+#[test]
+fn test_calc_transfer_fee() {
+    use anchor_lang::solana_program::program_pack::Pack;
+
+    let key = Pubkey::new_unique();
+    let owner = spl_token_2022::ID;
+
+    // 5% capped at 1_000 tokens, rounded up
+    let mut lamports = 0;
+    let mut data = token_2022_mint_data(Some((500, 1_000)));
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(calc_epoch_transfer_fee(&mint, 1, 10_000).unwrap(), 500);
+    assert_eq!(calc_epoch_transfer_fee(&mint, 1, 101).unwrap(), 6);
+    assert_eq!(calc_epoch_transfer_fee(&mint, 1, 100_000).unwrap(), 1_000);
+    assert_eq!(calc_epoch_transfer_fee(&mint, 1, 0).unwrap(), 0);
+
+    // No fee without the extension
+    let mut lamports = 0;
+    let mut data = token_2022_mint_data(None);
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(calc_epoch_transfer_fee(&mint, 1, 10_000).unwrap(), 0);
+
+    // Nor on legacy mints
+    let mut lamports = 0;
+    let mut data = vec![0u8; spl_token_2022::state::Mint::LEN];
+    let base = spl_token_2022::state::Mint {
+        is_initialized: true,
+        ..Default::default()
+    };
+    spl_token_2022::state::Mint::pack(base, &mut data).unwrap();
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(calc_epoch_transfer_fee(&mint, 1, 10_000).unwrap(), 0);
+}
+
+#[test]
+fn test_claim_amount_net_of_transfer_fee() {
+    let alice = Pubkey::new_unique();
+    let mut commitments = Commitments {
+        commiters: vec![],
+        total_commited_sols: 0,
+    };
+    commitments.add("DAO".to_string(), alice, 10, 1_000);
+    let vesting_percent = VestingPercent {
+        first_claim: 50_000_000,
+        daily_claim: 50_000_000,
+    };
+    // 1% transfer fee, rounded up
+    let transfer_fee = |amount: u64| Ok((amount * 100 + 9_999) / 10_000);
+
+    // Half vests first, the fee withheld not counted as claimed
+    let amount = commitments
+        .claim_amount(&alice, AmountType::Token, &vesting_percent, 0, transfer_fee)
+        .unwrap();
+    assert_eq!(amount, 5_000);
+    assert_eq!(commitments.commiters[0].amount_claimed, 4_950);
+
+    // The last claim stops at the tokens entitled net of the fee
+    let amount = commitments
+        .claim_amount(
+            &alice,
+            AmountType::Token,
+            &vesting_percent,
+            86_400 * 2,
+            transfer_fee,
+        )
+        .unwrap();
+    assert_eq!(amount, 5_000);
+    assert_eq!(commitments.commiters[0].amount_claimed, 9_900);
+
+    let amount = commitments
+        .claim_amount(
+            &alice,
+            AmountType::Token,
+            &vesting_percent,
+            86_400 * 3,
+            transfer_fee,
+        )
+        .unwrap();
+    assert_eq!(amount, 0);
+    assert_eq!(commitments.commiters[0].amount_claimed, 9_900);
+}
+
+/// Builds a Token-2022 mint storing its native metadata, updatable by `update_authority` if any.
+fn native_metadata_mint_data(mint: Pubkey, update_authority: Option<Pubkey>) -> Vec<u8> {
+    use spl_token_2022::extension::{
+        metadata_pointer::MetadataPointer, BaseStateWithExtensionsMut, StateWithExtensionsMut,
+    };
+
+    let token_metadata = TokenMetadata {
+        update_authority: update_authority.try_into().unwrap(),
+        mint,
+        name: "DAO".to_string(),
+        symbol: "DAO".to_string(),
+        uri: "https://dao.xyz/0.json".to_string(),
+        additional_metadata: vec![],
+    };
+    let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::MetadataPointer,
+    ])
+    .unwrap()
+        + token_metadata.tlv_size_of().unwrap();
+    let mut data = vec![0u8; len];
+    let mut state =
+        StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+            .unwrap();
+    state
+        .init_extension::<MetadataPointer>(true)
+        .unwrap()
+        .metadata_address = Some(mint).try_into().unwrap();
+    state.base.is_initialized = true;
+    state.pack_base();
+    state.init_account_type().unwrap();
+    state
+        .init_variable_len_extension(&token_metadata, false)
+        .unwrap();
+    data
+}
+
+#[test]
+fn test_native_metadata_rent_top_up() {
+    let key = Pubkey::new_unique();
+    let owner = spl_token_2022::ID;
+    let rent = Rent::default();
+    let mut data = native_metadata_mint_data(key, Some(key));
+    let len = data.len();
+    let mut lamports = rent.minimum_balance(len);
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    // Nothing to fund while the metadata does not grow
+    let shorter = [(Field::Uri, "https://dao.xyz/1".to_string())];
+    assert_eq!(calc_native_metadata_len(&mint, &shorter).unwrap(), len);
+    let same = [(Field::Name, "ABC".to_string())];
+    assert_eq!(calc_native_metadata_len(&mint, &same).unwrap(), len);
+
+    // Longer values and new keys grow the mint by their extra bytes
+    let longer = [(Field::Uri, "https://dao.xyz/0000.json".to_string())];
+    assert_eq!(calc_native_metadata_len(&mint, &longer).unwrap(), len + 3);
+
+    let collection = Pubkey::new_unique().to_string();
+    let fields = [
+        (Field::Uri, "https://dao.xyz/0000.json".to_string()),
+        (
+            Field::Key(COLLECTION_METADATA_KEY.to_string()),
+            collection.clone(),
+        ),
+    ];
+    let grown = len + 3 + 4 + COLLECTION_METADATA_KEY.len() + 4 + collection.len();
+    assert_eq!(calc_native_metadata_len(&mint, &fields).unwrap(), grown);
+
+    // The executor only tops up the rent the grown mint lacks
+    let top_up = rent
+        .minimum_balance(calc_native_metadata_len(&mint, &fields).unwrap())
+        .saturating_sub(mint.lamports());
+    assert_eq!(
+        top_up,
+        rent.minimum_balance(grown) - rent.minimum_balance(len)
+    );
+}
+
+#[test]
+fn test_metadata_lock_state() {
+    use anchor_spl::metadata::mpl_token_metadata::{
+        accounts::Metadata as MetaplexMetadata, types::Key,
+    };
+
+    let mint = Pubkey::new_unique();
+
+    // Native metadata is mutable until its update authority is removed
+    let mut lamports = 0;
+    let mut data = native_metadata_mint_data(mint, Some(mint));
+    let owner = spl_token_2022::ID;
+    let account = AccountInfo::new(
+        &mint,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert!(is_metadata_mutable(&account, &mint).unwrap());
+
+    let mut lamports = 0;
+    let mut data = native_metadata_mint_data(mint, None);
+    let account = AccountInfo::new(
+        &mint,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert!(!is_metadata_mutable(&account, &mint).unwrap());
+
+    // A mint not owned by Token-2022 is rejected
+    let mut lamports = 0;
+    let mut data = native_metadata_mint_data(mint, Some(mint));
+    let wrong_owner = Pubkey::new_unique();
+    let account = AccountInfo::new(
+        &mint,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &wrong_owner,
+        false,
+        0,
+    );
+    assert!(is_metadata_mutable(&account, &mint).is_err());
+
+    // Metaplex metadata is mutable until marked immutable
+    let metaplex_metadata = |mint: Pubkey, is_mutable: bool| {
+        MetaplexMetadata {
+            key: Key::MetadataV1,
+            update_authority: mint,
+            mint,
+            name: "DAO".to_string(),
+            symbol: "DAO".to_string(),
+            uri: "https://dao.xyz/0.json".to_string(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable,
+            edition_nonce: None,
+            token_standard: None,
+            collection: None,
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        }
+        .try_to_vec()
+        .unwrap()
+    };
+    let metadata_key = Pubkey::new_unique();
+    let metaplex = anchor_spl::metadata::ID;
+
+    let mut lamports = 0;
+    let mut data = metaplex_metadata(mint, true);
+    let account = AccountInfo::new(
+        &metadata_key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &metaplex,
+        false,
+        0,
+    );
+    assert!(is_metadata_mutable(&account, &mint).unwrap());
+
+    let mut lamports = 0;
+    let mut data = metaplex_metadata(mint, false);
+    let account = AccountInfo::new(
+        &metadata_key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &metaplex,
+        false,
+        0,
+    );
+    assert!(!is_metadata_mutable(&account, &mint).unwrap());
+
+    // Metadata of another mint is rejected
+    let mut lamports = 0;
+    let mut data = metaplex_metadata(Pubkey::new_unique(), true);
+    let account = AccountInfo::new(
+        &metadata_key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &metaplex,
+        false,
+        0,
+    );
+    assert!(is_metadata_mutable(&account, &mint).is_err());
+}
+
+#[test]
+fn test_global_config_from_legacy() {
+    let deployer = Pubkey::new_unique();
+    let sub_admin = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let legacy = |admins: Vec<Pubkey>, owner: Pubkey| {
+        let mut data = GlobalConfig::DISCRIMINATOR.to_vec();
+        data.extend(
+            (vec![deployer], vec![sub_admin], admins, owner)
+                .try_to_vec()
+                .unwrap(),
+        );
+        data
+    };
+
+    // An admin removed without shrinking the account leaves the old owner behind
+    let mut data = legacy(
+        vec![admin, Pubkey::new_unique()],
+        Pubkey::new_from_array([0xff; 32]),
+    );
+    data.extend([0u8; 64]);
+    let removed = legacy(vec![admin], owner);
+    data[..removed.len()].copy_from_slice(&removed);
+    assert!(GlobalConfig::try_deserialize(&mut &data[..]).is_err());
+
+    let global_config = GlobalConfig::from_legacy(&data).unwrap();
+    assert_eq!(global_config.deployers, vec![deployer]);
+    assert_eq!(global_config.sub_admins, vec![sub_admin]);
+    assert_eq!(global_config.admins, vec![admin]);
+    assert_eq!(global_config.owner, owner);
+    assert!(global_config.sub_admin_grants.is_empty());
+    assert_eq!(global_config.pending_owner, None);
+    assert!(global_config.tokenomics_bounds.is_none());
+
+    // Rewritten over zeroed data, the configuration loads again
+    data.fill(0);
+    global_config.try_serialize(&mut &mut data[..]).unwrap();
+    let migrated = GlobalConfig::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!(migrated.admins, vec![admin]);
+    assert_eq!(migrated.owner, owner);
+
+    // Other accounts are rejected
+    assert!(GlobalConfig::from_legacy(&data[8..]).is_err());
+}
+
+#[test]
+fn test_creator_info_from_legacy() {
+    let legacy = |token: &str| {
+        let mut data = CreatorInfo::DISCRIMINATOR.to_vec();
+        data.extend((token.to_string(), 250u32).try_to_vec().unwrap());
+        data
+    };
+
+    // A shorter name of the latest DAO left the end of the longer one behind
+    let mut data = vec![0u8; CreatorInfo::LEGACY_SIZE];
+    let first = legacy("A DAO WITH A LONGER NAME");
+    data[..first.len()].copy_from_slice(&first);
+    let second = legacy("DAO");
+    data[..second.len()].copy_from_slice(&second);
+    assert!(CreatorInfo::try_deserialize(&mut &data[..]).is_err());
+
+    let creator_info = CreatorInfo::from_legacy(&data).unwrap();
+    assert_eq!(creator_info.token, "DAO");
+    assert_eq!(creator_info.fee_percent, 250);
+    assert!(creator_info.daos.is_empty());
+
+    // Rewritten at its new size, the details load again and index new DAOs
+    let mut data = vec![0u8; calc_creator_info_size(0)];
+    assert!(CreatorInfo::LEGACY_SIZE < data.len());
+    creator_info.try_serialize(&mut &mut data[..]).unwrap();
+    let mut migrated = CreatorInfo::try_deserialize(&mut &data[..]).unwrap();
+    migrated.add_dao("NEXT".to_string(), Pubkey::new_unique());
+    let mut data = vec![0u8; calc_creator_info_size(1)];
+    migrated.try_serialize(&mut &mut data[..]).unwrap();
+}
+
+#[test]
+fn test_public_round_cap_and_lock() {
+    let public_round = PublicRound {
+        per_wallet_cap: 2_000_000_000,
+        single_commitment: true,
+    };
+
+    // Wallets are capped on their total commitment, the cap itself included
+    assert!(public_round.is_within_cap(0));
+    assert!(public_round.is_within_cap(2_000_000_000));
+    assert!(!public_round.is_within_cap(2_000_000_001));
+
+    // The configuration can only change before the public round starts
+    for status in [
+        Status::Created,
+        Status::FundraisingVip,
+        Status::FundraisingParty,
+    ] {
+        assert!(!PublicRound::has_started(status));
+    }
+    for status in [
+        Status::FundraisingPublic,
+        Status::Fundraising,
+        Status::FundraisingSuccess,
+        Status::FundraisingFail,
+    ] {
+        assert!(PublicRound::has_started(status));
+    }
+}
+
+#[test]
+fn test_require_single_commitment() {
+    use anchor_lang::solana_program::sysvar::instructions::{
+        construct_instructions_data, store_current_index, BorrowedInstruction,
+    };
+
+    fn check(instructions: &[(Pubkey, Vec<u8>)], current: u16) -> Result<()> {
+        let borrowed: Vec<BorrowedInstruction> = instructions
+            .iter()
+            .map(|(program_id, data)| BorrowedInstruction {
+                program_id,
+                accounts: vec![],
+                data,
+            })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed);
+        store_current_index(&mut data, current);
+
+        let owner = anchor_lang::solana_program::sysvar::ID;
+        let mut lamports = 0;
+        let sysvar = AccountInfo::new(
+            &tx_instructions::ID,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        require_single_commitment(&sysvar)
+    }
+
+    let commitment = (
+        crate::ID,
+        crate::instruction::Commitment::DISCRIMINATOR.to_vec(),
+    );
+    let other = (Pubkey::new_unique(), vec![1, 2, 3]);
+    let claim = (crate::ID, crate::instruction::Claim::DISCRIMINATOR.to_vec());
+
+    // A lone top-level commitment passes, alongside instructions of other kinds
+    assert!(check(std::slice::from_ref(&commitment), 0).is_ok());
+    assert!(check(&[other.clone(), commitment.clone(), claim.clone()], 1).is_ok());
+
+    // A second commitment in the transaction is rejected, wherever it sits
+    assert!(check(&[commitment.clone(), commitment.clone()], 0).is_err());
+    assert!(check(&[commitment.clone(), commitment.clone()], 1).is_err());
+
+    // A commitment made through another program shows that program at the top level
+    assert!(check(&[other, commitment], 0).is_err());
+}
+
+#[test]
+fn test_list_proposals() {
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let proposal = |id, proposal_type, dao_name: Option<&str>, created_by, status| ProposalData {
+        id,
+        created_at: 0,
+        created_by,
+        proposal_type,
+        approver_threshold: 1,
+        address: None,
+        dao_name: dao_name.map(str::to_string),
+        transfer_amount: None,
+        executor_type: ExecutorType::Admin,
+        approve_type: ApproverType::Admin,
+        status,
+        executed_at: None,
+        approvers: vec![],
+    };
+    let approved = ProposalStatus::Approved { timestamp: 1 };
+    let mut executed = proposal(
+        5,
+        ProposalType::RemoveLiquidity,
+        Some("DAO"),
+        alice,
+        approved,
+    );
+    executed.executed_at = Some(2);
+    let mut list = ProposalsList {
+        proposals: vec![
+            proposal(
+                1,
+                ProposalType::AddAdmin,
+                None,
+                alice,
+                ProposalStatus::Pending,
+            ),
+            proposal(
+                2,
+                ProposalType::RemoveLiquidity,
+                Some("DAO"),
+                bob,
+                ProposalStatus::Pending,
+            ),
+            proposal(3, ProposalType::AddAdmin, None, bob, approved),
+            proposal(
+                4,
+                ProposalType::RemoveLiquidity,
+                Some("OTHER"),
+                alice,
+                ProposalStatus::Rejected { timestamp: 1 },
+            ),
+            executed,
+        ],
+    };
+    let params = structs::list_proposals::Params {
+        status: None,
+        proposal_type: None,
+        dao_name: None,
+        created_by: None,
+        offset: 0,
+        limit: 10,
+    };
+
+    // Without filters every proposal is listed, in creation order
+    assert_eq!(list.list_proposals(&params), vec![1, 2, 3, 4, 5]);
+
+    // Each filter on its own
+    let states = [
+        (ProposalState::Pending, vec![1, 2]),
+        (ProposalState::Approved, vec![3]),
+        (ProposalState::Rejected, vec![4]),
+        (ProposalState::Executed, vec![5]),
+    ];
+    for (status, ids) in states {
+        let params = structs::list_proposals::Params {
+            status: Some(status),
+            ..params.clone()
+        };
+        assert_eq!(list.list_proposals(&params), ids);
+    }
+    let by_type = structs::list_proposals::Params {
+        proposal_type: Some(ProposalType::AddAdmin),
+        ..params.clone()
+    };
+    assert_eq!(list.list_proposals(&by_type), vec![1, 3]);
+    let by_dao = structs::list_proposals::Params {
+        dao_name: Some("DAO".to_string()),
+        ..params.clone()
+    };
+    assert_eq!(list.list_proposals(&by_dao), vec![2, 5]);
+    let by_creator = structs::list_proposals::Params {
+        created_by: Some(bob),
+        ..params.clone()
+    };
+    assert_eq!(list.list_proposals(&by_creator), vec![2, 3]);
+
+    // Filters combine, and a proposal must match all of them
+    let combined = structs::list_proposals::Params {
+        status: Some(ProposalState::Pending),
+        proposal_type: Some(ProposalType::RemoveLiquidity),
+        dao_name: Some("DAO".to_string()),
+        created_by: Some(bob),
+        ..params.clone()
+    };
+    assert_eq!(list.list_proposals(&combined), vec![2]);
+    assert!(!combined.matches(&list.proposals[4]));
+
+    // Pages apply to the matching proposals, and end quietly past the last one
+    let page = |offset, limit| structs::list_proposals::Params {
+        offset,
+        limit,
+        ..params.clone()
+    };
+    assert_eq!(list.list_proposals(&page(1, 2)), vec![2, 3]);
+    assert_eq!(list.list_proposals(&page(4, 10)), vec![5]);
+    assert!(list.list_proposals(&page(5, 10)).is_empty());
+    assert!(list.list_proposals(&page(u32::MAX, 10)).is_empty());
+    assert!(list.list_proposals(&page(0, 0)).is_empty());
+    let by_dao_page = structs::list_proposals::Params {
+        offset: 1,
+        ..by_dao.clone()
+    };
+    assert_eq!(list.list_proposals(&by_dao_page), vec![5]);
+
+    // Pages never exceed the maximum page size
+    let count = MAX_PROPOSALS_PAGE_SIZE + 50;
+    list.proposals = (1..=count)
+        .map(|id| {
+            proposal(
+                id,
+                ProposalType::AddAdmin,
+                None,
+                alice,
+                ProposalStatus::Pending,
+            )
+        })
+        .collect();
+    let ids = list.list_proposals(&page(0, u32::MAX));
+    assert_eq!(ids.len(), MAX_PROPOSALS_PAGE_SIZE as usize);
+    assert_eq!(ids[0], 1);
+    let ids = list.list_proposals(&page(MAX_PROPOSALS_PAGE_SIZE, u32::MAX));
+    assert_eq!(
+        ids,
+        (MAX_PROPOSALS_PAGE_SIZE + 1..=count).collect::<Vec<_>>()
+    );
+}
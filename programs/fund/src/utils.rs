@@ -1,3 +1,5 @@
+use super::*;
+
 /// Calculates amount based on amount and fee percentage
 ///
 /// # Arguments
@@ -31,19 +33,148 @@ pub fn calc_days(from: i64, to: i64) -> u16 {
 /// # Parameters
 /// - `fundraise_goal` (`u128`): The total amount of SOL targeted for fundraising.
 /// - `supply` (`u128`): The total supply of tokens available.
+/// - `sale_bps` (`u16`): Share of the supply sold to committers, in basis points.
 ///
 /// # Returns
 /// - `u64`: The number of tokens that can be obtained per SOL.
 ///
 /// # Formula
-/// - Only 90% of the total token supply is considered for distribution.
+/// - Only the share of the total token supply sold is considered for distribution.
 /// - The per-SOL token value is calculated as:
-///   [ tokens per SOL = (90% of supply) / fundraise_goal ]
-pub fn calc_token_per_sol(fundraise_goal: u128, supply: u128) -> u64 {
-    // Compute 90% of the total token supply
-    let supply_90 = supply * 90 / 100;
+///   [ tokens per SOL = (sold share of supply) / fundraise_goal ]
+pub fn calc_token_per_sol(fundraise_goal: u128, supply: u128, sale_bps: u16) -> u64 {
+    // Compute the share of the total token supply sold
+    let supply_sold = supply * sale_bps as u128 / MAX_SHARE_BPS as u128;
 
     // Calculate how many tokens can be allocated per SOL, based on the fundraising goal.
     // Converting the result to `u64` ensures compatibility with downstream calculations.
-    (supply_90 / fundraise_goal) as u64
+    (supply_sold / fundraise_goal) as u64
+}
+
+/// Calculates the share of an amount in basis points
+///
+/// # Arguments
+/// * `amount` - The base amount
+/// * `bps` - The share, in basis points
+///
+/// # Returns
+/// * The share of the amount as a u64
+pub fn calc_bps_amount(amount: u128, bps: u16) -> u64 {
+    (amount * bps as u128 / MAX_SHARE_BPS as u128) as u64
+}
+
+/// Computes the Merkle leaf of an allowlisted user.
+///
+/// Leaves are prefixed with `0x00` and nodes with `0x01`, so a node can never
+/// be passed off as a leaf.
+///
+/// # Arguments
+/// * `address` - The user's address.
+/// * `max_allowable_amount` - The user's maximum commitment, in lamports.
+/// * `tier` - The round the user is allowlisted for.
+///
+/// # Returns
+/// * The 32 byte leaf hash.
+pub fn calc_merkle_leaf(address: &Pubkey, max_allowable_amount: u64, tier: UserType) -> [u8; 32] {
+    keccak::hashv(&[
+        &[0u8],
+        address.as_ref(),
+        &max_allowable_amount.to_le_bytes(),
+        &tier.leaf_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Verifies a Merkle proof against a root.
+///
+/// Each pair of nodes is hashed in sorted order, so proofs carry no direction bits.
+///
+/// # Arguments
+/// * `proof` - Sibling hashes from the leaf up to the root.
+/// * `root` - The published root.
+/// * `leaf` - The leaf being proven.
+///
+/// # Returns
+/// * `true` if the proof leads from the leaf to the root.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (first, second) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        keccak::hashv(&[&[1u8], &first, &second]).to_bytes()
+    });
+
+    computed == *root
+}
+
+/// Splits an amount according to basis-point shares
+///
+/// # Arguments
+/// * `amount` - The amount to split
+/// * `shares` - Shares in basis points, summing to `MAX_SHARE_BPS`
+///
+/// # Returns
+/// * The part of each share, the rounding remainder going to the first share
+pub fn split_by_bps(amount: u64, shares: &[u16]) -> Vec<u64> {
+    let mut parts: Vec<u64> = shares
+        .iter()
+        .map(|share| (amount as u128 * *share as u128 / MAX_SHARE_BPS as u128) as u64)
+        .collect();
+
+    let remainder = amount - parts.iter().sum::<u64>();
+    if let Some(first) = parts.first_mut() {
+        *first += remainder;
+    }
+
+    parts
+}
+
+/// Converts basis-point shares to whole percent shares, as stored in token metadata
+///
+/// # Arguments
+/// * `shares` - Shares in basis points, summing to `MAX_SHARE_BPS`, each at least
+///   `MIN_CO_CREATOR_SHARE_BPS`
+///
+/// # Returns
+/// * The percent of each share, summing to 100, the rounding remainder going to the first share
+pub fn bps_to_percent_shares(shares: &[u16]) -> Vec<u8> {
+    let mut percents: Vec<u8> = shares.iter().map(|share| (share / 100) as u8).collect();
+
+    let remainder = 100 - percents.iter().map(|percent| *percent as u16).sum::<u16>();
+    if let Some(first) = percents.first_mut() {
+        *first += remainder as u8;
+    }
+
+    percents
+}
+
+/// Encodes token creators as the creators field of native Token-2022 metadata
+///
+/// # Arguments
+/// * `creators` - Creators and their percent shares, as stored in Metaplex metadata
+///
+/// # Returns
+/// * The comma separated `address:share` pairs
+pub fn format_metadata_creators(creators: &[mpl_token_metadata::types::Creator]) -> String {
+    creators
+        .iter()
+        .map(|creator| format!("{}:{}", creator.address, creator.share))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Decodes the creator addresses of the creators field of native Token-2022 metadata
+///
+/// # Arguments
+/// * `value` - The comma separated `address:share` pairs
+///
+/// # Returns
+/// * The creator addresses, malformed entries being skipped
+pub fn parse_metadata_creators(value: &str) -> Vec<Pubkey> {
+    value
+        .split(',')
+        .filter_map(|creator| creator.split(':').next()?.trim().parse().ok())
+        .collect()
 }
@@ -1,11 +1,14 @@
 use super::*;
 
 /// Function to add liquidity
-pub fn handler(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+    proposal_id: u32,
+) -> Result<()> {
     let cpi_program = ctx.accounts.bonding_curve_program.to_account_info();
 
     let cpi_accounts = bonding_curve::cpi::accounts::InitTrade {
-        fund_global_config: ctx.accounts.fund_global_config.to_account_info(),
+        role_membership: ctx.accounts.role_membership.to_account_info(),
         sol_reserve: ctx.accounts.sol_reserve.to_account_info(),
         token_reserve: ctx.accounts.token_reserve.to_account_info(),
         mint_account: ctx.accounts.mint_account.to_account_info(),
@@ -21,6 +24,7 @@ pub fn handler(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
 
     let cpi_accounts = bonding_curve::cpi::accounts::AddLiquidity {
         fund_global_config: ctx.accounts.fund_global_config.to_account_info(),
+        pause_config: ctx.accounts.pause_config.to_account_info(),
         sol_reserve: ctx.accounts.sol_reserve.to_account_info(),
         token_reserve: ctx.accounts.token_reserve.to_account_info(),
         mint_account: ctx.accounts.mint_account.to_account_info(),
@@ -31,7 +35,9 @@ pub fn handler(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
         escrow_mint_ata: ctx.accounts.escrow_mint_ata.to_account_info(),
         payer: ctx.accounts.payer.to_account_info(),
         escrow_sol_account: ctx.accounts.escrow_sol_account.to_account_info(),
+        treasury_ledger: ctx.accounts.treasury_ledger.to_account_info(),
         fund_data_store: ctx.accounts.fund_data_store.to_account_info(),
+        sale_tiers: ctx.accounts.sale_tiers.to_account_info(),
         fund: ctx.accounts.fund.to_account_info(),
         trade: ctx.accounts.trade.to_account_info(),
         creators: ctx.accounts.creators.to_account_info(),
@@ -39,7 +45,9 @@ pub fn handler(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
         bonding_curve_program: ctx.accounts.bonding_curve_program.to_account_info(),
     };
 
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    // Pass the accounts of the mint's transfer hook, if any
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
 
     bonding_curve::cpi::add_liquidity(cpi_ctx, proposal_id)?;
 
@@ -53,6 +61,14 @@ pub struct AddLiquidity<'info> {
     #[account()]
     pub fund_global_config: AccountInfo<'info>,
 
+    /// CHECK: Payer's role membership account in the fund program
+    #[account()]
+    pub role_membership: AccountInfo<'info>,
+
+    /// CHECK: Fund Pause Config
+    #[account()]
+    pub pause_config: AccountInfo<'info>,
+
     /// CHECK: Bonding Curve Global Config
     #[account()]
     pub bonding_curve_global_config: AccountInfo<'info>,
@@ -61,6 +77,10 @@ pub struct AddLiquidity<'info> {
     #[account(mut)]
     pub escrow_sol_account: AccountInfo<'info>,
 
+    /// CHECK: Treasury Ledger Account
+    #[account(mut)]
+    pub treasury_ledger: AccountInfo<'info>,
+
     /// CHECK: Mint Account
     #[account(mut)]
     pub mint_account: AccountInfo<'info>,
@@ -73,6 +93,9 @@ pub struct AddLiquidity<'info> {
     #[account(mut)]
     pub fund_data_store: AccountInfo<'info>,
 
+    /// CHECK: Sale tiers of the DAO, validated by the fund program
+    pub sale_tiers: AccountInfo<'info>,
+
     /// CHECK: Token Counter
     #[account(mut)]
     pub sol_reserve: AccountInfo<'info>,
@@ -110,7 +133,7 @@ pub struct AddLiquidity<'info> {
 
     pub system_program: Program<'info, System>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
@@ -6,24 +6,50 @@ pub fn handler(ctx: Context<CreateDao>, params: structs::create::Params) -> Resu
     let mint_params = params.to_mint_params();
     let cpi_program = ctx.accounts.fund.to_account_info();
 
-    let cpi_accounts = fund::cpi::accounts::CreateToken {
-        mint_account: ctx.accounts.mint_account.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        creators: ctx.accounts.creators.to_account_info(),
-        creator_info: ctx.accounts.creator_info.to_account_info(),
-        metadata: ctx.accounts.metadata.to_account_info(),
-        fund_data_store: ctx.accounts.fund_data_store.to_account_info(),
-        dao_list: ctx.accounts.dao_list.to_account_info(),
-        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
-        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
-        rent: ctx.accounts.rent.to_account_info(),
-    };
-
-    let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
-
-    fund::cpi::create(cpi_ctx, create_params)?;
+    // Tokens of the Token-2022 program store their metadata in the mint
+    if ctx.accounts.token_program.key() == token_2022::ID {
+        let cpi_accounts = fund::cpi::accounts::CreateToken2022 {
+            mint_account: ctx.accounts.mint_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            global_config: ctx.accounts.global_config.to_account_info(),
+            creators: ctx.accounts.creators.to_account_info(),
+            creator_info: ctx.accounts.creator_info.to_account_info(),
+            creator_dao_info: ctx.accounts.creator_dao_info.to_account_info(),
+            fund_data_store: ctx.accounts.fund_data_store.to_account_info(),
+            dao_list: ctx.accounts.dao_list.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            extra_account_meta_list: ctx.accounts.extra_account_meta_list.clone(),
+            transfer_hook_program: ctx.accounts.transfer_hook_program.clone(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+
+        fund::cpi::create_token_2022(cpi_ctx, create_params)?;
+    } else {
+        let cpi_accounts = fund::cpi::accounts::CreateToken {
+            mint_account: ctx.accounts.mint_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            global_config: ctx.accounts.global_config.to_account_info(),
+            creators: ctx.accounts.creators.to_account_info(),
+            creator_info: ctx.accounts.creator_info.to_account_info(),
+            creator_dao_info: ctx.accounts.creator_dao_info.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            fund_data_store: ctx.accounts.fund_data_store.to_account_info(),
+            dao_list: ctx.accounts.dao_list.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts);
+
+        fund::cpi::create(cpi_ctx, create_params)?;
+    }
 
     let cpi_accounts = fund::cpi::accounts::MintToken {
         mint_account: ctx.accounts.mint_account.to_account_info(),
@@ -45,6 +71,9 @@ pub fn handler(ctx: Context<CreateDao>, params: structs::create::Params) -> Resu
 #[derive(Accounts)]
 #[instruction(params: structs::create::Params)]
 pub struct CreateDao<'info> {
+    /// CHECK: Global Config
+    pub global_config: AccountInfo<'info>,
+
     /// CHECK: Creator
     #[account(mut)]
     pub creators: AccountInfo<'info>,
@@ -53,11 +82,15 @@ pub struct CreateDao<'info> {
     #[account(mut)]
     pub creator_info: AccountInfo<'info>,
 
+    /// CHECK: Creator Info of the DAO
+    #[account(mut)]
+    pub creator_dao_info: AccountInfo<'info>,
+
     /// CHECK: Mint Account
     #[account(mut)]
     pub mint_account: AccountInfo<'info>,
 
-    /// CHECK: New Metaplex Account being created
+    /// CHECK: New Metaplex Account being created, unused for Token-2022 tokens
     #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
 
@@ -89,9 +122,16 @@ pub struct CreateDao<'info> {
     #[account(executable, address = fund::ID)]
     pub fund: AccountInfo<'info>,
 
+    /// CHECK: Accounts resolved by the transfer hook, validated by the fund program
+    #[account(mut)]
+    pub extra_account_meta_list: Option<AccountInfo<'info>>,
+
+    /// CHECK: Transfer hook program, validated by the fund program
+    pub transfer_hook_program: Option<AccountInfo<'info>>,
+
     pub system_program: Program<'info, System>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
 
@@ -4,7 +4,10 @@ use anchor_lang::{
     prelude::*,
     solana_program::{account_info::AccountInfo, rent::Rent},
 };
-use anchor_spl::{associated_token::AssociatedToken, metadata::Metadata, token::Token};
+use anchor_spl::{
+    associated_token::AssociatedToken, metadata::Metadata, token_2022,
+    token_interface::TokenInterface,
+};
 
 mod enums;
 mod errors;
@@ -21,7 +24,10 @@ pub mod proxy {
         create::handler(ctx, params)
     }
 
-    pub fn add_liquidity(ctx: Context<AddLiquidity>, proposal_id: u32) -> Result<()> {
+    pub fn add_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddLiquidity<'info>>,
+        proposal_id: u32,
+    ) -> Result<()> {
         add_liquidity::handler(ctx, proposal_id)
     }
 }
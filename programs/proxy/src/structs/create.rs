@@ -23,6 +23,18 @@ pub struct Params {
 
     /// Vesting Percent
     pub vesting_percent: fund::VestingPercent,
+
+    /// Co-creators with their shares, the caller holding all shares if empty
+    pub co_creators: Vec<fund::CoCreator>,
+
+    /// Tokenomics, the default ones if none
+    pub tokenomics: Option<fund::Tokenomics>,
+
+    /// Milestones releasing the creator funds, released freely if empty
+    pub milestones: Vec<fund::MilestoneParams>,
+
+    /// Token-2022 extensions of the mint, none for legacy mints
+    pub token_extensions: Option<fund::TokenExtensionsParams>,
 }
 
 impl Params {
@@ -35,6 +47,10 @@ impl Params {
             fundraising_goal: self.fundraising_goal,
             vesting_percent: self.vesting_percent,
             amount: self.amount,
+            co_creators: self.co_creators.clone(),
+            tokenomics: self.tokenomics,
+            milestones: self.milestones.clone(),
+            token_extensions: self.token_extensions,
         }
     }
 
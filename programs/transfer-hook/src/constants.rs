@@ -0,0 +1,5 @@
+use super::*;
+
+/// Tag for per-mint lists of the accounts resolved on transfers, as defined by the transfer hook interface
+#[constant]
+pub const EXTRA_ACCOUNT_METAS_TAG: &[u8] = b"extra-account-metas";
@@ -0,0 +1,137 @@
+use super::*;
+
+/// Function to check a transfer of a DAO token against the blacklist, called by Token-2022
+///
+/// Sending is treated like selling under the blacklist policy of the DAO, and
+/// receiving like buying, or like claiming when the tokens leave the DAO escrow.
+///
+/// # Arguments
+/// * `ctx` - The execution context containing the transfer and its resolved accounts.
+/// * `_amount` - Amount of tokens transferred.
+pub fn handler(ctx: Context<Execute>, _amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // Ensure the sender may move its tokens out
+    fund::require_allowed_by_blacklist(
+        &ctx.accounts.source_token.owner,
+        &ctx.accounts.source_blacklist_entry,
+        &ctx.accounts.source_dao_blacklist_entry,
+        [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+        &ctx.accounts.blacklist_policy,
+        BlacklistAction::Sell,
+        now,
+    )?;
+
+    let (escrow_mint_account, _) = Pubkey::find_program_address(
+        &[
+            fund::ESCROW_TAG,
+            fund::MINT_TAG,
+            ctx.accounts.mint.key().as_ref(),
+        ],
+        &fund::ID,
+    );
+    let inflow = if ctx.accounts.source_token.owner == escrow_mint_account {
+        BlacklistAction::ClaimTokens
+    } else {
+        BlacklistAction::Buy
+    };
+
+    // Ensure the recipient may receive tokens
+    fund::require_allowed_by_blacklist(
+        &ctx.accounts.destination_token.owner,
+        &ctx.accounts.destination_blacklist_entry,
+        &ctx.accounts.destination_dao_blacklist_entry,
+        [&ctx.accounts.blacklist, &ctx.accounts.dao_blacklist],
+        &ctx.accounts.blacklist_policy,
+        inflow,
+        now,
+    )?;
+
+    Ok(())
+}
+
+/// Struct defining the accounts of a transfer, in the order of the interface, followed by
+/// the accounts resolved from the list.
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    /// Token account the tokens are sent from
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint account of the DAO
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account the tokens are sent to
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Owner or delegate of the source token account, validated by Token-2022
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: List of the accounts resolved on transfers
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_TAG, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// CHECK: Fund Program Address
+    #[account(address = fund::ID)]
+    pub fund: AccountInfo<'info>,
+
+    /// CHECK: Marker of the sender blocked in every DAO, absent unless blocked
+    #[account(
+        seeds = [fund::BLACKLIST_ENTRY_TAG, source_token.owner.as_ref()],
+        bump,
+        seeds::program = fund::ID,
+    )]
+    pub source_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the sender blocked in the DAO, absent unless blocked
+    #[account(
+        seeds = [fund::BLACKLIST_ENTRY_TAG, mint.key().as_ref(), source_token.owner.as_ref()],
+        bump,
+        seeds::program = fund::ID,
+    )]
+    pub source_dao_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the recipient blocked in every DAO, absent unless blocked
+    #[account(
+        seeds = [fund::BLACKLIST_ENTRY_TAG, destination_token.owner.as_ref()],
+        bump,
+        seeds::program = fund::ID,
+    )]
+    pub destination_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Marker of the recipient blocked in the DAO, absent unless blocked
+    #[account(
+        seeds = [fund::BLACKLIST_ENTRY_TAG, mint.key().as_ref(), destination_token.owner.as_ref()],
+        bump,
+        seeds::program = fund::ID,
+    )]
+    pub destination_dao_blacklist_entry: AccountInfo<'info>,
+
+    /// CHECK: Blacklist policy of the DAO, absent until set
+    #[account(
+        seeds = [fund::BLACKLIST_POLICY_TAG, mint.key().as_ref()],
+        bump,
+        seeds::program = fund::ID,
+    )]
+    pub blacklist_policy: AccountInfo<'info>,
+
+    /// CHECK: Legacy global blacklist, checked until migrated into markers
+    #[account(
+        seeds = [fund::BLACKLIST_TAG],
+        bump,
+        seeds::program = fund::ID,
+    )]
+    pub blacklist: AccountInfo<'info>,
+
+    /// CHECK: Legacy blacklist of the DAO, checked until migrated into markers
+    #[account(
+        seeds = [fund::BLACKLIST_TAG, mint.key().as_ref()],
+        bump,
+        seeds::program = fund::ID,
+    )]
+    pub dao_blacklist: AccountInfo<'info>,
+}
@@ -0,0 +1,121 @@
+use super::*;
+
+/// Function to initialize the accounts resolved on every transfer of a DAO token
+///
+/// Token-2022 resolves these accounts from the list when transferring, and passes
+/// them to `execute`: the fund program, then the global and DAO blacklist markers
+/// of the sender and of the recipient, the blacklist policy of the DAO, and the legacy
+/// global and DAO blacklists. Anyone can initialize the list of a mint, before its
+/// first transfer.
+pub fn handler(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+        &extra_account_metas()?,
+    )?;
+
+    Ok(())
+}
+
+/// Builds the accounts resolved on transfers, following the accounts of `Execute`
+///
+/// Seeds refer to the accounts of the transfer by index: the source token account (0),
+/// the mint (1) and the destination token account (2), whose owner is stored at offset 32.
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    let fund_program_index = 5;
+    let marker_seeds = |token_account_index: u8, in_dao: bool| {
+        let mut seeds = vec![Seed::Literal {
+            bytes: fund::BLACKLIST_ENTRY_TAG.to_vec(),
+        }];
+        if in_dao {
+            seeds.push(Seed::AccountKey { index: 1 });
+        }
+        seeds.push(Seed::AccountData {
+            account_index: token_account_index,
+            data_index: 32,
+            length: 32,
+        });
+        seeds
+    };
+
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(&fund::ID, false, false)?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            fund_program_index,
+            &marker_seeds(0, false),
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            fund_program_index,
+            &marker_seeds(0, true),
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            fund_program_index,
+            &marker_seeds(2, false),
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            fund_program_index,
+            &marker_seeds(2, true),
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            fund_program_index,
+            &[
+                Seed::Literal {
+                    bytes: fund::BLACKLIST_POLICY_TAG.to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            fund_program_index,
+            &[Seed::Literal {
+                bytes: fund::BLACKLIST_TAG.to_vec(),
+            }],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            fund_program_index,
+            &[
+                Seed::Literal {
+                    bytes: fund::BLACKLIST_TAG.to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+/// Struct defining the accounts required for initializing the list, in the order of the interface.
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// CHECK: List of the accounts resolved on transfers, written by the handler
+    #[account(
+        init,
+        seeds = [EXTRA_ACCOUNT_METAS_TAG, mint.key().as_ref()],
+        bump,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        payer = payer,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// Mint account of the DAO
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Account paying for the list
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+}
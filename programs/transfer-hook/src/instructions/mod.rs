@@ -0,0 +1,6 @@
+use super::*;
+
+pub mod execute;
+pub mod initialize_extra_account_meta_list;
+
+pub use self::{execute::*, initialize_extra_account_meta_list::*};
@@ -0,0 +1,38 @@
+#![allow(unexpected_cfgs)]
+/// Transfer Hook Program - A Solana program checking transfers of Token-2022 DAO tokens
+/// against the blacklist of the fund program, under the blacklist policy of each DAO.
+use crate::{constants::*, instructions::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use fund::BlacklistAction;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+mod constants; // Program constants
+mod instructions; // Instruction handlers
+
+#[cfg(test)]
+mod tests;
+
+declare_id!("45VLfgrEZk14A5Ev2du94Rc5sWGukZZyPTdP26b811Sc");
+
+#[program]
+pub mod transfer_hook {
+    use super::*;
+
+    /// Initialize the accounts resolved on every transfer of a DAO token
+    #[interface(spl_transfer_hook_interface::initialize_extra_account_meta_list)]
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        initialize_extra_account_meta_list::handler(ctx)
+    }
+
+    /// Check a transfer of a DAO token against the blacklist
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn execute(ctx: Context<Execute>, amount: u64) -> Result<()> {
+        execute::handler(ctx, amount)
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+#[test]
+fn test_extra_account_metas_resolve_blacklist_accounts() {
+    let source_owner = Pubkey::new_unique();
+    let destination_owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    // Token accounts store their mint, then their owner at offset 32
+    let token_account_data = |owner: &Pubkey| {
+        let mut data = vec![0u8; 165];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data
+    };
+    let source_data = token_account_data(&source_owner);
+    let destination_data = token_account_data(&destination_owner);
+
+    // Accounts of the transfer, in the order of the interface
+    let mut keys = vec![
+        Pubkey::new_unique(),
+        mint,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    for meta in extra_account_metas().unwrap() {
+        let resolved = meta
+            .resolve(&[], &crate::ID, |index| {
+                let data = match index {
+                    0 => Some(&source_data[..]),
+                    2 => Some(&destination_data[..]),
+                    _ => None,
+                };
+                keys.get(index).map(|key| (key, data))
+            })
+            .unwrap();
+        assert!(!resolved.is_signer && !resolved.is_writable);
+        keys.push(resolved.pubkey);
+    }
+
+    let fund_pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &fund::ID).0;
+    assert_eq!(
+        keys[5..],
+        [
+            fund::ID,
+            fund_pda(&[fund::BLACKLIST_ENTRY_TAG, source_owner.as_ref()]),
+            fund_pda(&[
+                fund::BLACKLIST_ENTRY_TAG,
+                mint.as_ref(),
+                source_owner.as_ref()
+            ]),
+            fund_pda(&[fund::BLACKLIST_ENTRY_TAG, destination_owner.as_ref()]),
+            fund_pda(&[
+                fund::BLACKLIST_ENTRY_TAG,
+                mint.as_ref(),
+                destination_owner.as_ref()
+            ]),
+            fund_pda(&[fund::BLACKLIST_POLICY_TAG, mint.as_ref()]),
+            fund_pda(&[fund::BLACKLIST_TAG]),
+            fund_pda(&[fund::BLACKLIST_TAG, mint.as_ref()]),
+        ]
+    );
+}
+
+#[test]
+fn test_extra_account_meta_list_fits_its_space() {
+    let metas = extra_account_metas().unwrap();
+    let space = ExtraAccountMetaList::size_of(metas.len()).unwrap();
+
+    let mut data = vec![0u8; space];
+    assert!(ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas).is_ok());
+
+    let mut data = vec![0u8; space - 1];
+    assert!(ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas).is_err());
+}
@@ -558,10 +558,15 @@ describe("fund", () => {
     await confirmTransaction(remove);
   };
 
-  const removeAdmin = async (proposalId, signer) => {
+  const removeAdmin = async (proposalId, address, signer) => {
+    const [roleMembership] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("role"), address.toBuffer()],
+      program.programId
+    );
     let create = await program.methods
       .removeAdmin(proposalId)
       .accounts({
+        roleMembership,
         executor: signer.publicKey,
       })
       .signers([signer])
@@ -2658,6 +2663,10 @@ describe("fund", () => {
     let removeSubAdmins = await program.methods
       .removeSubAdminAccounts([user1.publicKey])
       .accounts({
+        roleMembership: anchor.web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("role"), user1.publicKey.toBuffer()],
+          program.programId
+        )[0],
         authority: admin.publicKey,
       })
       .signers([admin])
@@ -2679,6 +2688,10 @@ describe("fund", () => {
       await program.methods
         .removeSubAdminAccounts([user2.publicKey])
         .accounts({
+          roleMembership: anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("role"), user2.publicKey.toBuffer()],
+            program.programId
+          )[0],
           authority: admin.publicKey,
         })
         .signers([admin])
@@ -2693,6 +2706,10 @@ describe("fund", () => {
       await program.methods
         .removeSubAdminAccounts([admin.publicKey])
         .accounts({
+          roleMembership: anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("role"), admin.publicKey.toBuffer()],
+            program.programId
+          )[0],
           authority: user1.publicKey,
         })
         .signers([user1])
@@ -2841,7 +2858,7 @@ describe("fund", () => {
   });
 
   it("Fund: Test remove admin", async () => {
-    await removeAdmin(currentProposalId, admin);
+    await removeAdmin(currentProposalId, user4.publicKey, admin);
 
     let globalConfig = await program.account.globalConfig.fetch(
       pdaGlobalConfig
@@ -2868,7 +2885,7 @@ describe("fund", () => {
   it("Fund: Test remove admin with other than admin account", async () => {
     // Test invalid authority
     try {
-      await removeAdmin(currentProposalId, payer);
+      await removeAdmin(currentProposalId, user4.publicKey, payer);
       assert.fail("Should throw Unauthorized error");
     } catch (err) {
       assert.include(err.message, "Unauthorized");
@@ -3100,6 +3117,81 @@ describe("fund", () => {
       assert.include(err.message, "Unauthorized");
     }
   });
+
+  it("Fund: Test custom instruction proposal updating the fee collection account", async () => {
+    const [governance] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("governance")],
+      program.programId
+    );
+    [pdaFeeAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [FEE],
+      program.programId
+    );
+
+    // The governance PDA executes the update, carrying the new account itself
+    const ix = await program.methods
+      .updateFeeAccount(0, feesCollectionAccount.publicKey)
+      .accountsPartial({ authority: governance })
+      .instruction();
+
+    currentProposalId += 1;
+    const [proposalInstructions] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("proposal_instructions"),
+        new BN(currentProposalId).toArrayLike(Buffer, "le", 4),
+      ],
+      program.programId
+    );
+
+    let create = await program.methods
+      .createCustomInstructionProposal([
+        {
+          programId: ix.programId,
+          accounts: ix.keys.map((key) => ({
+            pubkey: key.pubkey,
+            isSigner: key.isSigner,
+            isWritable: key.isWritable,
+          })),
+          data: ix.data,
+        },
+      ])
+      .accountsPartial({ proposalInstructions, signer: admin.publicKey })
+      .signers([admin])
+      .rpc();
+    await confirmTransaction(create);
+
+    for (const approver of [admin, owner, payer]) {
+      if (await isAdmin(approver.publicKey)) {
+        await approveProposal(currentProposalId, approver);
+      }
+    }
+
+    const globalConfig = await program.account.globalConfig.fetch(
+      pdaGlobalConfig
+    );
+    const executor = [admin, owner, payer].find((keypair) =>
+      keypair.publicKey.equals(globalConfig.owner)
+    );
+
+    let execute = await program.methods
+      .executeProposal(currentProposalId)
+      .accountsPartial({ proposalInstructions, executor: executor.publicKey })
+      .remainingAccounts([
+        ...ix.keys
+          .filter((key) => !key.pubkey.equals(governance))
+          .map((key) => ({ ...key, isSigner: false })),
+        { pubkey: program.programId, isSigner: false, isWritable: false },
+      ])
+      .signers([executor])
+      .rpc();
+    await confirmTransaction(execute);
+
+    let feeAccount = await program.account.feeAccount.fetch(pdaFeeAccount);
+    assert.equal(
+      feeAccount.feesCollectionAccount.toBase58(),
+      feesCollectionAccount.publicKey.toBase58()
+    );
+  });
 });
 
 describe("Bonding Curve", () => {